@@ -48,7 +48,7 @@ fn main() {
                 // Pattern match on the variant to get the progress value
                 if let Variant::Int32(progress) = variant {
                     // Draw progress bar background
-                    ctx.set_brush(Colour::rgb(240, 240, 240), BrushStyle::Solid);
+                    ctx.set_brush(&Brush::solid(Colour::rgb(240, 240, 240)));
                     ctx.draw_rectangle(rect.x, rect.y, rect.width, rect.height);
 
                     // Draw progress bar fill
@@ -63,7 +63,7 @@ fn main() {
                         Colour::rgb(244, 67, 54) // Red for low progress
                     };
 
-                    ctx.set_brush(color, BrushStyle::Solid);
+                    ctx.set_brush(&Brush::solid(color));
                     ctx.draw_rectangle(rect.x, rect.y, fill_width, rect.height);
 
                     // Draw progress text
@@ -103,7 +103,7 @@ fn main() {
                     };
 
                     // Draw background
-                    ctx.set_brush(bg_color, BrushStyle::Solid);
+                    ctx.set_brush(&Brush::solid(bg_color));
                     ctx.draw_rectangle(rect.x, rect.y, rect.width, rect.height);
 
                     // Draw text
@@ -1,7 +1,7 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::time::{Duration, Instant};
-use wxdragon::dc::{AutoBufferedPaintDC, BrushStyle, PenStyle};
+use wxdragon::dc::{AutoBufferedPaintDC, PenStyle};
 use wxdragon::prelude::*;
 
 /// Animation state for the fill effect
@@ -188,12 +188,12 @@ impl AniFillButton {
         let radius = config.border_radius as f64;
 
         // 清除背景（使用透明画刷）
-        dc.set_brush(Colour::new(0, 0, 0, 0), BrushStyle::Transparent);
+        dc.set_brush(&Brush::transparent());
         dc.draw_rectangle(0, 0, width, height);
 
         // 1. 绘制基础背景（不考虑pressed状态）
-        dc.set_brush(config.background_color, BrushStyle::Solid);
-        dc.set_pen(Colour::new(0, 0, 0, 0), 0, PenStyle::Transparent);
+        dc.set_brush(&Brush::solid(config.background_color));
+        dc.set_pen(&Pen::transparent());
         dc.draw_rounded_rectangle(0, 0, width, height, radius);
 
         // 2. 绘制填充动画（从左到右的填充效果，不考虑pressed状态）
@@ -201,8 +201,8 @@ impl AniFillButton {
             let fill_width = (width as f32 * progress) as i32;
 
             // 使用裁剪区域实现填充效果
-            dc.set_pen(Colour::new(0, 0, 0, 0), 0, PenStyle::Transparent);
-            dc.set_brush(config.fill_background_color, BrushStyle::Solid);
+            dc.set_pen(&Pen::transparent());
+            dc.set_brush(&Brush::solid(config.fill_background_color));
 
             // 设置裁剪区域来限制填充范围
             dc.set_clipping_region(0, 0, fill_width, height);
@@ -214,8 +214,8 @@ impl AniFillButton {
         if is_pressed {
             // 创建一个更强的半透明深色覆盖层来实现darken效果
             let overlay_color = Colour::new(0, 0, 0, 100); // 大约40%的透明度，更明显
-            dc.set_brush(overlay_color, BrushStyle::Solid);
-            dc.set_pen(Colour::new(0, 0, 0, 0), 0, PenStyle::Transparent);
+            dc.set_brush(&Brush::solid(overlay_color));
+            dc.set_pen(&Pen::transparent());
             dc.draw_rounded_rectangle(0, 0, width, height, radius);
         }
 
@@ -226,8 +226,12 @@ impl AniFillButton {
             } else {
                 config.border_color
             };
-            dc.set_pen(border_color, config.border_width, PenStyle::Solid);
-            dc.set_brush(Colour::new(0, 0, 0, 0), BrushStyle::Transparent);
+            dc.set_pen(&Pen::new(
+                border_color,
+                config.border_width,
+                PenStyle::Solid,
+            ));
+            dc.set_brush(&Brush::transparent());
             dc.draw_rounded_rectangle(0, 0, width, height, radius);
         }
 
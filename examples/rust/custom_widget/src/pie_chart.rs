@@ -1,7 +1,7 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::time::{Duration, Instant};
-use wxdragon::dc::{AutoBufferedPaintDC, BrushStyle, PenStyle, Point, PolygonFillMode};
+use wxdragon::dc::{AutoBufferedPaintDC, PenStyle, Point, PolygonFillMode};
 use wxdragon::event::WindowEventData;
 use wxdragon::prelude::*;
 
@@ -292,8 +292,8 @@ impl PieChart {
         let height = size.height;
 
         // Clear background
-        dc.set_brush(config.background_color, BrushStyle::Solid);
-        dc.set_pen(Colour::new(0, 0, 0, 0), 0, PenStyle::Transparent);
+        dc.set_brush(&Brush::solid(config.background_color));
+        dc.set_pen(&Pen::transparent());
         dc.draw_rectangle(0, 0, width, height);
 
         let center_x = width / 2;
@@ -320,8 +320,8 @@ impl PieChart {
             );
 
             // Draw color indicator
-            dc.set_brush(slice.color, BrushStyle::Solid);
-            dc.set_pen(config.border_color, 1, PenStyle::Solid);
+            dc.set_brush(&Brush::solid(slice.color));
+            dc.set_pen(&Pen::new(config.border_color, 1, PenStyle::Solid));
             dc.draw_rectangle(10, y_offset, 15, 15);
 
             // Draw label text
@@ -372,8 +372,12 @@ impl PieChart {
         }
 
         // Draw the filled slice
-        dc.set_brush(slice.color, BrushStyle::Solid);
-        dc.set_pen(config.border_color, config.border_width, PenStyle::Solid);
+        dc.set_brush(&Brush::solid(slice.color));
+        dc.set_pen(&Pen::new(
+            config.border_color,
+            config.border_width,
+            PenStyle::Solid,
+        ));
         dc.draw_polygon(&points, 0, 0, PolygonFillMode::Winding);
     }
 }
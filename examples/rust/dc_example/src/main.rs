@@ -1,3 +1,4 @@
+use wxdragon::dc::{PenCap, PenJoin, Point};
 use wxdragon::prelude::*;
 
 struct DrawingPanel {
@@ -25,17 +26,17 @@ impl DrawingPanel {
             let (width, height) = dc.get_size();
 
             // Draw a red rectangle
-            dc.set_pen(Colour::rgb(255, 0, 0), 2, PenStyle::Solid);
-            dc.set_brush(Colour::rgb(255, 200, 200), BrushStyle::Solid);
+            dc.set_pen(&Pen::new(Colour::rgb(255, 0, 0), 2, PenStyle::Solid));
+            dc.set_brush(&Brush::solid(Colour::rgb(255, 200, 200)));
             dc.draw_rectangle(10, 10, 100, 50);
 
             // Draw a blue circle
-            dc.set_pen(Colour::rgb(0, 0, 255), 2, PenStyle::Solid);
-            dc.set_brush(Colour::rgb(200, 200, 255), BrushStyle::Solid);
+            dc.set_pen(&Pen::new(Colour::rgb(0, 0, 255), 2, PenStyle::Solid));
+            dc.set_brush(&Brush::solid(Colour::rgb(200, 200, 255)));
             dc.draw_circle(width - 60, height - 60, 50);
 
             // Draw a green line
-            dc.set_pen(Colour::rgb(0, 150, 0), 3, PenStyle::Solid);
+            dc.set_pen(&Pen::new(Colour::rgb(0, 150, 0), 3, PenStyle::Solid));
             dc.draw_line(10, height - 10, width - 10, 10);
 
             // Draw some text
@@ -43,9 +44,27 @@ impl DrawingPanel {
             dc.draw_text("Hello, wxDragon DC!", 20, 80);
 
             // Draw a yellow ellipse with crosshatch pattern
-            dc.set_pen(Colour::rgb(150, 150, 0), 1, PenStyle::Solid);
-            dc.set_brush(Colour::rgb(255, 255, 0), BrushStyle::CrossHatch);
+            dc.set_pen(&Pen::new(Colour::rgb(150, 150, 0), 1, PenStyle::Solid));
+            dc.set_brush(&Brush::new(
+                Colour::rgb(255, 255, 0),
+                BrushStyle::CrossHatch,
+            ));
             dc.draw_ellipse(150, 120, 100, 60);
+
+            // Draw a dashed connector with rounded corners
+            let connector = Pen::builder(Colour::rgb(80, 80, 80))
+                .with_width(3)
+                .with_join(PenJoin::Round)
+                .with_cap(PenCap::Butt)
+                .with_dashes(&[3, 2])
+                .build();
+            dc.set_pen(&connector);
+            let points = [
+                Point::new(60, 60),
+                Point::new(60, 160),
+                Point::new(150, 150),
+            ];
+            dc.draw_lines(&points, 0, 0);
         });
 
         // Make a separate clone for the SIZE event handler
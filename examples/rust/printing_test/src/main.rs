@@ -0,0 +1,14 @@
+//! Prints a two-page document to a PostScript file and checks that both pages
+//! were written, exiting with an error otherwise.
+//!
+//! This needs wxWidgets' PostScript backend, so it only runs on Linux.
+
+#[cfg(target_os = "linux")]
+mod postscript;
+
+fn main() {
+    #[cfg(target_os = "linux")]
+    postscript::main();
+    #[cfg(not(target_os = "linux"))]
+    eprintln!("printing_test only runs on Linux, where the PostScript backend is available");
+}
@@ -0,0 +1,87 @@
+//! Prints through wxWidgets' PostScript backend, which is only used on Linux.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use wxdragon::prelude::*;
+use wxdragon::printing::{
+    use_postscript_backend, PageInfo, PrintData, PrintDialogData, PrintMode, Printer, Printout,
+    PrintoutDC,
+};
+
+const PAGES: i32 = 2;
+
+static PASSED: AtomicBool = AtomicBool::new(false);
+
+struct NumberedPages;
+
+impl Printout for NumberedPages {
+    fn get_page_info(&mut self) -> PageInfo {
+        PageInfo::new(1, PAGES)
+    }
+
+    fn has_page(&mut self, page: i32) -> bool {
+        (1..=PAGES).contains(&page)
+    }
+
+    fn on_print_page(&mut self, dc: &PrintoutDC, page: i32) -> bool {
+        dc.map_screen_size_to_page();
+        dc.draw_text(&format!("Page {page} of {PAGES}"), 50, 50);
+        dc.draw_rectangle(40, 40, 200, 40);
+        true
+    }
+
+    fn title(&self) -> String {
+        "Printing Test".to_string()
+    }
+}
+
+/// Prints to `path` without a dialog and checks that every page was written
+fn print_to_file(frame: &Frame, path: &Path) -> Result<(), String> {
+    let mut print_data = PrintData::new();
+    print_data.set_print_mode(PrintMode::File);
+    print_data.set_filename(path);
+    let mut dialog_data = PrintDialogData::new();
+    dialog_data.set_print_data(&print_data);
+
+    let printer = Printer::new(Some(&dialog_data));
+    printer
+        .print(Some(frame), &mut NumberedPages, false)
+        .map_err(|err| format!("printing failed: {err}"))?;
+
+    let postscript = std::fs::read_to_string(path)
+        .map_err(|err| format!("{} wasn't written: {err}", path.display()))?;
+    for page in 1..=PAGES {
+        if !postscript.contains(&format!("%%Page: {page}")) {
+            return Err(format!("page {page} is missing from {}", path.display()));
+        }
+    }
+    Ok(())
+}
+
+pub fn main() {
+    let _ = wxdragon::main(|_| {
+        // Writes PostScript itself, so no printer or GTK print dialog is needed
+        use_postscript_backend();
+
+        let frame = Frame::builder().with_title("Printing Test").build();
+        let path =
+            std::env::temp_dir().join(format!("wxdragon-printing-{}.ps", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        match print_to_file(&frame, &path) {
+            Ok(()) => {
+                println!("Printed {PAGES} pages to {}", path.display());
+                PASSED.store(true, Ordering::SeqCst);
+            }
+            Err(err) => eprintln!("{err}"),
+        }
+        let _ = std::fs::remove_file(&path);
+
+        // Closing the only frame ends the main loop
+        frame.close(true);
+    });
+
+    if !PASSED.load(Ordering::SeqCst) {
+        std::process::exit(1);
+    }
+}
@@ -377,6 +377,7 @@ fn setup_linking(wx_version: &str, target_os: &str, target_env: &str, out_dir: &
 fn link_macos_libraries() {
     // Core wxWidgets libraries for macOS
     println!("cargo:rustc-link-lib=static=wx_osx_cocoau_core-3.3");
+    println!("cargo:rustc-link-lib=static=wx_baseu_net-3.3");
     println!("cargo:rustc-link-lib=static=wx_baseu-3.3");
     println!("cargo:rustc-link-lib=static=wx_osx_cocoau_adv-3.3");
     println!("cargo:rustc-link-lib=static=wx_osx_cocoau_gl-3.3");
@@ -715,6 +716,7 @@ fn link_windows_libraries(target_env: &str) {
 fn link_linux_libraries() {
     // Core wxWidgets libraries for Linux
     println!("cargo:rustc-link-lib=static=wx_gtk3u_core-3.3");
+    println!("cargo:rustc-link-lib=static=wx_baseu_net-3.3");
     println!("cargo:rustc-link-lib=static=wx_baseu-3.3");
     println!("cargo:rustc-link-lib=static=wx_gtk3u_adv-3.3");
     println!("cargo:rustc-link-lib=static=wx_gtk3u_gl-3.3");
@@ -0,0 +1,38 @@
+#ifndef WXD_ACCELERATOR_H
+#define WXD_ACCELERATOR_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// --- Accelerator Table ---
+
+/// Creates an accelerator table from `count` entries
+WXD_EXPORTED wxd_AcceleratorTable_t* wxd_AcceleratorTable_Create(const wxd_AcceleratorEntry* entries, int count);
+
+/// Destroys an accelerator table (windows using it keep their own reference)
+WXD_EXPORTED void wxd_AcceleratorTable_Destroy(wxd_AcceleratorTable_t* table);
+
+/// Returns true if the table is valid
+WXD_EXPORTED bool wxd_AcceleratorTable_IsOk(wxd_AcceleratorTable_t* table);
+
+// --- Accelerator Entry ---
+
+/// Parses a string such as "Ctrl+Shift+P" (or a menu label with a "\t" suffix).
+/// The id of the parsed entry is left unchanged. Returns false if the string is not an accelerator.
+WXD_EXPORTED bool wxd_AcceleratorEntry_FromString(const char* str, wxd_AcceleratorEntry* out_entry);
+
+/// Formats an entry as a string such as "Ctrl+Shift+P". Caller must free with wxd_free_string.
+WXD_EXPORTED char* wxd_AcceleratorEntry_ToString(const wxd_AcceleratorEntry* entry);
+
+/// Formats an entry using untranslated key names, suitable for storing in settings.
+/// Caller must free with wxd_free_string.
+WXD_EXPORTED char* wxd_AcceleratorEntry_ToRawString(const wxd_AcceleratorEntry* entry);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_ACCELERATOR_H
@@ -8,9 +8,25 @@ WXD_EXPORTED wxd_App_t* wxd_GetApp();
 WXD_EXPORTED int wxd_Main(int argc, char** argv, wxd_OnInitCallback on_init, void* userData);
 WXD_EXPORTED void wxd_App_SetTopWindow(wxd_App_t* app, wxd_Window_t* window);
 
+// Application and vendor names, used for settings and standard paths.
+// Getters return strings to be freed with wxd_free_string.
+WXD_EXPORTED void wxd_App_SetAppName(wxd_App_t* app, const char* name);
+WXD_EXPORTED char* wxd_App_GetAppName(wxd_App_t* app);
+WXD_EXPORTED void wxd_App_SetVendorName(wxd_App_t* app, const char* name);
+WXD_EXPORTED char* wxd_App_GetVendorName(wxd_App_t* app);
+
 // Process callback queue
 WXD_EXPORTED void wxd_App_ProcessCallbacks();
 
+// Asks the main event loop to exit; wxd_Main returns once the loop has stopped
+WXD_EXPORTED void wxd_App_ExitMainLoop();
+
+// Wakes the event loop so idle processing runs soon. Safe to call from any thread.
+WXD_EXPORTED void wxd_WakeUpIdle();
+
+// Returns true when called from the thread wxWidgets runs on
+WXD_EXPORTED bool wxd_IsMainThread();
+
 // Utility to free strings allocated by wxDragon C API
 WXD_EXPORTED void wxd_free_string(char* str);
 
@@ -0,0 +1,82 @@
+#ifndef WXD_CONFIG_H
+#define WXD_CONFIG_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Configs created here are wxFileConfig objects; the default global config is
+// the registry on Windows. Keys are relative to the current path unless they
+// start with "/".
+
+// --- Creation ---
+
+/// Creates a config using the default file for the application and vendor names
+/// (which may be empty to use those of the running application)
+WXD_EXPORTED wxd_Config_t* wxd_Config_Create(const char* app_name, const char* vendor_name);
+
+/// Creates a config stored in the file at `path`
+WXD_EXPORTED wxd_Config_t* wxd_Config_CreateWithFile(const char* path);
+
+/// Flushes and destroys a config
+WXD_EXPORTED void wxd_Config_Destroy(wxd_Config_t* config);
+
+/// Makes `config` the global config used by wxWidgets (including window persistence),
+/// which then owns it. Destroys the previous global config unless it is `config`.
+/// `config` may be NULL.
+WXD_EXPORTED void wxd_Config_SetGlobal(wxd_Config_t* config);
+
+/// Gets the global config, creating the default one if there is none
+WXD_EXPORTED wxd_Config_t* wxd_Config_GetGlobal(void);
+
+// --- Paths ---
+
+WXD_EXPORTED void wxd_Config_SetPath(wxd_Config_t* config, const char* path);
+
+/// Caller must free with wxd_free_string
+WXD_EXPORTED char* wxd_Config_GetPath(wxd_Config_t* config);
+
+// --- Reading and Writing ---
+
+/// Reads a string. On success `*out_value` must be freed with wxd_free_string.
+WXD_EXPORTED bool wxd_Config_ReadString(wxd_Config_t* config, const char* key, char** out_value);
+WXD_EXPORTED bool wxd_Config_ReadLong(wxd_Config_t* config, const char* key, int64_t* out_value);
+WXD_EXPORTED bool wxd_Config_ReadDouble(wxd_Config_t* config, const char* key, double* out_value);
+WXD_EXPORTED bool wxd_Config_ReadBool(wxd_Config_t* config, const char* key, bool* out_value);
+
+WXD_EXPORTED bool wxd_Config_WriteString(wxd_Config_t* config, const char* key, const char* value);
+WXD_EXPORTED bool wxd_Config_WriteLong(wxd_Config_t* config, const char* key, int64_t value);
+WXD_EXPORTED bool wxd_Config_WriteDouble(wxd_Config_t* config, const char* key, double value);
+WXD_EXPORTED bool wxd_Config_WriteBool(wxd_Config_t* config, const char* key, bool value);
+
+// --- Enumeration ---
+
+WXD_EXPORTED bool wxd_Config_HasEntry(wxd_Config_t* config, const char* key);
+WXD_EXPORTED bool wxd_Config_HasGroup(wxd_Config_t* config, const char* key);
+
+/// Appends the names of the entries in the current group to `out`
+WXD_EXPORTED void wxd_Config_GetEntries(wxd_Config_t* config, wxd_ArrayString_t* out);
+
+/// Appends the names of the subgroups of the current group to `out`
+WXD_EXPORTED void wxd_Config_GetGroups(wxd_Config_t* config, wxd_ArrayString_t* out);
+
+// --- Deletion and Renaming ---
+
+/// Deletes an entry, and its group too if that leaves it empty and `delete_group_if_empty` is set
+WXD_EXPORTED bool wxd_Config_DeleteEntry(wxd_Config_t* config, const char* key, bool delete_group_if_empty);
+WXD_EXPORTED bool wxd_Config_DeleteGroup(wxd_Config_t* config, const char* key);
+/// Deletes everything, including the file
+WXD_EXPORTED bool wxd_Config_DeleteAll(wxd_Config_t* config);
+WXD_EXPORTED bool wxd_Config_RenameEntry(wxd_Config_t* config, const char* old_name, const char* new_name);
+WXD_EXPORTED bool wxd_Config_RenameGroup(wxd_Config_t* config, const char* old_name, const char* new_name);
+
+/// Writes pending changes to the file
+WXD_EXPORTED bool wxd_Config_Flush(wxd_Config_t* config);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_CONFIG_H
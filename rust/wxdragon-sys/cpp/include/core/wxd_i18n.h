@@ -0,0 +1,74 @@
+#ifndef WXD_I18N_H
+#define WXD_I18N_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// --- UI Locale ---
+
+/// Uses the user's default locale for formatting and the native UI. Returns false on failure.
+WXD_EXPORTED bool wxd_UILocale_UseDefault(void);
+
+/// Gets the name of the current UI locale, e.g. "de_DE". Caller must free with wxd_free_string.
+WXD_EXPORTED char* wxd_UILocale_GetName(void);
+
+// --- Translations ---
+
+/// Creates the global wxTranslations object if there is none. Catalogs are looked
+/// up among the embedded catalogs first, then in the catalog lookup paths.
+WXD_EXPORTED void wxd_Translations_Init(void);
+
+/// Sets the language to translate to, e.g. "fr" or "pt_BR". An empty string
+/// selects the user's preferred languages.
+WXD_EXPORTED void wxd_Translations_SetLanguage(const char* language);
+
+/// Adds a directory searched for <dir>/<lang>/[LC_MESSAGES/]<domain>.mo files
+WXD_EXPORTED void wxd_Translations_AddCatalogLookupPath(const char* dir);
+
+/// Registers a compiled .mo catalog held in memory. `data` is copied.
+WXD_EXPORTED void wxd_Translations_AddEmbeddedCatalog(const char* domain, const char* language, const uint8_t* data, size_t len);
+
+/// Loads the catalog for `domain`. Returns false if there is none for the language.
+WXD_EXPORTED bool wxd_Translations_AddCatalog(const char* domain);
+
+/// Loads the wxWidgets catalog translating standard dialogs and buttons
+WXD_EXPORTED bool wxd_Translations_AddStdCatalog(void);
+
+/// Translates `str`, searching all loaded catalogs if `domain` is NULL or empty.
+/// Caller must free with wxd_free_string.
+WXD_EXPORTED char* wxd_GetTranslation(const char* str, const char* domain);
+
+/// Translates the singular or plural form for `n`. Caller must free with wxd_free_string.
+WXD_EXPORTED char* wxd_GetTranslationPlural(const char* singular, const char* plural, unsigned int n, const char* domain);
+
+// --- Locale-aware Formatting ---
+
+/// Formats a number with the locale's decimal point and, if requested, thousands separators.
+/// A negative precision uses as many digits as needed. Caller must free with wxd_free_string.
+WXD_EXPORTED char* wxd_Locale_FormatNumber(double value, int precision, bool thousands_sep);
+
+/// Formats an integer, optionally with thousands separators. Caller must free with wxd_free_string.
+WXD_EXPORTED char* wxd_Locale_FormatInteger(int64_t value, bool thousands_sep);
+
+/// Parses a number written in the locale's format (thousands separators allowed)
+WXD_EXPORTED bool wxd_Locale_ParseNumber(const char* str, double* out_value);
+
+/// Which part of a date is formatted by wxd_Locale_FormatDateTime
+typedef enum {
+    WXD_DATE_FORMAT_SHORT_DATE = 0,
+    WXD_DATE_FORMAT_LONG_DATE = 1,
+    WXD_DATE_FORMAT_TIME = 2,
+    WXD_DATE_FORMAT_DATE_TIME = 3,
+} wxd_DateFormat;
+
+/// Formats a date and/or time the way the locale does. Caller must free with wxd_free_string.
+WXD_EXPORTED char* wxd_Locale_FormatDateTime(const wxd_DateTime_t* datetime, wxd_DateFormat format);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_I18N_H
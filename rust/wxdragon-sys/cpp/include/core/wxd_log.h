@@ -0,0 +1,51 @@
+#ifndef WXD_LOG_H
+#define WXD_LOG_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Log levels are wxLogLevel values (wxLOG_FatalError = 0 ... wxLOG_Trace = 7).
+
+// --- Log Target ---
+
+/// Makes `callback` the active log target, replacing (and destroying) the current
+/// one, so that no message boxes are shown for logged errors. Passing NULL
+/// restores the default target if the callback target is still the active one.
+/// A log window is never destroyed here: if it was the active target, messages
+/// are passed on to it and it becomes the active target again when restoring.
+WXD_EXPORTED void wxd_Log_SetCallbackTarget(wxd_LogCallback callback);
+
+/// Sets the most verbose level that is logged
+WXD_EXPORTED void wxd_Log_SetLogLevel(int level);
+
+/// Enables wxLogVerbose messages
+WXD_EXPORTED void wxd_Log_SetVerbose(bool verbose);
+WXD_EXPORTED bool wxd_Log_GetVerbose(void);
+
+/// Logs a message at the given level. Can be called from any thread; messages
+/// from other threads are passed to the target from the main thread.
+WXD_EXPORTED void wxd_Log_LogText(int level, const char* message);
+
+/// Sets the first status bar field of `frame` if it still exists and has a status bar.
+/// Must be called from the main thread.
+WXD_EXPORTED void wxd_Log_SetFrameStatus(wxd_Frame_t* frame, const char* message);
+
+// --- Log Window ---
+
+/// Creates a frame showing log messages and makes it the active log target.
+/// With `pass_to_old`, messages are passed on to the previous target too.
+/// The window belongs to the log system, which destroys it at exit and not before.
+WXD_EXPORTED wxd_LogWindow_t* wxd_LogWindow_Create(wxd_Window_t* parent, const char* title, bool show, bool pass_to_old);
+
+WXD_EXPORTED void wxd_LogWindow_Show(wxd_LogWindow_t* log_window, bool show);
+
+WXD_EXPORTED wxd_Frame_t* wxd_LogWindow_GetFrame(wxd_LogWindow_t* log_window);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_LOG_H
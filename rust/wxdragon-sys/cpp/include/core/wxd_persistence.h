@@ -0,0 +1,54 @@
+#ifndef WXD_PERSISTENCE_H
+#define WXD_PERSISTENCE_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Persistence is supported for top-level windows, book controls (notebooks,
+// treebooks, ...), splitter windows, list controls in report mode and data
+// view controls. The window name is used as its key in the store.
+
+// --- Registration ---
+
+/// Result of wxd_Persistence_RegisterAndRestore when the window kind isn't supported
+#define WXD_PERSISTENCE_UNSUPPORTED -1
+
+/// Sets the window name, registers it with the persistence manager and restores
+/// any saved state. Its state is saved automatically when it is destroyed.
+/// Returns 1 if state was restored, 0 if there was none, or WXD_PERSISTENCE_UNSUPPORTED.
+WXD_EXPORTED int wxd_Persistence_RegisterAndRestore(wxd_Window_t* window, const char* name);
+
+/// Saves the current state of a registered window
+WXD_EXPORTED void wxd_Persistence_Save(wxd_Window_t* window);
+
+/// Restores the saved state of a registered window. Returns false if there was none.
+WXD_EXPORTED bool wxd_Persistence_Restore(wxd_Window_t* window);
+
+/// Stops managing a window without saving its state
+WXD_EXPORTED void wxd_Persistence_Unregister(wxd_Window_t* window);
+
+/// Returns true if the window is registered
+WXD_EXPORTED bool wxd_Persistence_IsRegistered(wxd_Window_t* window);
+
+// --- Store ---
+
+// A Rust store receives every value as a string (bools as "0"/"1") under a
+// "/Persistent_Options/<kind>/<name>/<property>" key.
+
+/// Stores state in the application's default wxConfig (the default)
+WXD_EXPORTED void wxd_Persistence_UseDefaultConfig(void);
+
+/// Stores state in a wxFileConfig at `path`
+WXD_EXPORTED void wxd_Persistence_UseConfigFile(const char* path);
+
+/// Stores state through the Rust store callbacks, which own `rust_store`
+WXD_EXPORTED void wxd_Persistence_UseRustStore(void* rust_store);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_PERSISTENCE_H
@@ -0,0 +1,162 @@
+#ifndef WXD_PRINTING_H
+#define WXD_PRINTING_H
+
+#include "../wxd_types.h"
+#include "../graphics/wxd_dc.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct wxd_PrintData_t wxd_PrintData_t;
+typedef struct wxd_PrintDialogData_t wxd_PrintDialogData_t;
+typedef struct wxd_PageSetupDialogData_t wxd_PageSetupDialogData_t;
+typedef struct wxd_Printout_t wxd_Printout_t;
+typedef struct wxd_Printer_t wxd_Printer_t;
+
+typedef enum {
+    WXD_PRINT_PORTRAIT = 0,
+    WXD_PRINT_LANDSCAPE = 1
+} wxd_PrintOrientation;
+
+typedef enum {
+    WXD_PRINT_MODE_NONE = 0,
+    WXD_PRINT_MODE_PREVIEW = 1,
+    WXD_PRINT_MODE_FILE = 2,
+    WXD_PRINT_MODE_PRINTER = 3
+} wxd_PrintMode;
+
+typedef enum {
+    WXD_PRINTER_NO_ERROR = 0,
+    WXD_PRINTER_CANCELLED = 1,
+    WXD_PRINTER_ERROR = 2
+} wxd_PrinterError;
+
+// --- Print data (printer settings) ---
+
+WXD_EXPORTED wxd_PrintData_t* wxd_PrintData_Create(void);
+WXD_EXPORTED wxd_PrintData_t* wxd_PrintData_Clone(const wxd_PrintData_t* data);
+WXD_EXPORTED void wxd_PrintData_Destroy(wxd_PrintData_t* data);
+WXD_EXPORTED bool wxd_PrintData_IsOk(const wxd_PrintData_t* data);
+WXD_EXPORTED void wxd_PrintData_SetOrientation(wxd_PrintData_t* data, wxd_PrintOrientation orientation);
+WXD_EXPORTED wxd_PrintOrientation wxd_PrintData_GetOrientation(const wxd_PrintData_t* data);
+WXD_EXPORTED void wxd_PrintData_SetPrintMode(wxd_PrintData_t* data, wxd_PrintMode mode);
+WXD_EXPORTED wxd_PrintMode wxd_PrintData_GetPrintMode(const wxd_PrintData_t* data);
+// Output file used with WXD_PRINT_MODE_FILE
+WXD_EXPORTED void wxd_PrintData_SetFilename(wxd_PrintData_t* data, const char* filename);
+// Returns a string to be freed with wxd_free_string
+WXD_EXPORTED char* wxd_PrintData_GetFilename(const wxd_PrintData_t* data);
+WXD_EXPORTED void wxd_PrintData_SetNoCopies(wxd_PrintData_t* data, int copies);
+WXD_EXPORTED int wxd_PrintData_GetNoCopies(const wxd_PrintData_t* data);
+WXD_EXPORTED void wxd_PrintData_SetColour(wxd_PrintData_t* data, bool colour);
+WXD_EXPORTED bool wxd_PrintData_GetColour(const wxd_PrintData_t* data);
+
+// --- Print dialog data (page range and settings shown in the print dialog) ---
+
+// data may be NULL for default printer settings
+WXD_EXPORTED wxd_PrintDialogData_t* wxd_PrintDialogData_Create(const wxd_PrintData_t* data);
+WXD_EXPORTED wxd_PrintDialogData_t* wxd_PrintDialogData_Clone(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_Destroy(wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_SetFromPage(wxd_PrintDialogData_t* data, int page);
+WXD_EXPORTED int wxd_PrintDialogData_GetFromPage(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_SetToPage(wxd_PrintDialogData_t* data, int page);
+WXD_EXPORTED int wxd_PrintDialogData_GetToPage(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_SetMinPage(wxd_PrintDialogData_t* data, int page);
+WXD_EXPORTED int wxd_PrintDialogData_GetMinPage(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_SetMaxPage(wxd_PrintDialogData_t* data, int page);
+WXD_EXPORTED int wxd_PrintDialogData_GetMaxPage(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_SetNoCopies(wxd_PrintDialogData_t* data, int copies);
+WXD_EXPORTED int wxd_PrintDialogData_GetNoCopies(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_SetAllPages(wxd_PrintDialogData_t* data, bool all_pages);
+WXD_EXPORTED bool wxd_PrintDialogData_GetAllPages(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_SetSelection(wxd_PrintDialogData_t* data, bool selection);
+WXD_EXPORTED bool wxd_PrintDialogData_GetSelection(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_EnableSelection(wxd_PrintDialogData_t* data, bool enable);
+WXD_EXPORTED void wxd_PrintDialogData_EnablePageNumbers(wxd_PrintDialogData_t* data, bool enable);
+WXD_EXPORTED void wxd_PrintDialogData_EnablePrintToFile(wxd_PrintDialogData_t* data, bool enable);
+// Returns a copy to be destroyed with wxd_PrintData_Destroy
+WXD_EXPORTED wxd_PrintData_t* wxd_PrintDialogData_GetPrintData(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_PrintDialogData_SetPrintData(wxd_PrintDialogData_t* data, const wxd_PrintData_t* print_data);
+
+// --- Page setup data (paper and margins, in millimetres) ---
+
+// data may be NULL for default printer settings
+WXD_EXPORTED wxd_PageSetupDialogData_t* wxd_PageSetupDialogData_Create(const wxd_PrintData_t* data);
+WXD_EXPORTED wxd_PageSetupDialogData_t* wxd_PageSetupDialogData_Clone(const wxd_PageSetupDialogData_t* data);
+WXD_EXPORTED void wxd_PageSetupDialogData_Destroy(wxd_PageSetupDialogData_t* data);
+WXD_EXPORTED void wxd_PageSetupDialogData_SetMarginTopLeft(wxd_PageSetupDialogData_t* data, wxd_Point margin);
+WXD_EXPORTED wxd_Point wxd_PageSetupDialogData_GetMarginTopLeft(const wxd_PageSetupDialogData_t* data);
+WXD_EXPORTED void wxd_PageSetupDialogData_SetMarginBottomRight(wxd_PageSetupDialogData_t* data, wxd_Point margin);
+WXD_EXPORTED wxd_Point wxd_PageSetupDialogData_GetMarginBottomRight(const wxd_PageSetupDialogData_t* data);
+WXD_EXPORTED wxd_Size wxd_PageSetupDialogData_GetPaperSize(const wxd_PageSetupDialogData_t* data);
+// Returns a copy to be destroyed with wxd_PrintData_Destroy
+WXD_EXPORTED wxd_PrintData_t* wxd_PageSetupDialogData_GetPrintData(const wxd_PageSetupDialogData_t* data);
+WXD_EXPORTED void wxd_PageSetupDialogData_SetPrintData(wxd_PageSetupDialogData_t* data, const wxd_PrintData_t* print_data);
+
+// --- Dialogs (modal; the data is updated when they return true) ---
+
+WXD_EXPORTED bool wxd_PrintDialog_ShowModal(wxd_Window_t* parent, wxd_PrintDialogData_t* data);
+WXD_EXPORTED bool wxd_PageSetupDialog_ShowModal(wxd_Window_t* parent, wxd_PageSetupDialogData_t* data);
+
+// --- Printout (pages drawn by Rust callbacks) ---
+
+// handler is passed to the rust_printout_* callbacks and dropped with rust_printout_drop
+WXD_EXPORTED wxd_Printout_t* wxd_Printout_Create(void* handler, const char* title);
+WXD_EXPORTED void wxd_Printout_Destroy(wxd_Printout_t* printout);
+// The DC to draw on; only valid while printing or previewing
+WXD_EXPORTED wxd_DC_t* wxd_Printout_GetDC(wxd_Printout_t* printout);
+WXD_EXPORTED bool wxd_Printout_IsPreview(wxd_Printout_t* printout);
+
+// Set the DC's scale and origin so that an image of the given size fits, centred
+WXD_EXPORTED void wxd_Printout_FitThisSizeToPaper(wxd_Printout_t* printout, wxd_Size size);
+WXD_EXPORTED void wxd_Printout_FitThisSizeToPage(wxd_Printout_t* printout, wxd_Size size);
+WXD_EXPORTED void wxd_Printout_FitThisSizeToPageMargins(wxd_Printout_t* printout, wxd_Size size,
+                                                        const wxd_PageSetupDialogData_t* page_setup);
+// Set the DC's scale so that screen-sized drawing has the same size on paper
+WXD_EXPORTED void wxd_Printout_MapScreenSizeToPaper(wxd_Printout_t* printout);
+WXD_EXPORTED void wxd_Printout_MapScreenSizeToPage(wxd_Printout_t* printout);
+WXD_EXPORTED void wxd_Printout_MapScreenSizeToPageMargins(wxd_Printout_t* printout,
+                                                          const wxd_PageSetupDialogData_t* page_setup);
+// Set the DC's scale so that one logical unit is one device pixel
+WXD_EXPORTED void wxd_Printout_MapScreenSizeToDevice(wxd_Printout_t* printout);
+
+// Areas in logical coordinates of the current scale and origin
+WXD_EXPORTED wxd_Rect wxd_Printout_GetLogicalPaperRect(wxd_Printout_t* printout);
+WXD_EXPORTED wxd_Rect wxd_Printout_GetLogicalPageRect(wxd_Printout_t* printout);
+WXD_EXPORTED wxd_Rect wxd_Printout_GetLogicalPageMarginsRect(wxd_Printout_t* printout,
+                                                            const wxd_PageSetupDialogData_t* page_setup);
+WXD_EXPORTED void wxd_Printout_OffsetLogicalOrigin(wxd_Printout_t* printout, int dx, int dy);
+
+WXD_EXPORTED wxd_Size wxd_Printout_GetPageSizePixels(wxd_Printout_t* printout);
+WXD_EXPORTED wxd_Size wxd_Printout_GetPageSizeMM(wxd_Printout_t* printout);
+WXD_EXPORTED wxd_Size wxd_Printout_GetPPIPrinter(wxd_Printout_t* printout);
+WXD_EXPORTED wxd_Size wxd_Printout_GetPPIScreen(wxd_Printout_t* printout);
+
+// --- Printer ---
+
+// data may be NULL for default settings
+WXD_EXPORTED wxd_Printer_t* wxd_Printer_Create(const wxd_PrintDialogData_t* data);
+WXD_EXPORTED void wxd_Printer_Destroy(wxd_Printer_t* printer);
+// Prints synchronously; the printout stays owned by the caller
+WXD_EXPORTED bool wxd_Printer_Print(wxd_Printer_t* printer, wxd_Window_t* parent, wxd_Printout_t* printout, bool prompt);
+WXD_EXPORTED wxd_PrinterError wxd_Printer_GetLastError(void);
+// Returns a copy of the settings chosen in the print dialog, to be destroyed by the caller
+WXD_EXPORTED wxd_PrintDialogData_t* wxd_Printer_GetPrintDialogData(wxd_Printer_t* printer);
+
+// --- Print preview ---
+
+// Shows a preview frame. Takes ownership of both printouts (print_printout may be
+// NULL to disable printing from the preview), even when it fails and returns false.
+WXD_EXPORTED bool wxd_PrintPreview_Show(wxd_Printout_t* preview_printout, wxd_Printout_t* print_printout,
+                                        const wxd_PrintDialogData_t* data, wxd_Window_t* parent,
+                                        const char* title, wxd_Size size);
+
+// Use the PostScript printing backend instead of the GTK one, so that output can
+// go to a file without a print dialog. Does nothing on other platforms.
+WXD_EXPORTED void wxd_Printing_UsePostScript(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WXD_PRINTING_H
@@ -0,0 +1,47 @@
+#ifndef WXD_PROCESS_H
+#define WXD_PROCESS_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Output streams passed to rust_process_on_output
+#define WXD_PROCESS_STDOUT 0
+#define WXD_PROCESS_STDERR 1
+
+// Creates a process object with redirected standard streams. `handler` is passed
+// to the rust_process_* callbacks and dropped with rust_process_drop.
+WXD_EXPORTED wxd_Process_t* wxd_Process_Create(void* handler);
+
+// Starts `argv[0]` with the given arguments. `cwd` may be NULL to use the current
+// directory; the `env_count` variables are added to the inherited environment.
+// Returns the process ID, or 0 if it could not be started.
+WXD_EXPORTED long wxd_Process_Execute(wxd_Process_t* process, const char* const* argv, int argc,
+                                      const char* cwd, const char* const* env_names,
+                                      const char* const* env_values, int env_count);
+
+WXD_EXPORTED long wxd_Process_GetPid(wxd_Process_t* process);
+WXD_EXPORTED bool wxd_Process_IsRunning(wxd_Process_t* process);
+
+// Queues data for the process's standard input, which is written without blocking
+// as the process reads it. Returns false if the process isn't running or standard
+// input is closed (or about to be).
+WXD_EXPORTED bool wxd_Process_WriteStdin(wxd_Process_t* process, const uint8_t* data, size_t len);
+// Closes standard input once the queued data has been written
+WXD_EXPORTED void wxd_Process_CloseStdin(wxd_Process_t* process);
+
+// Sends a wxSignal (e.g. 15 for wxSIGTERM, 9 for wxSIGKILL) to the process and its children.
+// Returns true on success.
+WXD_EXPORTED bool wxd_Process_Kill(wxd_Process_t* process, int signal);
+
+// Gives up the handle. A running process keeps reporting output and is
+// destroyed once it has terminated.
+WXD_EXPORTED void wxd_Process_Release(wxd_Process_t* process);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_PROCESS_H
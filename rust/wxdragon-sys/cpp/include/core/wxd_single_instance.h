@@ -0,0 +1,29 @@
+#ifndef WXD_SINGLE_INSTANCE_H
+#define WXD_SINGLE_INSTANCE_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Returns true if another process of the current user holds the lock for `name`.
+// The first call takes the lock if it is free; it is held until wxd_SingleInstance_Release.
+WXD_EXPORTED bool wxd_SingleInstance_IsAnotherRunning(const char* name);
+
+// Sends `data` to the instance listening under `name`. Returns false if it could not be reached.
+WXD_EXPORTED bool wxd_SingleInstance_Forward(const char* name, const char* data, size_t len);
+
+// Starts listening for data sent by other instances, replacing any previous listener.
+// `handler` is passed to rust_single_instance_on_args for each message and to
+// rust_single_instance_drop when the listener is destroyed, also if this fails.
+WXD_EXPORTED bool wxd_SingleInstance_Listen(const char* name, void* handler);
+
+// Stops listening and releases the lock. Called when the application exits.
+WXD_EXPORTED void wxd_SingleInstance_Release(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_SINGLE_INSTANCE_H
@@ -0,0 +1,39 @@
+#ifndef WXD_STDPATHS_H
+#define WXD_STDPATHS_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// All functions return an empty string if the application has not been created
+// yet. Returned strings must be freed with wxd_free_string.
+
+WXD_EXPORTED char* wxd_StandardPaths_GetUserConfigDir(void);
+WXD_EXPORTED char* wxd_StandardPaths_GetUserDataDir(void);
+WXD_EXPORTED char* wxd_StandardPaths_GetUserLocalDataDir(void);
+WXD_EXPORTED char* wxd_StandardPaths_GetUserCacheDir(void);
+WXD_EXPORTED char* wxd_StandardPaths_GetDocumentsDir(void);
+WXD_EXPORTED char* wxd_StandardPaths_GetTempDir(void);
+WXD_EXPORTED char* wxd_StandardPaths_GetExecutablePath(void);
+WXD_EXPORTED char* wxd_StandardPaths_GetResourcesDir(void);
+WXD_EXPORTED char* wxd_StandardPaths_GetDataDir(void);
+
+// Path of the file used by wxd_Config_Create for the current application name
+WXD_EXPORTED char* wxd_StandardPaths_GetConfigFilePath(void);
+
+typedef enum {
+    WXD_FILE_LAYOUT_CLASSIC = 0,  // wxStandardPaths::FileLayout_Classic
+    WXD_FILE_LAYOUT_XDG = 1       // wxStandardPaths::FileLayout_XDG
+} wxd_FileLayout;
+
+// Only has an effect on Unix systems other than macOS
+WXD_EXPORTED void wxd_StandardPaths_SetFileLayout(wxd_FileLayout layout);
+WXD_EXPORTED wxd_FileLayout wxd_StandardPaths_GetFileLayout(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_STDPATHS_H
@@ -0,0 +1,59 @@
+#ifndef WXD_VALIDATOR_H
+#define WXD_VALIDATOR_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Validators carry an opaque Rust data pointer that provides the optional
+// check closure and bound value. Copies made by wxWidgets share it through the
+// Rust clone/drop callbacks.
+
+// --- Validator Creation ---
+
+/// Creates a wxTextValidator with the given wxFILTER_* style
+WXD_EXPORTED wxd_Validator_t* wxd_Validator_CreateText(int64_t style, void* rust_data);
+
+/// Creates a wxIntegerValidator with the given wxNUM_VAL_* style and range
+WXD_EXPORTED wxd_Validator_t* wxd_Validator_CreateInteger(int style, int64_t min, int64_t max, void* rust_data);
+
+/// Creates a wxFloatingPointValidator with the given wxNUM_VAL_* style, precision and range
+WXD_EXPORTED wxd_Validator_t* wxd_Validator_CreateFloat(int style, int precision, double min, double max, void* rust_data);
+
+/// Creates a validator that only runs the Rust check
+WXD_EXPORTED wxd_Validator_t* wxd_Validator_CreateCustom(void* rust_data);
+
+/// Destroys a validator (windows using it keep their own copy)
+WXD_EXPORTED void wxd_Validator_Destroy(wxd_Validator_t* validator);
+
+// --- Validator Configuration ---
+
+/// Sets the characters accepted (wxFILTER_INCLUDE_CHAR_LIST) by a text validator
+WXD_EXPORTED void wxd_TextValidator_SetCharIncludes(wxd_Validator_t* validator, const char* chars);
+
+/// Sets the characters rejected (wxFILTER_EXCLUDE_CHAR_LIST) by a text validator
+WXD_EXPORTED void wxd_TextValidator_SetCharExcludes(wxd_Validator_t* validator, const char* chars);
+
+/// Sets the wxNUM_VAL_* style of an integer or floating point validator
+WXD_EXPORTED void wxd_NumValidator_SetStyle(wxd_Validator_t* validator, int style);
+
+/// Returns 1 if `text` matches `pattern` as a whole, 0 if not and -1 if the pattern is invalid
+WXD_EXPORTED int wxd_Validator_RegexMatches(const char* pattern, const char* text);
+
+// --- Window Integration ---
+
+/// Sets the window's validator (the window stores a copy). Returns false
+/// without setting it if a text or numeric validator is given a window that
+/// isn't a text entry.
+WXD_EXPORTED bool wxd_Window_SetValidator(wxd_Window_t* window, wxd_Validator_t* validator);
+WXD_EXPORTED bool wxd_Window_Validate(wxd_Window_t* window);
+WXD_EXPORTED bool wxd_Window_TransferDataToWindow(wxd_Window_t* window);
+WXD_EXPORTED bool wxd_Window_TransferDataFromWindow(wxd_Window_t* window);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // WXD_VALIDATOR_H
@@ -68,6 +68,9 @@ WXD_EXPORTED void wxd_Window_SetExtraStyle(wxd_Window_t *window,
                                            int64_t exStyle);
 WXD_EXPORTED int64_t wxd_Window_GetExtraStyle(wxd_Window_t *window);
 
+// Sends wxEVT_UPDATE_UI to the window and its children
+WXD_EXPORTED void wxd_Window_UpdateWindowUI(wxd_Window_t *window);
+
 // Color management functions
 WXD_EXPORTED void wxd_Window_SetForegroundColor(wxd_Window_t *window,
                                                 wxd_Colour_t color);
@@ -104,6 +107,22 @@ WXD_EXPORTED void wxd_Window_SetCursor(wxd_Window_t *window,
                                        wxd_Cursor_t *cursor);
 WXD_EXPORTED wxd_Cursor_t *wxd_Window_GetCursor(wxd_Window_t *window);
 
+// --- Accelerator Functions ---
+WXD_EXPORTED void wxd_Window_SetAcceleratorTable(wxd_Window_t *window,
+                                                 wxd_AcceleratorTable_t *table);
+
+// --- DPI Functions ---
+// Conversions between device-independent and physical pixels. `window` may be
+// NULL to use the DPI of the primary display. Components equal to -1 are kept.
+WXD_EXPORTED wxd_Size wxd_Window_FromDIPSize(wxd_Window_t *window, wxd_Size size);
+WXD_EXPORTED wxd_Size wxd_Window_ToDIPSize(wxd_Window_t *window, wxd_Size size);
+WXD_EXPORTED wxd_Point wxd_Window_FromDIPPoint(wxd_Window_t *window, wxd_Point point);
+WXD_EXPORTED wxd_Point wxd_Window_ToDIPPoint(wxd_Window_t *window, wxd_Point point);
+WXD_EXPORTED int wxd_Window_FromDIPInt(wxd_Window_t *window, int value);
+WXD_EXPORTED int wxd_Window_ToDIPInt(wxd_Window_t *window, int value);
+WXD_EXPORTED double wxd_Window_GetDPIScaleFactor(wxd_Window_t *window);
+WXD_EXPORTED wxd_Size wxd_Window_GetDPI(wxd_Window_t *window);
+
 // --- Z-Order Management Functions ---
 WXD_EXPORTED void wxd_Window_Raise(wxd_Window_t *window);
 WXD_EXPORTED void wxd_Window_Lower(wxd_Window_t *window);
@@ -146,6 +165,16 @@ WXD_EXPORTED int64_t wxd_Window_GetLastPosition(wxd_Window_t *window);
 
 // Widget type identification using wxWidgets' built-in RTTI
 WXD_EXPORTED const char *wxd_Window_GetClassName(wxd_Window_t *window);
+// True if the window's class is `class_name` or derives from it
+WXD_EXPORTED bool wxd_Window_IsKindOf(wxd_Window_t *window,
+                                      const char *class_name);
+
+// --- Child Enumeration ---
+// Copies up to `max_count` child pointers into `out_children` and returns the
+// total number of children. Pass NULL/0 to query the count.
+WXD_EXPORTED int wxd_Window_GetChildren(wxd_Window_t *window,
+                                        wxd_Window_t **out_children,
+                                        int max_count);
 
 // --- Tab Order Functions ---
 WXD_EXPORTED void wxd_Window_MoveAfterInTabOrder(wxd_Window_t *window,
@@ -13,6 +13,12 @@ WXD_EXPORTED wxd_XmlResource_t* wxd_XmlResource_Get(void);
 // Initialize all standard handlers
 WXD_EXPORTED void wxd_XmlResource_InitAllHandlers(wxd_XmlResource_t* self);
 
+// Set the message catalog domain used to translate labels
+WXD_EXPORTED void wxd_XmlResource_SetDomain(wxd_XmlResource_t* self, const char* domain);
+
+// Enable or disable translation of labels (enabled by default)
+WXD_EXPORTED void wxd_XmlResource_SetUseLocale(wxd_XmlResource_t* self, bool use_locale);
+
 // Load XRC from file
 WXD_EXPORTED bool wxd_XmlResource_LoadFromFile(wxd_XmlResource_t* self, const char* filemask);
 
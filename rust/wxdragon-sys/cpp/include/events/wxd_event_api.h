@@ -10,15 +10,22 @@ extern "C" {
 typedef int wxEventType;
 
 // --- Event Handling & Data Access --- 
-WXD_EXPORTED void wxd_EvtHandler_Bind(
+// Returns false if the closure could not be bound; it has already been released via
+// drop_rust_closure_box in that case.
+WXD_EXPORTED bool wxd_EvtHandler_Bind(
     wxd_EvtHandler_t* handler, 
     WXDEventTypeCEnum eventTypeC,
     void* rust_trampoline_fn,          
     void* rust_closure_ptr             
 );
 
-// ID-specific event binding for tools and menu items
-WXD_EXPORTED void wxd_EvtHandler_BindWithId(wxd_EvtHandler_t* evt_handler, WXDEventTypeCEnum event_type, int id, void* callback, void* user_data);
+// ID-specific event binding for tools and menu items. Returns false like wxd_EvtHandler_Bind.
+WXD_EXPORTED bool wxd_EvtHandler_BindWithId(wxd_EvtHandler_t* evt_handler, WXDEventTypeCEnum event_type, int id, void* callback, void* user_data);
+
+// Removes a handler bound with wxd_EvtHandler_Bind (pass id = wxID_ANY) or wxd_EvtHandler_BindWithId.
+// The closure is identified by the pointer passed when binding and is released via drop_rust_closure_box.
+// Returns false if no such binding exists.
+WXD_EXPORTED bool wxd_EvtHandler_Unbind(wxd_EvtHandler_t* evt_handler, WXDEventTypeCEnum event_type, int id, void* user_data);
 
 WXD_EXPORTED int wxd_Event_GetId(wxd_Event_t* event);
 WXD_EXPORTED wxd_Window_t* wxd_Event_GetEventObject(wxd_Event_t* event);
@@ -118,9 +125,52 @@ WXD_EXPORTED bool wxd_IdleEvent_MoreRequested(wxd_Event_t* event);
 WXD_EXPORTED void wxd_IdleEvent_SetMode(int mode);
 WXD_EXPORTED int wxd_IdleEvent_GetMode();
 
+// --- UpdateUI Event Specific Methods ---
+WXD_EXPORTED void wxd_UpdateUIEvent_Enable(wxd_Event_t* event, bool enable);
+WXD_EXPORTED void wxd_UpdateUIEvent_Check(wxd_Event_t* event, bool check);
+WXD_EXPORTED void wxd_UpdateUIEvent_SetText(wxd_Event_t* event, const char* text);
+WXD_EXPORTED void wxd_UpdateUIEvent_Show(wxd_Event_t* event, bool show);
+WXD_EXPORTED bool wxd_UpdateUIEvent_GetEnabled(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_UpdateUIEvent_GetChecked(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_UpdateUIEvent_GetShown(wxd_Event_t* event);
+WXD_EXPORTED void wxd_UpdateUIEvent_SetMode(int mode);
+WXD_EXPORTED int wxd_UpdateUIEvent_GetMode();
+WXD_EXPORTED void wxd_UpdateUIEvent_SetUpdateInterval(int64_t interval_ms);
+WXD_EXPORTED int64_t wxd_UpdateUIEvent_GetUpdateInterval();
+
 // Mouse wheel event functions
 WXD_EXPORTED int wxd_MouseEvent_GetWheelRotation(wxd_Event_t* event);
 WXD_EXPORTED int wxd_MouseEvent_GetWheelDelta(wxd_Event_t* event);
+// Returns 0 for the vertical and 1 for the horizontal wheel axis (wxMouseWheelAxis)
+WXD_EXPORTED int wxd_MouseEvent_GetWheelAxis(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_MouseEvent_IsWheelInverted(wxd_Event_t* event);
+WXD_EXPORTED int wxd_MouseEvent_GetLinesPerAction(wxd_Event_t* event);
+
+// Mouse button and state functions. Buttons use the wxMOUSE_BTN_* values.
+WXD_EXPORTED int wxd_MouseEvent_GetButton(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_MouseEvent_ButtonDown(wxd_Event_t* event, int button);
+WXD_EXPORTED bool wxd_MouseEvent_ButtonUp(wxd_Event_t* event, int button);
+WXD_EXPORTED bool wxd_MouseEvent_ButtonDClick(wxd_Event_t* event, int button);
+WXD_EXPORTED bool wxd_MouseEvent_ButtonIsDown(wxd_Event_t* event, int button);
+WXD_EXPORTED int wxd_MouseEvent_GetClickCount(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_MouseEvent_Dragging(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_MouseEvent_Moving(wxd_Event_t* event);
+// Returns the wxMOD_* flags of the modifier keys held during the event
+WXD_EXPORTED int wxd_MouseEvent_GetModifiers(wxd_Event_t* event);
+// Returns false if event is not a mouse event or dc is NULL. Negative positions are valid.
+WXD_EXPORTED bool wxd_MouseEvent_GetLogicalPosition(wxd_Event_t* event, wxd_DC_t* dc, wxd_Point* out_pos);
+
+// Keyboard event accessors
+WXD_EXPORTED uint32_t wxd_KeyEvent_GetRawKeyCode(wxd_Event_t* event);
+WXD_EXPORTED uint32_t wxd_KeyEvent_GetRawKeyFlags(wxd_Event_t* event);
+WXD_EXPORTED int wxd_KeyEvent_GetModifiers(wxd_Event_t* event);
+WXD_EXPORTED bool wxd_KeyEvent_IsKeyInCategory(wxd_Event_t* event, int category);
+WXD_EXPORTED void wxd_KeyEvent_DoAllowNextEvent(wxd_Event_t* event);
+
+// DPI changed event accessors
+WXD_EXPORTED wxd_Size wxd_DPIChangedEvent_GetOldDPI(wxd_Event_t* event);
+WXD_EXPORTED wxd_Size wxd_DPIChangedEvent_GetNewDPI(wxd_Event_t* event);
+WXD_EXPORTED wxd_Size wxd_DPIChangedEvent_Scale(wxd_Event_t* event, wxd_Size size);
 
 // General veto support for all event types (replaces old close event specific functions)
 WXD_EXPORTED bool wxd_Event_CanVeto(wxd_Event_t* event);
@@ -135,6 +185,29 @@ WXD_EXPORTED bool wxd_MenuEvent_IsPopup(wxd_Event_t* event);
 // NEW: Context menu event specific accessors
 WXD_EXPORTED wxd_Point wxd_ContextMenuEvent_GetPosition(wxd_Event_t* event);
 
+// --- Custom (user-defined) events ---
+// Registers (or looks up) a custom event type by name. The same name always maps to the same value.
+// Safe to call from any thread.
+WXD_EXPORTED WXDEventTypeCEnum wxd_Event_RegisterCustomType(const char* name);
+
+// Returns the generation of the Rust event handler attached to `handler`, attaching one if needed.
+// A generation identifies one handler object and is never reused, even if its address is.
+// Returns 0 for a null handler. GUI thread only.
+WXD_EXPORTED uint64_t wxd_EvtHandler_GetGeneration(wxd_EvtHandler_t* handler);
+
+// Queues a custom event carrying a Rust payload for delivery to `handler` on the GUI thread.
+// The event is dropped if `handler` no longer has the given generation by then.
+// Safe to call from any thread. Ownership of `rust_payload_ptr` is transferred to C++ and it is
+// released with drop_rust_event_payload once the event is gone. Returns false if the event could
+// not be queued (no application object, zero generation or unknown event type); the payload is dropped in that case.
+WXD_EXPORTED bool wxd_EvtHandler_QueueCustomEvent(wxd_EvtHandler_t* handler, uint64_t generation, WXDEventTypeCEnum eventTypeC, void* rust_payload_ptr);
+
+// Returns the Rust payload pointer of a custom event, or NULL if the event is not a custom event.
+WXD_EXPORTED void* wxd_CustomEvent_GetPayload(wxd_Event_t* event);
+
+// Rust callback for dropping a custom event payload
+WXD_EXPORTED void drop_rust_event_payload(void* ptr);
+
 #ifdef __cplusplus
 }
 #endif
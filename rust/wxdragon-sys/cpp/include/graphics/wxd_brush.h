@@ -0,0 +1,33 @@
+#ifndef WXD_BRUSH_H
+#define WXD_BRUSH_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Opaque type for wxBrush
+typedef struct wxd_Brush_t wxd_Brush_t;
+
+// style is a WXD_BRUSHSTYLE_* value
+WXD_EXPORTED wxd_Brush_t* wxd_Brush_Create(wxd_Colour_t colour, int style);
+// Creates a brush filling with the bitmap repeated
+WXD_EXPORTED wxd_Brush_t* wxd_Brush_CreateFromStipple(const wxd_Bitmap_t* bitmap);
+WXD_EXPORTED wxd_Brush_t* wxd_Brush_Clone(const wxd_Brush_t* brush);
+WXD_EXPORTED void wxd_Brush_Destroy(wxd_Brush_t* brush);
+WXD_EXPORTED bool wxd_Brush_IsOk(const wxd_Brush_t* brush);
+
+WXD_EXPORTED void wxd_Brush_SetColour(wxd_Brush_t* brush, wxd_Colour_t colour);
+WXD_EXPORTED wxd_Colour_t wxd_Brush_GetColour(const wxd_Brush_t* brush);
+WXD_EXPORTED void wxd_Brush_SetStyle(wxd_Brush_t* brush, int style);
+WXD_EXPORTED int wxd_Brush_GetStyle(const wxd_Brush_t* brush);
+// Also sets the style to WXD_BRUSHSTYLE_STIPPLE, or a STIPPLE_MASK style if the bitmap has a mask
+WXD_EXPORTED void wxd_Brush_SetStipple(wxd_Brush_t* brush, const wxd_Bitmap_t* bitmap);
+WXD_EXPORTED bool wxd_Brush_IsHatch(const wxd_Brush_t* brush);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WXD_BRUSH_H
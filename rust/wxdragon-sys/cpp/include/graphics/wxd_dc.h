@@ -2,6 +2,8 @@
 #define WXD_DC_H
 
 #include "../wxd_types.h"
+#include "wxd_pen.h"
+#include "wxd_brush.h"
 
 #ifdef __cplusplus
 extern "C" {
@@ -15,6 +17,7 @@ typedef struct wxd_PaintDC_t wxd_PaintDC_t;
 typedef struct wxd_MemoryDC_t wxd_MemoryDC_t;
 typedef struct wxd_ScreenDC_t wxd_ScreenDC_t;
 typedef struct wxd_AutoBufferedPaintDC_t wxd_AutoBufferedPaintDC_t;
+typedef struct wxd_SVGFileDC_t wxd_SVGFileDC_t;
 
 // DC Creation/Destruction
 WXD_EXPORTED wxd_WindowDC_t* wxd_WindowDC_Create(wxd_Window_t* window);
@@ -35,6 +38,10 @@ WXD_EXPORTED void wxd_ScreenDC_Destroy(wxd_ScreenDC_t* dc);
 WXD_EXPORTED wxd_AutoBufferedPaintDC_t* wxd_AutoBufferedPaintDC_Create(wxd_Window_t* window);
 WXD_EXPORTED void wxd_AutoBufferedPaintDC_Destroy(wxd_AutoBufferedPaintDC_t* dc);
 
+// Returns NULL if the file can't be created. The file is complete once the DC is destroyed.
+WXD_EXPORTED wxd_SVGFileDC_t* wxd_SVGFileDC_Create(const char* filename, int width, int height, double dpi, const char* title);
+WXD_EXPORTED void wxd_SVGFileDC_Destroy(wxd_SVGFileDC_t* dc);
+
 // Common DC operations (operates on the base type)
 WXD_EXPORTED void wxd_DC_Clear(wxd_DC_t* dc);
 WXD_EXPORTED void wxd_DC_SetBackground(wxd_DC_t* dc, wxd_Colour_t background);
@@ -42,8 +49,8 @@ WXD_EXPORTED void wxd_DC_SetBackgroundMode(wxd_DC_t* dc, int mode); // WXD_TRANS
 WXD_EXPORTED void wxd_DC_SetTextBackground(wxd_DC_t* dc, wxd_Colour_t colour);
 WXD_EXPORTED void wxd_DC_SetTextForeground(wxd_DC_t* dc, wxd_Colour_t colour);
 WXD_EXPORTED void wxd_DC_SetFont(wxd_DC_t* dc, const wxd_Font_t* font);
-WXD_EXPORTED void wxd_DC_SetPen(wxd_DC_t* dc, wxd_Colour_t colour, int width, int style);
-WXD_EXPORTED void wxd_DC_SetBrush(wxd_DC_t* dc, wxd_Colour_t colour, int style);
+WXD_EXPORTED void wxd_DC_SetPen(wxd_DC_t* dc, const wxd_Pen_t* pen);
+WXD_EXPORTED void wxd_DC_SetBrush(wxd_DC_t* dc, const wxd_Brush_t* brush);
 
 // Basic drawing operations
 WXD_EXPORTED void wxd_DC_DrawPoint(wxd_DC_t* dc, int x, int y);
@@ -145,6 +152,7 @@ WXD_EXPORTED wxd_DC_t* wxd_PaintDC_AsDC(wxd_PaintDC_t* dc);
 WXD_EXPORTED wxd_DC_t* wxd_MemoryDC_AsDC(wxd_MemoryDC_t* dc);
 WXD_EXPORTED wxd_DC_t* wxd_ScreenDC_AsDC(wxd_ScreenDC_t* dc);
 WXD_EXPORTED wxd_DC_t* wxd_AutoBufferedPaintDC_AsDC(wxd_AutoBufferedPaintDC_t* dc);
+WXD_EXPORTED wxd_DC_t* wxd_SVGFileDC_AsDC(wxd_SVGFileDC_t* dc);
 
 #ifdef __cplusplus
 } // extern "C"
@@ -0,0 +1,132 @@
+#ifndef WXD_GRAPHICS_H
+#define WXD_GRAPHICS_H
+
+#include "../wxd_types.h"
+#include "wxd_dc.h"
+#include "wxd_pen.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Opaque graphics types
+typedef struct wxd_GraphicsContext_t wxd_GraphicsContext_t;
+typedef struct wxd_GraphicsPath_t wxd_GraphicsPath_t;
+typedef struct wxd_GraphicsPen_t wxd_GraphicsPen_t;
+typedef struct wxd_GraphicsBrush_t wxd_GraphicsBrush_t;
+typedef struct wxd_GraphicsFont_t wxd_GraphicsFont_t;
+
+typedef struct {
+    double x;
+    double y;
+} wxd_Point2DDouble;
+
+// --- Context creation ---
+
+// Creates a context drawing on a window, client, paint or memory DC. Returns NULL
+// for other DCs. The DC must outlive the context.
+WXD_EXPORTED wxd_GraphicsContext_t* wxd_GraphicsContext_CreateFromDC(wxd_DC_t* dc);
+
+// Creates a context drawing on a bitmap, which must not be selected into a DC.
+// The drawing is stored in the bitmap when the context is destroyed.
+WXD_EXPORTED wxd_GraphicsContext_t* wxd_GraphicsContext_CreateFromBitmap(wxd_Bitmap_t* bitmap);
+
+// Flushes pending drawing and destroys the context
+WXD_EXPORTED void wxd_GraphicsContext_Destroy(wxd_GraphicsContext_t* gc);
+
+// --- Pens, brushes and fonts (renderer objects, destroy with their _Destroy function) ---
+
+WXD_EXPORTED wxd_GraphicsPen_t* wxd_GraphicsContext_CreatePen(wxd_GraphicsContext_t* gc, wxd_Colour_t colour, double width,
+                                                           int style, wxd_PenJoin join, wxd_PenCap cap);
+WXD_EXPORTED void wxd_GraphicsPen_Destroy(wxd_GraphicsPen_t* pen);
+
+WXD_EXPORTED wxd_GraphicsBrush_t* wxd_GraphicsContext_CreateBrush(wxd_GraphicsContext_t* gc, wxd_Colour_t colour);
+
+// Gradients go through `count` (at least 2) colours at the given positions in [0, 1]
+WXD_EXPORTED wxd_GraphicsBrush_t* wxd_GraphicsContext_CreateLinearGradientBrush(wxd_GraphicsContext_t* gc,
+                                                                             double x1, double y1, double x2, double y2,
+                                                                             const wxd_Colour_t* colours, const float* positions, int count);
+WXD_EXPORTED wxd_GraphicsBrush_t* wxd_GraphicsContext_CreateRadialGradientBrush(wxd_GraphicsContext_t* gc,
+                                                                             double start_x, double start_y,
+                                                                             double end_x, double end_y, double radius,
+                                                                             const wxd_Colour_t* colours, const float* positions, int count);
+WXD_EXPORTED void wxd_GraphicsBrush_Destroy(wxd_GraphicsBrush_t* brush);
+
+WXD_EXPORTED wxd_GraphicsFont_t* wxd_GraphicsContext_CreateFont(wxd_GraphicsContext_t* gc, const wxd_Font_t* font, wxd_Colour_t colour);
+WXD_EXPORTED void wxd_GraphicsFont_Destroy(wxd_GraphicsFont_t* font);
+
+// NULL pens and brushes draw nothing
+WXD_EXPORTED void wxd_GraphicsContext_SetPen(wxd_GraphicsContext_t* gc, const wxd_GraphicsPen_t* pen);
+WXD_EXPORTED void wxd_GraphicsContext_SetBrush(wxd_GraphicsContext_t* gc, const wxd_GraphicsBrush_t* brush);
+WXD_EXPORTED void wxd_GraphicsContext_SetFont(wxd_GraphicsContext_t* gc, const wxd_GraphicsFont_t* font);
+
+// --- Paths ---
+
+WXD_EXPORTED wxd_GraphicsPath_t* wxd_GraphicsContext_CreatePath(wxd_GraphicsContext_t* gc);
+WXD_EXPORTED void wxd_GraphicsPath_Destroy(wxd_GraphicsPath_t* path);
+WXD_EXPORTED void wxd_GraphicsPath_MoveToPoint(wxd_GraphicsPath_t* path, double x, double y);
+WXD_EXPORTED void wxd_GraphicsPath_AddLineToPoint(wxd_GraphicsPath_t* path, double x, double y);
+WXD_EXPORTED void wxd_GraphicsPath_AddCurveToPoint(wxd_GraphicsPath_t* path, double cx1, double cy1,
+                                                   double cx2, double cy2, double x, double y);
+WXD_EXPORTED void wxd_GraphicsPath_AddQuadCurveToPoint(wxd_GraphicsPath_t* path, double cx, double cy, double x, double y);
+// Angles are in radians
+WXD_EXPORTED void wxd_GraphicsPath_AddArc(wxd_GraphicsPath_t* path, double x, double y, double r,
+                                          double start_angle, double end_angle, bool clockwise);
+WXD_EXPORTED void wxd_GraphicsPath_AddArcToPoint(wxd_GraphicsPath_t* path, double x1, double y1, double x2, double y2, double r);
+WXD_EXPORTED void wxd_GraphicsPath_AddRectangle(wxd_GraphicsPath_t* path, double x, double y, double w, double h);
+WXD_EXPORTED void wxd_GraphicsPath_AddRoundedRectangle(wxd_GraphicsPath_t* path, double x, double y, double w, double h, double radius);
+WXD_EXPORTED void wxd_GraphicsPath_AddCircle(wxd_GraphicsPath_t* path, double x, double y, double r);
+WXD_EXPORTED void wxd_GraphicsPath_AddEllipse(wxd_GraphicsPath_t* path, double x, double y, double w, double h);
+WXD_EXPORTED void wxd_GraphicsPath_AddPath(wxd_GraphicsPath_t* path, const wxd_GraphicsPath_t* other);
+WXD_EXPORTED void wxd_GraphicsPath_CloseSubpath(wxd_GraphicsPath_t* path);
+WXD_EXPORTED wxd_Point2DDouble wxd_GraphicsPath_GetCurrentPoint(const wxd_GraphicsPath_t* path);
+WXD_EXPORTED void wxd_GraphicsPath_GetBox(const wxd_GraphicsPath_t* path, double* x, double* y, double* w, double* h);
+WXD_EXPORTED bool wxd_GraphicsPath_Contains(const wxd_GraphicsPath_t* path, double x, double y, int fill_style);
+
+// --- Drawing ---
+
+WXD_EXPORTED void wxd_GraphicsContext_StrokePath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path);
+// fill_style is WXD_ODDEVEN_RULE or WXD_WINDING_RULE
+WXD_EXPORTED void wxd_GraphicsContext_FillPath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path, int fill_style);
+WXD_EXPORTED void wxd_GraphicsContext_DrawPath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path, int fill_style);
+WXD_EXPORTED void wxd_GraphicsContext_StrokeLine(wxd_GraphicsContext_t* gc, double x1, double y1, double x2, double y2);
+WXD_EXPORTED void wxd_GraphicsContext_StrokeLines(wxd_GraphicsContext_t* gc, const wxd_Point2DDouble* points, int count);
+WXD_EXPORTED void wxd_GraphicsContext_DrawLines(wxd_GraphicsContext_t* gc, const wxd_Point2DDouble* points, int count, int fill_style);
+WXD_EXPORTED void wxd_GraphicsContext_DrawRectangle(wxd_GraphicsContext_t* gc, double x, double y, double w, double h);
+WXD_EXPORTED void wxd_GraphicsContext_DrawRoundedRectangle(wxd_GraphicsContext_t* gc, double x, double y, double w, double h, double radius);
+WXD_EXPORTED void wxd_GraphicsContext_DrawEllipse(wxd_GraphicsContext_t* gc, double x, double y, double w, double h);
+WXD_EXPORTED void wxd_GraphicsContext_DrawBitmap(wxd_GraphicsContext_t* gc, const wxd_Bitmap_t* bitmap, double x, double y, double w, double h);
+
+// Text uses the current font; the angle is in radians
+WXD_EXPORTED void wxd_GraphicsContext_DrawText(wxd_GraphicsContext_t* gc, const char* text, double x, double y, double angle);
+WXD_EXPORTED void wxd_GraphicsContext_GetTextExtent(wxd_GraphicsContext_t* gc, const char* text, double* width, double* height,
+                                                    double* descent, double* external_leading);
+
+// --- State and transforms ---
+
+WXD_EXPORTED void wxd_GraphicsContext_PushState(wxd_GraphicsContext_t* gc);
+WXD_EXPORTED void wxd_GraphicsContext_PopState(wxd_GraphicsContext_t* gc);
+WXD_EXPORTED void wxd_GraphicsContext_Translate(wxd_GraphicsContext_t* gc, double dx, double dy);
+WXD_EXPORTED void wxd_GraphicsContext_Rotate(wxd_GraphicsContext_t* gc, double angle);
+WXD_EXPORTED void wxd_GraphicsContext_Scale(wxd_GraphicsContext_t* gc, double x_scale, double y_scale);
+// Multiplies the current transform by the matrix (a, b, c, d, tx, ty)
+WXD_EXPORTED void wxd_GraphicsContext_ConcatTransform(wxd_GraphicsContext_t* gc, double a, double b, double c, double d,
+                                                      double tx, double ty);
+WXD_EXPORTED void wxd_GraphicsContext_ResetTransform(wxd_GraphicsContext_t* gc);
+
+WXD_EXPORTED void wxd_GraphicsContext_Clip(wxd_GraphicsContext_t* gc, double x, double y, double w, double h);
+WXD_EXPORTED void wxd_GraphicsContext_ResetClip(wxd_GraphicsContext_t* gc);
+
+// Drawing between BeginLayer and EndLayer is composited with the given opacity
+WXD_EXPORTED void wxd_GraphicsContext_BeginLayer(wxd_GraphicsContext_t* gc, double opacity);
+WXD_EXPORTED void wxd_GraphicsContext_EndLayer(wxd_GraphicsContext_t* gc);
+
+WXD_EXPORTED bool wxd_GraphicsContext_SetAntialias(wxd_GraphicsContext_t* gc, bool enabled);
+WXD_EXPORTED void wxd_GraphicsContext_GetSize(wxd_GraphicsContext_t* gc, double* width, double* height);
+WXD_EXPORTED void wxd_GraphicsContext_Flush(wxd_GraphicsContext_t* gc);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WXD_GRAPHICS_H
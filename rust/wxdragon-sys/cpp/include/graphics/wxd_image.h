@@ -0,0 +1,107 @@
+#ifndef WXD_IMAGE_H
+#define WXD_IMAGE_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// File formats. WXD_IMAGE_TYPE_ANY detects the format when loading and uses
+// the file extension when saving to a file.
+typedef enum {
+    WXD_IMAGE_TYPE_ANY = 0,
+    WXD_IMAGE_TYPE_PNG = 1,
+    WXD_IMAGE_TYPE_JPEG = 2,
+    WXD_IMAGE_TYPE_BMP = 3,
+    WXD_IMAGE_TYPE_GIF = 4,
+    WXD_IMAGE_TYPE_ICO = 5,
+    WXD_IMAGE_TYPE_TIFF = 6
+} wxd_ImageType;
+
+typedef enum {
+    WXD_IMAGE_QUALITY_NORMAL = 0,
+    WXD_IMAGE_QUALITY_HIGH = 1,
+    WXD_IMAGE_QUALITY_NEAREST = 2,
+    WXD_IMAGE_QUALITY_BILINEAR = 3,
+    WXD_IMAGE_QUALITY_BICUBIC = 4,
+    WXD_IMAGE_QUALITY_BOX_AVERAGE = 5
+} wxd_ImageResizeQuality;
+
+// --- Creation (all return NULL on failure; destroy with wxd_Image_Destroy) ---
+
+// A black image without alpha
+WXD_EXPORTED wxd_Image_t* wxd_Image_Create(int width, int height);
+// data holds width * height RGBA pixels
+WXD_EXPORTED wxd_Image_t* wxd_Image_CreateFromRGBA(const unsigned char* data, int width, int height);
+WXD_EXPORTED wxd_Image_t* wxd_Image_CreateFromFile(const char* path, wxd_ImageType type);
+WXD_EXPORTED wxd_Image_t* wxd_Image_CreateFromData(const unsigned char* data, size_t len, wxd_ImageType type);
+WXD_EXPORTED wxd_Image_t* wxd_Image_CreateFromBitmap(const wxd_Bitmap_t* bitmap);
+WXD_EXPORTED wxd_Image_t* wxd_Image_Clone(const wxd_Image_t* image);
+WXD_EXPORTED void wxd_Image_Destroy(wxd_Image_t* image);
+
+// Returns a bitmap to be destroyed with wxd_Bitmap_Destroy, or NULL
+WXD_EXPORTED wxd_Bitmap_t* wxd_Bitmap_CreateFromImage(const wxd_Image_t* image);
+
+// --- Saving ---
+
+WXD_EXPORTED bool wxd_Image_SaveFile(const wxd_Image_t* image, const char* path, wxd_ImageType type);
+// Encodes the image in the given format (not ANY). Returns a buffer of *len bytes
+// to be freed with wxd_Image_FreeData, or NULL.
+WXD_EXPORTED unsigned char* wxd_Image_SaveToData(const wxd_Image_t* image, wxd_ImageType type, size_t* len);
+WXD_EXPORTED void wxd_Image_FreeData(unsigned char* data);
+
+// --- Properties ---
+
+WXD_EXPORTED bool wxd_Image_IsOk(const wxd_Image_t* image);
+WXD_EXPORTED int wxd_Image_GetWidth(const wxd_Image_t* image);
+WXD_EXPORTED int wxd_Image_GetHeight(const wxd_Image_t* image);
+// The format the image was loaded from, or ANY
+WXD_EXPORTED wxd_ImageType wxd_Image_GetType(const wxd_Image_t* image);
+
+// --- Pixels ---
+
+// Returns width * height RGBA pixels to be freed with wxd_Image_FreeData
+WXD_EXPORTED unsigned char* wxd_Image_GetRGBAData(const wxd_Image_t* image);
+WXD_EXPORTED wxd_Colour_t wxd_Image_GetPixel(const wxd_Image_t* image, int x, int y);
+// The alpha is only stored if the image has an alpha channel
+WXD_EXPORTED void wxd_Image_SetPixel(wxd_Image_t* image, int x, int y, wxd_Colour_t colour);
+
+// --- Transformations (return a new image, or NULL) ---
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_Scale(const wxd_Image_t* image, int width, int height, wxd_ImageResizeQuality quality);
+WXD_EXPORTED void wxd_Image_Rescale(wxd_Image_t* image, int width, int height, wxd_ImageResizeQuality quality);
+WXD_EXPORTED wxd_Image_t* wxd_Image_Rotate90(const wxd_Image_t* image, bool clockwise);
+WXD_EXPORTED wxd_Image_t* wxd_Image_Rotate180(const wxd_Image_t* image);
+// Rotates by angle radians around the centre. The result is large enough for the
+// whole rotated image; uncovered areas are transparent if the image has alpha.
+WXD_EXPORTED wxd_Image_t* wxd_Image_Rotate(const wxd_Image_t* image, double angle, bool interpolating);
+WXD_EXPORTED wxd_Image_t* wxd_Image_Mirror(const wxd_Image_t* image, bool horizontally);
+// Returns NULL unless the rectangle lies inside the image
+WXD_EXPORTED wxd_Image_t* wxd_Image_GetSubImage(const wxd_Image_t* image, wxd_Rect rect);
+WXD_EXPORTED wxd_Image_t* wxd_Image_ConvertToGreyscale(const wxd_Image_t* image);
+WXD_EXPORTED wxd_Image_t* wxd_Image_ConvertToDisabled(const wxd_Image_t* image, unsigned char brightness);
+// Draws other onto image at (x, y), clipped to the image
+WXD_EXPORTED void wxd_Image_Paste(wxd_Image_t* image, const wxd_Image_t* other, int x, int y);
+
+// --- Alpha and mask ---
+
+WXD_EXPORTED bool wxd_Image_HasAlpha(const wxd_Image_t* image);
+// Adds a fully opaque alpha channel, or converts the mask to one
+WXD_EXPORTED void wxd_Image_InitAlpha(wxd_Image_t* image);
+WXD_EXPORTED void wxd_Image_ClearAlpha(wxd_Image_t* image);
+WXD_EXPORTED unsigned char wxd_Image_GetAlpha(const wxd_Image_t* image, int x, int y);
+WXD_EXPORTED void wxd_Image_SetAlpha(wxd_Image_t* image, int x, int y, unsigned char alpha);
+WXD_EXPORTED bool wxd_Image_HasMask(const wxd_Image_t* image);
+WXD_EXPORTED void wxd_Image_SetMask(wxd_Image_t* image, bool has_mask);
+// Pixels of this colour are transparent; enables the mask
+WXD_EXPORTED void wxd_Image_SetMaskColour(wxd_Image_t* image, wxd_Colour_t colour);
+WXD_EXPORTED wxd_Colour_t wxd_Image_GetMaskColour(const wxd_Image_t* image);
+// Replaces the alpha channel by a mask of the pixels with alpha below threshold
+WXD_EXPORTED bool wxd_Image_ConvertAlphaToMask(wxd_Image_t* image, unsigned char threshold);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WXD_IMAGE_H
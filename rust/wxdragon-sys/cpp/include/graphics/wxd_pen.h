@@ -0,0 +1,57 @@
+#ifndef WXD_PEN_H
+#define WXD_PEN_H
+
+#include "../wxd_types.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// Opaque type for wxPen
+typedef struct wxd_Pen_t wxd_Pen_t;
+
+// Line joins and caps
+typedef enum {
+    WXD_JOIN_BEVEL = 0,
+    WXD_JOIN_MITER = 1,
+    WXD_JOIN_ROUND = 2
+} wxd_PenJoin;
+
+typedef enum {
+    WXD_CAP_ROUND = 0,
+    WXD_CAP_PROJECTING = 1,
+    WXD_CAP_BUTT = 2
+} wxd_PenCap;
+
+// style is a WXD_PENSTYLE_* value
+WXD_EXPORTED wxd_Pen_t* wxd_Pen_Create(wxd_Colour_t colour, int width, int style);
+WXD_EXPORTED wxd_Pen_t* wxd_Pen_Clone(const wxd_Pen_t* pen);
+WXD_EXPORTED void wxd_Pen_Destroy(wxd_Pen_t* pen);
+WXD_EXPORTED bool wxd_Pen_IsOk(const wxd_Pen_t* pen);
+
+WXD_EXPORTED void wxd_Pen_SetColour(wxd_Pen_t* pen, wxd_Colour_t colour);
+WXD_EXPORTED wxd_Colour_t wxd_Pen_GetColour(const wxd_Pen_t* pen);
+WXD_EXPORTED void wxd_Pen_SetWidth(wxd_Pen_t* pen, int width);
+WXD_EXPORTED int wxd_Pen_GetWidth(const wxd_Pen_t* pen);
+WXD_EXPORTED void wxd_Pen_SetStyle(wxd_Pen_t* pen, int style);
+WXD_EXPORTED int wxd_Pen_GetStyle(const wxd_Pen_t* pen);
+WXD_EXPORTED void wxd_Pen_SetJoin(wxd_Pen_t* pen, wxd_PenJoin join);
+WXD_EXPORTED wxd_PenJoin wxd_Pen_GetJoin(const wxd_Pen_t* pen);
+WXD_EXPORTED void wxd_Pen_SetCap(wxd_Pen_t* pen, wxd_PenCap cap);
+WXD_EXPORTED wxd_PenCap wxd_Pen_GetCap(const wxd_Pen_t* pen);
+
+// Alternating dash and gap lengths, in multiples of the pen width. A non-empty
+// array also sets the style to WXD_PENSTYLE_USER_DASH; an empty one removes the
+// dashes and turns a user dash pen into a solid one.
+WXD_EXPORTED void wxd_Pen_SetDashes(wxd_Pen_t* pen, const int* dashes, int count);
+// Copies up to max_count dash lengths into dashes and returns the total count
+WXD_EXPORTED int wxd_Pen_GetDashes(const wxd_Pen_t* pen, int* dashes, int max_count);
+
+// Also sets the style to WXD_PENSTYLE_STIPPLE; not supported by every platform
+WXD_EXPORTED void wxd_Pen_SetStipple(wxd_Pen_t* pen, const wxd_Bitmap_t* bitmap);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
+#endif // WXD_PEN_H
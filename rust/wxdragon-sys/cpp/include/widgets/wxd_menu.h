@@ -19,5 +19,6 @@ WXD_EXPORTED void wxd_MenuItem_Enable(wxd_MenuItem_t* item, bool enable);
 WXD_EXPORTED bool wxd_MenuItem_IsEnabled(wxd_MenuItem_t* item);
 WXD_EXPORTED void wxd_MenuItem_Check(wxd_MenuItem_t* item, bool check);
 WXD_EXPORTED bool wxd_MenuItem_IsChecked(wxd_MenuItem_t* item);
+WXD_EXPORTED bool wxd_MenuItem_GetAccel(wxd_MenuItem_t* item, wxd_AcceleratorEntry* out_entry); // False if the item has no accelerator
 
 #endif // WXD_MENU_H 
\ No newline at end of file
@@ -327,9 +327,29 @@ typedef enum {
     WXD_EVENT_TYPE_MENU_HIGHLIGHT = 352,                // wxEVT_MENU_HIGHLIGHT
     WXD_EVENT_TYPE_CONTEXT_MENU = 353,                  // wxEVT_CONTEXT_MENU
 
+    WXD_EVENT_TYPE_UPDATE_UI = 354,                     // wxEVT_UPDATE_UI
+
+    // Additional mouse event types
+    WXD_EVENT_TYPE_LEFT_DCLICK = 355,                   // wxEVT_LEFT_DCLICK
+    WXD_EVENT_TYPE_RIGHT_DCLICK = 356,                  // wxEVT_RIGHT_DCLICK
+    WXD_EVENT_TYPE_MIDDLE_DCLICK = 357,                 // wxEVT_MIDDLE_DCLICK
+    WXD_EVENT_TYPE_AUX1_DOWN = 358,                     // wxEVT_AUX1_DOWN
+    WXD_EVENT_TYPE_AUX1_UP = 359,                       // wxEVT_AUX1_UP
+    WXD_EVENT_TYPE_AUX1_DCLICK = 360,                   // wxEVT_AUX1_DCLICK
+    WXD_EVENT_TYPE_AUX2_DOWN = 361,                     // wxEVT_AUX2_DOWN
+    WXD_EVENT_TYPE_AUX2_UP = 362,                       // wxEVT_AUX2_UP
+    WXD_EVENT_TYPE_AUX2_DCLICK = 363,                   // wxEVT_AUX2_DCLICK
+    WXD_EVENT_TYPE_MOUSE_CAPTURE_LOST = 364,            // wxEVT_MOUSE_CAPTURE_LOST
+    WXD_EVENT_TYPE_CHAR_HOOK = 365,                     // wxEVT_CHAR_HOOK
+    WXD_EVENT_TYPE_DPI_CHANGED = 366,                   // wxEVT_DPI_CHANGED
+
     WXD_EVENT_TYPE_MAX // Keep this last for count if needed, or remove if not used for iteration
 } WXDEventTypeCEnum;
 
+// User-defined event types registered at runtime are numbered from this value upwards,
+// well clear of the built-in WXDEventTypeCEnum values above.
+#define WXD_EVENT_TYPE_CUSTOM_BASE 10000
+
 typedef int64_t wxd_Style_t;
 typedef int wxd_Direction_t;
 typedef int wxd_Orientation_t;
@@ -469,6 +489,8 @@ typedef enum {
 // --- Function Pointer Typedefs --- 
 typedef bool (*wxd_OnInitCallback)(void* userData);
 typedef void (*wxd_ClosureCallback)(void* closure_ptr, wxd_Event_t* event);
+// Receives a wx log record; level is a wxLogLevel, component may be empty and file NULL
+typedef void (*wxd_LogCallback)(int level, const char* message, const char* component, const char* file, int line);
 
 // wxDragResult C Enum (for drag and drop operations)
 typedef enum {
@@ -509,13 +531,35 @@ typedef enum {
 } wxd_DataViewCellModeCEnum;
 
 // Image related opaque types
-typedef void wxd_Image_t;          // Added if not present
+typedef struct wxd_Image_t wxd_Image_t;
 typedef void wxd_ImageList_t;      // Added
 typedef struct wxd_Icon_t wxd_Icon_t; // Assuming this pattern from Bitmap_t
 
 // --- Cursor type ---
 typedef struct wxd_Cursor_t wxd_Cursor_t;
 
+// --- Accelerator types ---
+typedef struct wxd_AcceleratorTable_t wxd_AcceleratorTable_t;
+
+// A keyboard accelerator; modifiers use the wxMOD_* flags
+typedef struct {
+    int modifiers;
+    int key_code;
+    int id;
+} wxd_AcceleratorEntry;
+
+// --- Validator type ---
+typedef struct wxd_Validator_t wxd_Validator_t;
+
+// --- Config type ---
+typedef struct wxd_Config_t wxd_Config_t;
+
+// --- Log types ---
+typedef struct wxd_LogWindow_t wxd_LogWindow_t;
+
+// --- Process type ---
+typedef struct wxd_Process_t wxd_Process_t;
+
 // --- Cursor Stock Types ---
 typedef enum {
     WXD_CURSOR_NONE = 0,
@@ -31,6 +31,16 @@ extern "C" {
 #include "core/wxd_app.h"
 #include "core/wxd_window_base.h"
 #include "core/wxd_cursor.h"
+#include "core/wxd_accelerator.h"
+#include "core/wxd_validator.h"
+#include "core/wxd_config.h"
+#include "core/wxd_log.h"
+#include "core/wxd_i18n.h"
+#include "core/wxd_stdpaths.h"
+#include "core/wxd_single_instance.h"
+#include "core/wxd_process.h"
+#include "core/wxd_persistence.h"
+#include "core/wxd_printing.h"
 #if wxdUSE_XRC
 #include "core/wxd_xrc.h"
 #endif
@@ -102,6 +112,7 @@ extern "C" {
 
 // Graphics
 #include "graphics/wxd_bitmapbundle.h"
+#include "graphics/wxd_image.h"
 
 // Other widgets
 #include "widgets/wxd_pickers.h"
@@ -121,6 +132,7 @@ extern "C" {
 #include "dialogs/wxd_dialogs.h"
 #include "dnd/wxd_dnd.h" // Drag and drop functionality
 #include "graphics/wxd_dc.h" // Device context functionality
+#include "graphics/wxd_graphics.h"
 
 // DataView related includes.
 // wxd_dataview.h provides main FFI for DataViewCtrl, ListCtrl, TreeCtrl (creation),
@@ -0,0 +1,78 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include "wxd_utils.h"
+#include <wx/accel.h>
+#include <cstring>
+#include <vector>
+
+namespace wxd_cpp_utils {
+
+wxAcceleratorEntry to_wx(const wxd_AcceleratorEntry& entry) {
+    int flags = wxACCEL_NORMAL;
+    if (entry.modifiers & wxMOD_ALT) flags |= wxACCEL_ALT;
+    if (entry.modifiers & wxMOD_CONTROL) flags |= wxACCEL_CTRL;
+    if (entry.modifiers & wxMOD_SHIFT) flags |= wxACCEL_SHIFT;
+    if (entry.modifiers & wxMOD_RAW_CONTROL) flags |= wxACCEL_RAW_CTRL;
+    return wxAcceleratorEntry(flags, entry.key_code, entry.id);
+}
+
+wxd_AcceleratorEntry from_wx(const wxAcceleratorEntry& entry) {
+    int flags = entry.GetFlags();
+    int modifiers = wxMOD_NONE;
+    if (flags & wxACCEL_ALT) modifiers |= wxMOD_ALT;
+    if (flags & wxACCEL_CTRL) modifiers |= wxMOD_CONTROL;
+    if (flags & wxACCEL_SHIFT) modifiers |= wxMOD_SHIFT;
+    if (flags & wxACCEL_RAW_CTRL) modifiers |= wxMOD_RAW_CONTROL;
+    wxd_AcceleratorEntry result = { modifiers, entry.GetKeyCode(), entry.GetCommand() };
+    return result;
+}
+
+} // namespace wxd_cpp_utils
+
+extern "C" {
+
+WXD_EXPORTED wxd_AcceleratorTable_t* wxd_AcceleratorTable_Create(const wxd_AcceleratorEntry* entries, int count) {
+    std::vector<wxAcceleratorEntry> wx_entries;
+    if (entries && count > 0) {
+        wx_entries.reserve(count);
+        for (int i = 0; i < count; ++i) {
+            wx_entries.push_back(wxd_cpp_utils::to_wx(entries[i]));
+        }
+    }
+    wxAcceleratorTable* table = new wxAcceleratorTable(
+        static_cast<int>(wx_entries.size()), wx_entries.empty() ? nullptr : wx_entries.data());
+    return reinterpret_cast<wxd_AcceleratorTable_t*>(table);
+}
+
+WXD_EXPORTED void wxd_AcceleratorTable_Destroy(wxd_AcceleratorTable_t* table) {
+    if (!table) return;
+    delete reinterpret_cast<wxAcceleratorTable*>(table);
+}
+
+WXD_EXPORTED bool wxd_AcceleratorTable_IsOk(wxd_AcceleratorTable_t* table) {
+    if (!table) return false;
+    return reinterpret_cast<wxAcceleratorTable*>(table)->IsOk();
+}
+
+WXD_EXPORTED bool wxd_AcceleratorEntry_FromString(const char* str, wxd_AcceleratorEntry* out_entry) {
+    if (!str || !out_entry) return false;
+    wxAcceleratorEntry entry;
+    if (!entry.FromString(wxString::FromUTF8(str))) return false;
+    int id = out_entry->id;
+    *out_entry = wxd_cpp_utils::from_wx(entry);
+    out_entry->id = id;
+    return true;
+}
+
+WXD_EXPORTED char* wxd_AcceleratorEntry_ToString(const wxd_AcceleratorEntry* entry) {
+    if (!entry) return strdup("");
+    return wxd_cpp_utils::to_c_string(wxd_cpp_utils::to_wx(*entry).ToString());
+}
+
+WXD_EXPORTED char* wxd_AcceleratorEntry_ToRawString(const wxd_AcceleratorEntry* entry) {
+    if (!entry) return strdup("");
+    return wxd_cpp_utils::to_c_string(wxd_cpp_utils::to_wx(*entry).ToRawString());
+}
+
+} // extern "C"
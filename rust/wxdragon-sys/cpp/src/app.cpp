@@ -3,6 +3,7 @@
 #include "../include/wxdragon.h"
 #include <wx/app.h>
 #include <wx/image.h>
+#include <wx/thread.h>
 #include <cstdlib>
 
 // --- Globals --- 
@@ -13,6 +14,9 @@ static void* g_OnInitUserData = nullptr;
 // Function to process Rust callbacks, implemented in Rust
 extern "C" int process_rust_callbacks();
 
+// Drops tasks still pending in the Rust executor, implemented in Rust
+extern "C" void shutdown_rust_executor();
+
 // --- Internal C++ App Class --- 
 
 class WxdApp : public wxApp {
@@ -23,8 +27,8 @@ public:
     // Idle event handler to process callbacks
     void OnIdle(wxIdleEvent& event);
     
-    // Optional: Override OnExit for cleanup if needed
-    // virtual int OnExit() override;
+    // Releases Rust-side state that may still reference windows
+    virtual int OnExit() override;
 };
 
 // Implementation of OnInit - this is where we call the C callback
@@ -70,6 +74,13 @@ void WxdApp::OnIdle(wxIdleEvent& event) {
     }
 }
 
+int WxdApp::OnExit() {
+    // Pending futures may own widgets or timers, so drop them while wx is still alive
+    shutdown_rust_executor();
+    wxd_SingleInstance_Release();
+    return wxApp::OnExit();
+}
+
 // --- C API Implementation --- 
 
 // This macro creates the necessary wxWidgets entry points (like main or WinMain)
@@ -108,6 +119,8 @@ int wxd_Main(int argc, char** argv, wxd_OnInitCallback on_init_cb, void* userDat
     if (wxTheApp && wxTheApp->CallOnInit()) {
         // Rust initialization was successful (returned true)
         wxTheApp->OnRun(); // Start the main event loop
+        // wxEntry would normally do this; we drive the app ourselves so call it explicitly
+        wxTheApp->OnExit();
     } else {
         // wxApp initialization failed (CallOnInit returned false or wxTheApp was null)
         // Log this case, as WxdApp::OnInit (and thus Rust init) might have returned false.
@@ -135,11 +148,45 @@ void wxd_App_SetTopWindow(wxd_App_t* app, wxd_Window_t* window) {
     wx_app->SetTopWindow(wx_window);
 }
 
+void wxd_App_SetAppName(wxd_App_t* app, const char* name) {
+    if (!app || !name) return;
+    reinterpret_cast<wxApp*>(app)->SetAppName(wxString::FromUTF8(name));
+}
+
+char* wxd_App_GetAppName(wxd_App_t* app) {
+    if (!app) return strdup("");
+    return strdup(reinterpret_cast<wxApp*>(app)->GetAppName().utf8_str().data());
+}
+
+void wxd_App_SetVendorName(wxd_App_t* app, const char* name) {
+    if (!app || !name) return;
+    reinterpret_cast<wxApp*>(app)->SetVendorName(wxString::FromUTF8(name));
+}
+
+char* wxd_App_GetVendorName(wxd_App_t* app) {
+    if (!app) return strdup("");
+    return strdup(reinterpret_cast<wxApp*>(app)->GetVendorName().utf8_str().data());
+}
+
 // Manual callback processing for cases where we need to trigger it
 void wxd_App_ProcessCallbacks() {
     process_rust_callbacks();
 }
 
+void wxd_App_ExitMainLoop() {
+    if (wxTheApp) {
+        wxTheApp->ExitMainLoop();
+    }
+}
+
+void wxd_WakeUpIdle() {
+    wxWakeUpIdle();
+}
+
+bool wxd_IsMainThread() {
+    return wxIsMainThread();
+}
+
 // Implementation for wxd_free_string
 void wxd_free_string(char* str) {
     if (str) {
@@ -0,0 +1,74 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+
+static wxBrush* ToWx(wxd_Brush_t* brush) {
+    return reinterpret_cast<wxBrush*>(brush);
+}
+
+static const wxBrush* ToWx(const wxd_Brush_t* brush) {
+    return reinterpret_cast<const wxBrush*>(brush);
+}
+
+extern "C" {
+
+WXD_EXPORTED wxd_Brush_t* wxd_Brush_Create(wxd_Colour_t colour, int style) {
+    wxColour wx_colour(colour.r, colour.g, colour.b, colour.a);
+    return reinterpret_cast<wxd_Brush_t*>(new wxBrush(wx_colour, static_cast<wxBrushStyle>(style)));
+}
+
+WXD_EXPORTED wxd_Brush_t* wxd_Brush_CreateFromStipple(const wxd_Bitmap_t* bitmap) {
+    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!wx_bitmap || !wx_bitmap->IsOk()) return nullptr;
+    return reinterpret_cast<wxd_Brush_t*>(new wxBrush(*wx_bitmap));
+}
+
+WXD_EXPORTED wxd_Brush_t* wxd_Brush_Clone(const wxd_Brush_t* brush) {
+    if (!brush) return nullptr;
+    return reinterpret_cast<wxd_Brush_t*>(new wxBrush(*ToWx(brush)));
+}
+
+WXD_EXPORTED void wxd_Brush_Destroy(wxd_Brush_t* brush) {
+    delete ToWx(brush);
+}
+
+WXD_EXPORTED bool wxd_Brush_IsOk(const wxd_Brush_t* brush) {
+    return brush && ToWx(brush)->IsOk();
+}
+
+WXD_EXPORTED void wxd_Brush_SetColour(wxd_Brush_t* brush, wxd_Colour_t colour) {
+    if (brush) ToWx(brush)->SetColour(wxColour(colour.r, colour.g, colour.b, colour.a));
+}
+
+WXD_EXPORTED wxd_Colour_t wxd_Brush_GetColour(const wxd_Brush_t* brush) {
+    wxd_Colour_t colour = {0, 0, 0, 255};
+    if (brush && ToWx(brush)->IsOk()) {
+        wxColour wx_colour = ToWx(brush)->GetColour();
+        colour.r = wx_colour.Red();
+        colour.g = wx_colour.Green();
+        colour.b = wx_colour.Blue();
+        colour.a = wx_colour.Alpha();
+    }
+    return colour;
+}
+
+WXD_EXPORTED void wxd_Brush_SetStyle(wxd_Brush_t* brush, int style) {
+    if (brush) ToWx(brush)->SetStyle(static_cast<wxBrushStyle>(style));
+}
+
+WXD_EXPORTED int wxd_Brush_GetStyle(const wxd_Brush_t* brush) {
+    if (!brush || !ToWx(brush)->IsOk()) return static_cast<int>(wxBRUSHSTYLE_SOLID);
+    return static_cast<int>(ToWx(brush)->GetStyle());
+}
+
+WXD_EXPORTED void wxd_Brush_SetStipple(wxd_Brush_t* brush, const wxd_Bitmap_t* bitmap) {
+    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!brush || !wx_bitmap || !wx_bitmap->IsOk()) return;
+    ToWx(brush)->SetStipple(*wx_bitmap);
+}
+
+WXD_EXPORTED bool wxd_Brush_IsHatch(const wxd_Brush_t* brush) {
+    return brush && ToWx(brush)->IsOk() && ToWx(brush)->IsHatch();
+}
+
+} // extern "C"
@@ -0,0 +1,172 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include "wxd_utils.h"
+#include <wx/config.h>
+#include <wx/fileconf.h>
+
+static wxConfigBase* ToWx(wxd_Config_t* config) {
+    return reinterpret_cast<wxConfigBase*>(config);
+}
+
+extern "C" {
+
+// --- Creation ---
+
+WXD_EXPORTED wxd_Config_t* wxd_Config_Create(const char* app_name, const char* vendor_name) {
+    wxFileConfig* config = new wxFileConfig(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(app_name),
+                                            WXD_STR_TO_WX_STRING_UTF8_NULL_OK(vendor_name),
+                                            wxEmptyString, wxEmptyString, wxCONFIG_USE_LOCAL_FILE);
+    return reinterpret_cast<wxd_Config_t*>(static_cast<wxConfigBase*>(config));
+}
+
+WXD_EXPORTED wxd_Config_t* wxd_Config_CreateWithFile(const char* path) {
+    if (!path) return nullptr;
+    wxFileConfig* config = new wxFileConfig(wxEmptyString, wxEmptyString, wxString::FromUTF8(path),
+                                            wxEmptyString, wxCONFIG_USE_LOCAL_FILE);
+    return reinterpret_cast<wxd_Config_t*>(static_cast<wxConfigBase*>(config));
+}
+
+WXD_EXPORTED void wxd_Config_Destroy(wxd_Config_t* config) {
+    delete ToWx(config);
+}
+
+WXD_EXPORTED void wxd_Config_SetGlobal(wxd_Config_t* config) {
+    wxConfigBase* previous = wxConfigBase::Set(ToWx(config));
+    // Setting the current global config again must not destroy it
+    if (previous != ToWx(config)) {
+        delete previous;
+    }
+}
+
+WXD_EXPORTED wxd_Config_t* wxd_Config_GetGlobal(void) {
+    return reinterpret_cast<wxd_Config_t*>(wxConfigBase::Get());
+}
+
+// --- Paths ---
+
+WXD_EXPORTED void wxd_Config_SetPath(wxd_Config_t* config, const char* path) {
+    if (!config) return;
+    ToWx(config)->SetPath(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(path));
+}
+
+WXD_EXPORTED char* wxd_Config_GetPath(wxd_Config_t* config) {
+    if (!config) return strdup("");
+    return wxd_cpp_utils::to_c_string(ToWx(config)->GetPath());
+}
+
+// --- Reading and Writing ---
+
+WXD_EXPORTED bool wxd_Config_ReadString(wxd_Config_t* config, const char* key, char** out_value) {
+    if (!config || !key || !out_value) return false;
+    wxString value;
+    if (!ToWx(config)->Read(wxString::FromUTF8(key), &value)) return false;
+    *out_value = wxd_cpp_utils::to_c_string(value);
+    return true;
+}
+
+WXD_EXPORTED bool wxd_Config_ReadLong(wxd_Config_t* config, const char* key, int64_t* out_value) {
+    if (!config || !key || !out_value) return false;
+    wxLongLong_t value;
+    if (!ToWx(config)->Read(wxString::FromUTF8(key), &value)) return false;
+    *out_value = value;
+    return true;
+}
+
+WXD_EXPORTED bool wxd_Config_ReadDouble(wxd_Config_t* config, const char* key, double* out_value) {
+    if (!config || !key || !out_value) return false;
+    return ToWx(config)->Read(wxString::FromUTF8(key), out_value);
+}
+
+WXD_EXPORTED bool wxd_Config_ReadBool(wxd_Config_t* config, const char* key, bool* out_value) {
+    if (!config || !key || !out_value) return false;
+    return ToWx(config)->Read(wxString::FromUTF8(key), out_value);
+}
+
+WXD_EXPORTED bool wxd_Config_WriteString(wxd_Config_t* config, const char* key, const char* value) {
+    if (!config || !key) return false;
+    return ToWx(config)->Write(wxString::FromUTF8(key), WXD_STR_TO_WX_STRING_UTF8_NULL_OK(value));
+}
+
+WXD_EXPORTED bool wxd_Config_WriteLong(wxd_Config_t* config, const char* key, int64_t value) {
+    if (!config || !key) return false;
+    return ToWx(config)->Write(wxString::FromUTF8(key), static_cast<wxLongLong_t>(value));
+}
+
+WXD_EXPORTED bool wxd_Config_WriteDouble(wxd_Config_t* config, const char* key, double value) {
+    if (!config || !key) return false;
+    return ToWx(config)->Write(wxString::FromUTF8(key), value);
+}
+
+WXD_EXPORTED bool wxd_Config_WriteBool(wxd_Config_t* config, const char* key, bool value) {
+    if (!config || !key) return false;
+    return ToWx(config)->Write(wxString::FromUTF8(key), value);
+}
+
+// --- Enumeration ---
+
+WXD_EXPORTED bool wxd_Config_HasEntry(wxd_Config_t* config, const char* key) {
+    if (!config || !key) return false;
+    return ToWx(config)->HasEntry(wxString::FromUTF8(key));
+}
+
+WXD_EXPORTED bool wxd_Config_HasGroup(wxd_Config_t* config, const char* key) {
+    if (!config || !key) return false;
+    return ToWx(config)->HasGroup(wxString::FromUTF8(key));
+}
+
+WXD_EXPORTED void wxd_Config_GetEntries(wxd_Config_t* config, wxd_ArrayString_t* out) {
+    if (!config || !out || !out->internal_data) return;
+    wxArrayString* names = static_cast<wxArrayString*>(out->internal_data);
+    wxString name;
+    long index;
+    for (bool more = ToWx(config)->GetFirstEntry(name, index); more;
+         more = ToWx(config)->GetNextEntry(name, index)) {
+        names->Add(name);
+    }
+}
+
+WXD_EXPORTED void wxd_Config_GetGroups(wxd_Config_t* config, wxd_ArrayString_t* out) {
+    if (!config || !out || !out->internal_data) return;
+    wxArrayString* names = static_cast<wxArrayString*>(out->internal_data);
+    wxString name;
+    long index;
+    for (bool more = ToWx(config)->GetFirstGroup(name, index); more;
+         more = ToWx(config)->GetNextGroup(name, index)) {
+        names->Add(name);
+    }
+}
+
+// --- Deletion and Renaming ---
+
+WXD_EXPORTED bool wxd_Config_DeleteEntry(wxd_Config_t* config, const char* key, bool delete_group_if_empty) {
+    if (!config || !key) return false;
+    return ToWx(config)->DeleteEntry(wxString::FromUTF8(key), delete_group_if_empty);
+}
+
+WXD_EXPORTED bool wxd_Config_DeleteGroup(wxd_Config_t* config, const char* key) {
+    if (!config || !key) return false;
+    return ToWx(config)->DeleteGroup(wxString::FromUTF8(key));
+}
+
+WXD_EXPORTED bool wxd_Config_DeleteAll(wxd_Config_t* config) {
+    if (!config) return false;
+    return ToWx(config)->DeleteAll();
+}
+
+WXD_EXPORTED bool wxd_Config_RenameEntry(wxd_Config_t* config, const char* old_name, const char* new_name) {
+    if (!config || !old_name || !new_name) return false;
+    return ToWx(config)->RenameEntry(wxString::FromUTF8(old_name), wxString::FromUTF8(new_name));
+}
+
+WXD_EXPORTED bool wxd_Config_RenameGroup(wxd_Config_t* config, const char* old_name, const char* new_name) {
+    if (!config || !old_name || !new_name) return false;
+    return ToWx(config)->RenameGroup(wxString::FromUTF8(old_name), wxString::FromUTF8(new_name));
+}
+
+WXD_EXPORTED bool wxd_Config_Flush(wxd_Config_t* config) {
+    if (!config) return false;
+    return ToWx(config)->Flush();
+}
+
+} // extern "C"
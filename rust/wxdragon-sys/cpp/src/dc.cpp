@@ -4,6 +4,7 @@
 #include <wx/dcmemory.h>
 #include <wx/dcscreen.h>
 #include <wx/dcbuffer.h>
+#include <wx/dcsvg.h>
 
 // Type aliases for easier reference
 using wxd_DC_t = struct wxd_DC_t;
@@ -13,6 +14,7 @@ using wxd_PaintDC_t = struct wxd_PaintDC_t;
 using wxd_MemoryDC_t = struct wxd_MemoryDC_t;
 using wxd_ScreenDC_t = struct wxd_ScreenDC_t;
 using wxd_AutoBufferedPaintDC_t = struct wxd_AutoBufferedPaintDC_t;
+using wxd_SVGFileDC_t = struct wxd_SVGFileDC_t;
 
 // The wxWidgets DC hierarchy provides some challenges for C FFI
 // We need to use the opaque type pattern and typecasts:
@@ -22,6 +24,7 @@ using wxd_AutoBufferedPaintDC_t = struct wxd_AutoBufferedPaintDC_t;
 //     - wxPaintDC
 //   - wxMemoryDC
 //   - wxScreenDC
+//   - wxSVGFileDC
 
 // Since wxDC is abstract, we need to ensure we only expose classes that wxWidgets actually provides
 
@@ -94,6 +97,23 @@ void wxd_AutoBufferedPaintDC_Destroy(wxd_AutoBufferedPaintDC_t* dc) {
     }
 }
 
+wxd_SVGFileDC_t* wxd_SVGFileDC_Create(const char* filename, int width, int height, double dpi, const char* title) {
+    if (!filename) return nullptr;
+    wxString wx_title = title ? wxString::FromUTF8(title) : wxString();
+    wxSVGFileDC* dc = new wxSVGFileDC(wxString::FromUTF8(filename), width, height, dpi, wx_title);
+    if (!dc->IsOk()) {
+        delete dc;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_SVGFileDC_t*>(dc);
+}
+
+void wxd_SVGFileDC_Destroy(wxd_SVGFileDC_t* dc) {
+    if (dc) {
+        delete reinterpret_cast<wxSVGFileDC*>(dc);
+    }
+}
+
 // Type casting functions
 wxd_DC_t* wxd_WindowDC_AsDC(wxd_WindowDC_t* dc) {
     return reinterpret_cast<wxd_DC_t*>(static_cast<wxDC*>(reinterpret_cast<wxWindowDC*>(dc)));
@@ -119,6 +139,10 @@ wxd_DC_t* wxd_AutoBufferedPaintDC_AsDC(wxd_AutoBufferedPaintDC_t* dc) {
     return reinterpret_cast<wxd_DC_t*>(static_cast<wxDC*>(reinterpret_cast<wxAutoBufferedPaintDC*>(dc)));
 }
 
+wxd_DC_t* wxd_SVGFileDC_AsDC(wxd_SVGFileDC_t* dc) {
+    return reinterpret_cast<wxd_DC_t*>(static_cast<wxDC*>(reinterpret_cast<wxSVGFileDC*>(dc)));
+}
+
 // Common DC operations
 void wxd_DC_Clear(wxd_DC_t* dc) {
     if (dc) {
@@ -167,25 +191,17 @@ void wxd_DC_SetFont(wxd_DC_t* dc, const wxd_Font_t* font) {
     }
 }
 
-void wxd_DC_SetPen(wxd_DC_t* dc, wxd_Colour_t colour, int width, int style) {
-    if (dc) {
+void wxd_DC_SetPen(wxd_DC_t* dc, const wxd_Pen_t* pen) {
+    if (dc && pen) {
         wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
-        wxColour wx_color(colour.r, colour.g, colour.b, colour.a);
-        
-        wxPenStyle wx_style = static_cast<wxPenStyle>(style);
-        
-        wx_dc->SetPen(wxPen(wx_color, width, wx_style));
+        wx_dc->SetPen(*reinterpret_cast<const wxPen*>(pen));
     }
 }
 
-void wxd_DC_SetBrush(wxd_DC_t* dc, wxd_Colour_t colour, int style) {
-    if (dc) {
+void wxd_DC_SetBrush(wxd_DC_t* dc, const wxd_Brush_t* brush) {
+    if (dc && brush) {
         wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
-        wxColour wx_color(colour.r, colour.g, colour.b, colour.a);
-        
-        wxBrushStyle wx_style = static_cast<wxBrushStyle>(style);
-        
-        wx_dc->SetBrush(wxBrush(wx_color, wx_style));
+        wx_dc->SetBrush(*reinterpret_cast<const wxBrush*>(brush));
     }
 }
 
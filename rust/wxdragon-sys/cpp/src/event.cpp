@@ -4,8 +4,12 @@
 // #include "../include/events/wxd_event_api.h" // No longer needed, wxd_Event_t defined in wxd_types.h (via wxdragon.h)
 #include <unordered_map>
 #include <vector>  // For std::vector used in closureMap
+#include <algorithm> // For std::find_if/std::remove_if when unbinding closures
 #include <memory> // For std::unique_ptr if we want safer memory management
 #include <tuple>  // For std::pair used in map key
+#include <mutex>  // For the custom event type registry
+#include <string>
+#include <unordered_set>
 #include <wx/event.h>
 #include <wx/app.h>
 #include <wx/window.h> // For wxCloseEvent
@@ -78,6 +82,53 @@ struct RustClosureInfo {
 class WxdEventHandler;
 static wxEventType get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val);
 
+// --- Custom Event Types ---
+
+// Registry of user-defined event types. Custom types get a stable C enum value
+// (>= WXD_EVENT_TYPE_CUSTOM_BASE) and a wxEventType allocated with wxNewEventType().
+// Registration may happen on any thread, so all access goes through the mutex.
+struct CustomEventTypeRegistry {
+    std::mutex mutex;
+    std::unordered_map<std::string, int> by_name;
+    std::unordered_map<int, wxEventType> c_to_wx;
+    std::unordered_map<wxEventType, int> wx_to_c;
+};
+
+static CustomEventTypeRegistry& GetCustomEventTypeRegistry() {
+    static CustomEventTypeRegistry registry;
+    return registry;
+}
+
+static wxEventType LookupCustomWxEventType(int c_value) {
+    CustomEventTypeRegistry& registry = GetCustomEventTypeRegistry();
+    std::lock_guard<std::mutex> lock(registry.mutex);
+    auto it = registry.c_to_wx.find(c_value);
+    return it != registry.c_to_wx.end() ? it->second : wxEVT_NULL;
+}
+
+static bool LookupCustomCEnum(wxEventType wx_type, int* c_value) {
+    CustomEventTypeRegistry& registry = GetCustomEventTypeRegistry();
+    std::lock_guard<std::mutex> lock(registry.mutex);
+    auto it = registry.wx_to_c.find(wx_type);
+    if (it == registry.wx_to_c.end()) return false;
+    *c_value = it->second;
+    return true;
+}
+
+// wxEvtHandlers that currently have a WxdEventHandler attached, mapped to the generation of
+// that WxdEventHandler. Generations are never reused, so a queued custom event is not delivered
+// to a new handler that happens to live at the address of the destroyed target.
+// Only touched on the GUI thread.
+static std::unordered_map<wxEvtHandler*, uint64_t>& GetLiveEventHandlers() {
+    static std::unordered_map<wxEvtHandler*, uint64_t> live_handlers;
+    return live_handlers;
+}
+
+static uint64_t NextEventHandlerGeneration() {
+    static uint64_t last_generation = 0;
+    return ++last_generation;
+}
+
 // Check if an event type typically supports veto (derived from wxNotifyEvent or wxCloseEvent)
 static bool IsVetableEventType(wxEventType eventType) {
     // Close events
@@ -174,8 +225,17 @@ public:
     std::unordered_map<std::pair<wxEventType, wxd_Id>, bool, PairHash> wx_bindings_made;
     wxd_EvtHandler_t* c_handle = nullptr; // Changed type to wxd_EvtHandler_t*
     wxEvtHandler* ownerHandler = nullptr; // Store the actual wxEvtHandler*
+    // Nesting depth of DispatchEvent; closures unbound while dispatching are released afterwards
+    int dispatch_depth = 0;
+    std::vector<void*> pending_drops;
+
+    // Identifies this handler in GetLiveEventHandlers()
+    uint64_t generation = 0;
 
-    WxdEventHandler(wxd_EvtHandler_t* handle, wxEvtHandler* owner) : c_handle(handle), ownerHandler(owner) {}
+    WxdEventHandler(wxd_EvtHandler_t* handle, wxEvtHandler* owner) : c_handle(handle), ownerHandler(owner) {
+        generation = NextEventHandlerGeneration();
+        GetLiveEventHandlers()[owner] = generation;
+    }
 
     // Destructor - Now needs to notify Rust to drop closures via drop_rust_closure_box
     ~WxdEventHandler(); // Declaration moved, definition below
@@ -185,6 +245,16 @@ public:
     
     // Special dispatch method for close events with correct signature
     void DispatchCloseEvent(wxCloseEvent& event);
+
+    // Removes a single Rust closure bound under `key`. Returns false if it is not bound here.
+    bool UnbindClosure(const std::pair<wxEventType, wxd_Id>& key, void* closure_ptr);
+
+private:
+    // Disconnects DispatchEvent from wxWidgets once no closures are left for `key`
+    void ReleaseUnusedBinding(const std::pair<wxEventType, wxd_Id>& key);
+
+    // Drops closures that were unbound while an event was being dispatched
+    void FlushPendingDrops();
 };
 
 // Define WxdHandlerClientData destructor (no change needed here, it still just deletes the handler)
@@ -201,10 +271,47 @@ extern "C" {
     
     // Function implemented in Rust to drop the Box<dyn FnMut(Event)>.
     void drop_rust_closure_box(void* ptr);
+
+    // Function implemented in Rust to drop the payload of a custom event.
+    void drop_rust_event_payload(void* ptr);
 }
 
+// Owns the Rust payload of a custom event. Shared between an event and its clones,
+// the payload is handed back to Rust once the last copy goes away.
+class WxdRustPayload {
+public:
+    explicit WxdRustPayload(void* ptr) : payload_ptr(ptr) {}
+    ~WxdRustPayload() {
+        if (payload_ptr) {
+            drop_rust_event_payload(payload_ptr);
+        }
+    }
+    WxdRustPayload(const WxdRustPayload&) = delete;
+    WxdRustPayload& operator=(const WxdRustPayload&) = delete;
+
+    void* payload_ptr;
+};
+
+// Event class used for all custom event types
+class WxdCustomEvent : public wxEvent {
+public:
+    WxdCustomEvent(wxEventType eventType, std::shared_ptr<WxdRustPayload> payload)
+        : wxEvent(wxID_ANY, eventType), m_payload(std::move(payload)) {}
+
+    wxEvent* Clone() const override { return new WxdCustomEvent(*this); }
+
+    void* GetPayload() const { return m_payload ? m_payload->payload_ptr : nullptr; }
+
+private:
+    std::shared_ptr<WxdRustPayload> m_payload;
+};
+
 // WxdEventHandler Destructor Implementation
 WxdEventHandler::~WxdEventHandler() {
+    auto live = GetLiveEventHandlers().find(ownerHandler);
+    if (live != GetLiveEventHandlers().end() && live->second == generation) {
+        GetLiveEventHandlers().erase(live);
+    }
     // wxLogDebug("WxdEventHandler destroying for handler %p. Notifying Rust to drop closures.", ownerHandler);
     for (auto const& [key, closure_vector] : closureMap) {
         for (auto const& info : closure_vector) {
@@ -214,6 +321,11 @@ WxdEventHandler::~WxdEventHandler() {
             }
         }
     }
+    // Closures unbound during a dispatch that never finished
+    for (void* closure_ptr : pending_drops) {
+        drop_rust_closure_box(closure_ptr);
+    }
+    pending_drops.clear();
     // Clear the maps (optional, as the handler is being destroyed)
     closureMap.clear();
     wx_bindings_made.clear();
@@ -229,6 +341,7 @@ void WxdEventHandler::DispatchEvent(wxEvent& event) {
     std::pair<wxEventType, wxd_Id> key_any_id = {eventType, wxID_ANY};
     
     bool event_consumed = false;
+    dispatch_depth++;
 
     // Process Specific ID Handlers first
     auto it_specific = closureMap.find(key_specific_id);
@@ -284,6 +397,11 @@ void WxdEventHandler::DispatchEvent(wxEvent& event) {
             event.Skip(true);
         }
     }
+
+    dispatch_depth--;
+    if (dispatch_depth == 0 && !pending_drops.empty()) {
+        FlushPendingDrops();
+    }
 }
 
 // Special dispatch method for close events
@@ -297,6 +415,82 @@ void WxdEventHandler::DispatchCloseEvent(wxCloseEvent& event) {
     }
 }
 
+bool WxdEventHandler::UnbindClosure(const std::pair<wxEventType, wxd_Id>& key, void* closure_ptr) {
+    auto it = closureMap.find(key);
+    if (it == closureMap.end()) return false;
+
+    std::vector<RustClosureInfo>& closures = it->second;
+    auto info_it = std::find_if(closures.begin(), closures.end(), [closure_ptr](const RustClosureInfo& info) {
+        return info.closure_ptr == closure_ptr;
+    });
+    if (info_it == closures.end()) return false;
+
+    if (dispatch_depth > 0) {
+        // A handler may be running right now (possibly the one being removed), so only blank the
+        // entry here. DispatchEvent skips null closures and FlushPendingDrops cleans up afterwards.
+        info_it->closure_ptr = nullptr;
+        pending_drops.push_back(closure_ptr);
+        return true;
+    }
+
+    closures.erase(info_it);
+    drop_rust_closure_box(closure_ptr);
+    ReleaseUnusedBinding(key);
+    return true;
+}
+
+void WxdEventHandler::ReleaseUnusedBinding(const std::pair<wxEventType, wxd_Id>& key) {
+    auto it = closureMap.find(key);
+    if (it != closureMap.end()) {
+        if (!it->second.empty()) return;
+        closureMap.erase(it);
+    }
+
+    auto bound = wx_bindings_made.find(key);
+    if (bound == wx_bindings_made.end() || !bound->second || !ownerHandler) return;
+
+    // Mirror the Connect()/Bind() choice made in wxd_EvtHandler_Bind/BindWithId
+    wxEventType eventType = key.first;
+    if (IsVetableEventType(eventType)) {
+        if (eventType == wxEVT_CLOSE_WINDOW) {
+            ownerHandler->Disconnect(eventType,
+                                     wxCloseEventHandler(WxdEventHandler::DispatchCloseEvent),
+                                     nullptr,
+                                     this);
+        } else {
+            ownerHandler->Disconnect(eventType,
+                                     wxEventHandler(WxdEventHandler::DispatchEvent),
+                                     nullptr,
+                                     this);
+        }
+    } else {
+        ownerHandler->Unbind(eventType, &WxdEventHandler::DispatchEvent, this, key.second, key.second);
+    }
+    wx_bindings_made.erase(bound);
+}
+
+void WxdEventHandler::FlushPendingDrops() {
+    std::vector<void*> drops;
+    drops.swap(pending_drops);
+
+    std::vector<std::pair<wxEventType, wxd_Id>> emptied_keys;
+    for (auto& [key, closures] : closureMap) {
+        closures.erase(std::remove_if(closures.begin(), closures.end(), [](const RustClosureInfo& info) {
+            return info.closure_ptr == nullptr;
+        }), closures.end());
+        if (closures.empty()) {
+            emptied_keys.push_back(key);
+        }
+    }
+    for (const auto& key : emptied_keys) {
+        ReleaseUnusedBinding(key);
+    }
+
+    for (void* closure_ptr : drops) {
+        drop_rust_closure_box(closure_ptr);
+    }
+}
+
 // --- C API Implementation --- 
 
 // Gets the handler associated with the wxEvtHandler via client data,
@@ -401,7 +595,7 @@ public:
 
 // --- C API Implementation ---
 
-extern "C" void wxd_EvtHandler_Bind(
+extern "C" bool wxd_EvtHandler_Bind(
     wxd_EvtHandler_t* handler,
     WXDEventTypeCEnum eventTypeC, 
     void* rust_trampoline_fn, 
@@ -411,13 +605,13 @@ extern "C" void wxd_EvtHandler_Bind(
     if (!wx_handler) {
          wxLogWarning("wxd_EvtHandler_Bind called with null handler."); 
          if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-         return;
+         return false;
     }
 
     if (!rust_trampoline_fn || !rust_closure_ptr) {
         wxLogWarning("wxd_EvtHandler_Bind called with null trampoline (%p) or closure (%p).", rust_trampoline_fn, rust_closure_ptr);
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); } // Drop if trampoline is null but closure isn't
-        return;
+        return false;
     }
 
     // Get or create the custom event handler
@@ -425,7 +619,7 @@ extern "C" void wxd_EvtHandler_Bind(
     if (!customHandler) {
         wxLogWarning("wxd_EvtHandler_Bind: Failed to create custom handler.");
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return false;
     }
 
     // Convert C enum to wxEventType
@@ -433,7 +627,7 @@ extern "C" void wxd_EvtHandler_Bind(
     if (wx_event_type_to_bind == wxEVT_NULL) {
         wxLogWarning("wxd_EvtHandler_Bind: Unsupported WXDEventTypeCEnum value %d.", (int)eventTypeC);
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return false;
     }
 
     // Use wxID_ANY for wxd_EvtHandler_Bind (non-ID-specific binding)
@@ -471,10 +665,11 @@ extern "C" void wxd_EvtHandler_Bind(
     
     // Add the closure to the vector (do this after binding to ensure cleanup on failure)
     customHandler->closureMap[map_key].push_back(new_rust_info);
+    return true;
 }
 
 // ID-specific event binding implementation
-extern "C" void wxd_EvtHandler_BindWithId(
+extern "C" bool wxd_EvtHandler_BindWithId(
     wxd_EvtHandler_t* handler,
     WXDEventTypeCEnum eventTypeC, 
     int id,
@@ -485,13 +680,13 @@ extern "C" void wxd_EvtHandler_BindWithId(
     if (!wx_handler) {
          wxLogWarning("wxd_EvtHandler_BindWithId called with null handler."); 
          if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-         return;
+         return false;
     }
 
     if (!rust_trampoline_fn || !rust_closure_ptr) {
         wxLogWarning("wxd_EvtHandler_BindWithId called with null trampoline (%p) or closure (%p).", rust_trampoline_fn, rust_closure_ptr);
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return false;
     }
 
     // Get or create the custom event handler
@@ -499,7 +694,7 @@ extern "C" void wxd_EvtHandler_BindWithId(
     if (!customHandler) {
         wxLogWarning("wxd_EvtHandler_BindWithId: Failed to create custom handler.");
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return false;
     }
 
     // Convert C enum to wxEventType
@@ -507,7 +702,7 @@ extern "C" void wxd_EvtHandler_BindWithId(
     if (wx_event_type_to_bind == wxEVT_NULL) {
         wxLogWarning("wxd_EvtHandler_BindWithId: Unsupported WXDEventTypeCEnum value %d.", (int)eventTypeC);
         if (rust_closure_ptr) { drop_rust_closure_box(rust_closure_ptr); }
-        return;
+        return false;
     }
 
     // Use the specific ID for ID-specific binding
@@ -545,6 +740,27 @@ extern "C" void wxd_EvtHandler_BindWithId(
     
     // Add the closure to the vector (do this after binding to ensure cleanup on failure)
     customHandler->closureMap[map_key].push_back(new_rust_info);
+    return true;
+}
+
+// Removes a closure bound with wxd_EvtHandler_Bind (id == wxID_ANY) or wxd_EvtHandler_BindWithId
+extern "C" bool wxd_EvtHandler_Unbind(
+    wxd_EvtHandler_t* handler,
+    WXDEventTypeCEnum eventTypeC,
+    int id,
+    void* rust_closure_ptr
+) {
+    wxEvtHandler* wx_handler = reinterpret_cast<wxEvtHandler*>(handler);
+    if (!wx_handler || !rust_closure_ptr) return false;
+
+    // Don't create a handler just to find out nothing is bound
+    WxdHandlerClientData* clientData = static_cast<WxdHandlerClientData*>(wx_handler->GetClientData());
+    if (!clientData || !clientData->handler) return false;
+
+    wxEventType wx_event_type = get_wx_event_type_for_c_enum(eventTypeC);
+    if (wx_event_type == wxEVT_NULL) return false;
+
+    return clientData->handler->UnbindClosure({wx_event_type, id}, rust_closure_ptr);
 }
 
 // --- Event Accessors (Unchanged) ---
@@ -674,6 +890,12 @@ static WXDEventTypeCEnum get_c_enum_for_wx_event_type(wxEventType wx_event_type)
         return WXD_EVENT_TYPE_MENU;
     }
 
+    // User-defined event types live outside the built-in enum range
+    int custom_c_value = 0;
+    if (LookupCustomCEnum(wx_event_type, &custom_c_value)) {
+        return static_cast<WXDEventTypeCEnum>(custom_c_value);
+    }
+
     // Fallback to the original loop for other event types
     for (int i = WXD_EVENT_TYPE_NULL; i < WXD_EVENT_TYPE_MAX; i++) {
         WXDEventTypeCEnum c_enum = static_cast<WXDEventTypeCEnum>(i);
@@ -720,6 +942,10 @@ WXDEventTypeCEnum wxd_Event_GetEventType(wxd_Event_t* event) {
 
 // Implement get_wx_event_type_for_c_enum to handle the mapping
 static wxEventType get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val) {
+    if (static_cast<int>(c_enum_val) >= WXD_EVENT_TYPE_CUSTOM_BASE) {
+        return LookupCustomWxEventType(static_cast<int>(c_enum_val));
+    }
+
     switch (c_enum_val) {
         case WXD_EVENT_TYPE_COMMAND_BUTTON_CLICKED: return wxEVT_BUTTON;
         case WXD_EVENT_TYPE_CLOSE_WINDOW: return wxEVT_CLOSE_WINDOW;
@@ -734,6 +960,18 @@ static wxEventType get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val) {
         case WXD_EVENT_TYPE_RIGHT_UP: return wxEVT_RIGHT_UP;
         case WXD_EVENT_TYPE_MIDDLE_DOWN: return wxEVT_MIDDLE_DOWN;
         case WXD_EVENT_TYPE_MIDDLE_UP: return wxEVT_MIDDLE_UP;
+        case WXD_EVENT_TYPE_LEFT_DCLICK: return wxEVT_LEFT_DCLICK;
+        case WXD_EVENT_TYPE_RIGHT_DCLICK: return wxEVT_RIGHT_DCLICK;
+        case WXD_EVENT_TYPE_MIDDLE_DCLICK: return wxEVT_MIDDLE_DCLICK;
+        case WXD_EVENT_TYPE_AUX1_DOWN: return wxEVT_AUX1_DOWN;
+        case WXD_EVENT_TYPE_AUX1_UP: return wxEVT_AUX1_UP;
+        case WXD_EVENT_TYPE_AUX1_DCLICK: return wxEVT_AUX1_DCLICK;
+        case WXD_EVENT_TYPE_AUX2_DOWN: return wxEVT_AUX2_DOWN;
+        case WXD_EVENT_TYPE_AUX2_UP: return wxEVT_AUX2_UP;
+        case WXD_EVENT_TYPE_AUX2_DCLICK: return wxEVT_AUX2_DCLICK;
+        case WXD_EVENT_TYPE_MOUSE_CAPTURE_LOST: return wxEVT_MOUSE_CAPTURE_LOST;
+        case WXD_EVENT_TYPE_CHAR_HOOK: return wxEVT_CHAR_HOOK;
+        case WXD_EVENT_TYPE_DPI_CHANGED: return wxEVT_DPI_CHANGED;
         case WXD_EVENT_TYPE_MOTION: return wxEVT_MOTION;
         case WXD_EVENT_TYPE_MOUSEWHEEL: return wxEVT_MOUSEWHEEL;
         case WXD_EVENT_TYPE_ENTER_WINDOW: return wxEVT_ENTER_WINDOW;
@@ -1048,6 +1286,8 @@ static wxEventType get_wx_event_type_for_c_enum(WXDEventTypeCEnum c_enum_val) {
         case WXD_EVENT_TYPE_MENU_HIGHLIGHT: return wxEVT_MENU_HIGHLIGHT;
         case WXD_EVENT_TYPE_CONTEXT_MENU: return wxEVT_CONTEXT_MENU;
 
+        case WXD_EVENT_TYPE_UPDATE_UI: return wxEVT_UPDATE_UI;
+
         default: return wxEVT_NULL;
     }
 }
@@ -1074,6 +1314,15 @@ extern "C" int wxd_IsMouseButtonEvent(wxd_Event_t* event) {
             type == wxEVT_RIGHT_UP || 
             type == wxEVT_MIDDLE_DOWN || 
             type == wxEVT_MIDDLE_UP || 
+            type == wxEVT_LEFT_DCLICK ||
+            type == wxEVT_RIGHT_DCLICK ||
+            type == wxEVT_MIDDLE_DCLICK ||
+            type == wxEVT_AUX1_DOWN ||
+            type == wxEVT_AUX1_UP ||
+            type == wxEVT_AUX1_DCLICK ||
+            type == wxEVT_AUX2_DOWN ||
+            type == wxEVT_AUX2_UP ||
+            type == wxEVT_AUX2_DCLICK ||
             type == wxEVT_MOUSEWHEEL) ? 1 : 0;
 }
 
@@ -1094,7 +1343,8 @@ extern "C" int wxd_IsKeyboardEvent(wxd_Event_t* event) {
     // Check if it's any of the keyboard event types
     return (type == wxEVT_KEY_DOWN || 
             type == wxEVT_KEY_UP || 
-            type == wxEVT_CHAR) ? 1 : 0;
+            type == wxEVT_CHAR ||
+            type == wxEVT_CHAR_HOOK) ? 1 : 0;
 }
 
 extern "C" int wxd_IsSizeEvent(wxd_Event_t* event) {
@@ -1282,6 +1532,66 @@ WXD_EXPORTED int wxd_IdleEvent_GetMode() {
     return static_cast<int>(wxIdleEvent::GetMode());
 }
 
+// --- UpdateUI Event Specific Methods ---
+WXD_EXPORTED void wxd_UpdateUIEvent_Enable(wxd_Event_t* event, bool enable) {
+    wxUpdateUIEvent* ui_event = wxEvent_SafeDynamicCast<wxUpdateUIEvent>(event);
+    if (ui_event) {
+        ui_event->Enable(enable);
+    }
+}
+
+WXD_EXPORTED void wxd_UpdateUIEvent_Check(wxd_Event_t* event, bool check) {
+    wxUpdateUIEvent* ui_event = wxEvent_SafeDynamicCast<wxUpdateUIEvent>(event);
+    if (ui_event) {
+        ui_event->Check(check);
+    }
+}
+
+WXD_EXPORTED void wxd_UpdateUIEvent_SetText(wxd_Event_t* event, const char* text) {
+    wxUpdateUIEvent* ui_event = wxEvent_SafeDynamicCast<wxUpdateUIEvent>(event);
+    if (ui_event) {
+        ui_event->SetText(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(text));
+    }
+}
+
+WXD_EXPORTED void wxd_UpdateUIEvent_Show(wxd_Event_t* event, bool show) {
+    wxUpdateUIEvent* ui_event = wxEvent_SafeDynamicCast<wxUpdateUIEvent>(event);
+    if (ui_event) {
+        ui_event->Show(show);
+    }
+}
+
+WXD_EXPORTED bool wxd_UpdateUIEvent_GetEnabled(wxd_Event_t* event) {
+    wxUpdateUIEvent* ui_event = wxEvent_SafeDynamicCast<wxUpdateUIEvent>(event);
+    return ui_event ? ui_event->GetEnabled() : false;
+}
+
+WXD_EXPORTED bool wxd_UpdateUIEvent_GetChecked(wxd_Event_t* event) {
+    wxUpdateUIEvent* ui_event = wxEvent_SafeDynamicCast<wxUpdateUIEvent>(event);
+    return ui_event ? ui_event->GetChecked() : false;
+}
+
+WXD_EXPORTED bool wxd_UpdateUIEvent_GetShown(wxd_Event_t* event) {
+    wxUpdateUIEvent* ui_event = wxEvent_SafeDynamicCast<wxUpdateUIEvent>(event);
+    return ui_event ? ui_event->GetShown() : false;
+}
+
+WXD_EXPORTED void wxd_UpdateUIEvent_SetMode(int mode) {
+    wxUpdateUIEvent::SetMode(static_cast<wxUpdateUIMode>(mode));
+}
+
+WXD_EXPORTED int wxd_UpdateUIEvent_GetMode() {
+    return static_cast<int>(wxUpdateUIEvent::GetMode());
+}
+
+WXD_EXPORTED void wxd_UpdateUIEvent_SetUpdateInterval(int64_t interval_ms) {
+    wxUpdateUIEvent::SetUpdateInterval(static_cast<long>(interval_ms));
+}
+
+WXD_EXPORTED int64_t wxd_UpdateUIEvent_GetUpdateInterval() {
+    return static_cast<int64_t>(wxUpdateUIEvent::GetUpdateInterval());
+}
+
 // Mouse wheel event functions
 WXD_EXPORTED int wxd_MouseEvent_GetWheelRotation(wxd_Event_t* event) {
     if (!event) return 0;
@@ -1301,6 +1611,132 @@ WXD_EXPORTED int wxd_MouseEvent_GetWheelDelta(wxd_Event_t* event) {
     return mouse_event->GetWheelDelta();
 }
 
+WXD_EXPORTED int wxd_MouseEvent_GetWheelAxis(wxd_Event_t* event) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    if (!mouse_event) return 0;
+    return static_cast<int>(mouse_event->GetWheelAxis());
+}
+
+WXD_EXPORTED bool wxd_MouseEvent_IsWheelInverted(wxd_Event_t* event) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->IsWheelInverted() : false;
+}
+
+WXD_EXPORTED int wxd_MouseEvent_GetLinesPerAction(wxd_Event_t* event) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->GetLinesPerAction() : 0;
+}
+
+WXD_EXPORTED int wxd_MouseEvent_GetButton(wxd_Event_t* event) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->GetButton() : wxMOUSE_BTN_NONE;
+}
+
+WXD_EXPORTED bool wxd_MouseEvent_ButtonDown(wxd_Event_t* event, int button) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->ButtonDown(static_cast<wxMouseButton>(button)) : false;
+}
+
+WXD_EXPORTED bool wxd_MouseEvent_ButtonUp(wxd_Event_t* event, int button) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->ButtonUp(static_cast<wxMouseButton>(button)) : false;
+}
+
+WXD_EXPORTED bool wxd_MouseEvent_ButtonDClick(wxd_Event_t* event, int button) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->ButtonDClick(static_cast<wxMouseButton>(button)) : false;
+}
+
+WXD_EXPORTED bool wxd_MouseEvent_ButtonIsDown(wxd_Event_t* event, int button) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->ButtonIsDown(static_cast<wxMouseButton>(button)) : false;
+}
+
+WXD_EXPORTED int wxd_MouseEvent_GetClickCount(wxd_Event_t* event) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->GetClickCount() : 0;
+}
+
+WXD_EXPORTED bool wxd_MouseEvent_Dragging(wxd_Event_t* event) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->Dragging() : false;
+}
+
+WXD_EXPORTED bool wxd_MouseEvent_Moving(wxd_Event_t* event) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->Moving() : false;
+}
+
+WXD_EXPORTED int wxd_MouseEvent_GetModifiers(wxd_Event_t* event) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    return mouse_event ? mouse_event->GetModifiers() : wxMOD_NONE;
+}
+
+WXD_EXPORTED bool wxd_MouseEvent_GetLogicalPosition(wxd_Event_t* event, wxd_DC_t* dc, wxd_Point* out_pos) {
+    wxMouseEvent* mouse_event = wxEvent_SafeDynamicCast<wxMouseEvent>(event);
+    if (!mouse_event || !dc || !out_pos) return false;
+
+    wxPoint pos = mouse_event->GetLogicalPosition(*reinterpret_cast<wxDC*>(dc));
+    out_pos->x = pos.x;
+    out_pos->y = pos.y;
+    return true;
+}
+
+// Keyboard event accessors
+WXD_EXPORTED uint32_t wxd_KeyEvent_GetRawKeyCode(wxd_Event_t* event) {
+    wxKeyEvent* key_event = wxEvent_SafeDynamicCast<wxKeyEvent>(event);
+    return key_event ? key_event->GetRawKeyCode() : 0;
+}
+
+WXD_EXPORTED uint32_t wxd_KeyEvent_GetRawKeyFlags(wxd_Event_t* event) {
+    wxKeyEvent* key_event = wxEvent_SafeDynamicCast<wxKeyEvent>(event);
+    return key_event ? key_event->GetRawKeyFlags() : 0;
+}
+
+WXD_EXPORTED int wxd_KeyEvent_GetModifiers(wxd_Event_t* event) {
+    wxKeyEvent* key_event = wxEvent_SafeDynamicCast<wxKeyEvent>(event);
+    return key_event ? key_event->GetModifiers() : wxMOD_NONE;
+}
+
+WXD_EXPORTED bool wxd_KeyEvent_IsKeyInCategory(wxd_Event_t* event, int category) {
+    wxKeyEvent* key_event = wxEvent_SafeDynamicCast<wxKeyEvent>(event);
+    return key_event ? key_event->IsKeyInCategory(category) : false;
+}
+
+WXD_EXPORTED void wxd_KeyEvent_DoAllowNextEvent(wxd_Event_t* event) {
+    wxKeyEvent* key_event = wxEvent_SafeDynamicCast<wxKeyEvent>(event);
+    if (key_event) key_event->DoAllowNextEvent();
+}
+
+// DPI changed event accessors
+WXD_EXPORTED wxd_Size wxd_DPIChangedEvent_GetOldDPI(wxd_Event_t* event) {
+    wxd_Size result = { 0, 0 };
+    wxDPIChangedEvent* dpi_event = wxEvent_SafeDynamicCast<wxDPIChangedEvent>(event);
+    if (!dpi_event) return result;
+    wxSize dpi = dpi_event->GetOldDPI();
+    result.width = dpi.GetWidth();
+    result.height = dpi.GetHeight();
+    return result;
+}
+
+WXD_EXPORTED wxd_Size wxd_DPIChangedEvent_GetNewDPI(wxd_Event_t* event) {
+    wxd_Size result = { 0, 0 };
+    wxDPIChangedEvent* dpi_event = wxEvent_SafeDynamicCast<wxDPIChangedEvent>(event);
+    if (!dpi_event) return result;
+    wxSize dpi = dpi_event->GetNewDPI();
+    result.width = dpi.GetWidth();
+    result.height = dpi.GetHeight();
+    return result;
+}
+
+WXD_EXPORTED wxd_Size wxd_DPIChangedEvent_Scale(wxd_Event_t* event, wxd_Size size) {
+    wxDPIChangedEvent* dpi_event = wxEvent_SafeDynamicCast<wxDPIChangedEvent>(event);
+    if (!dpi_event) return size;
+    wxSize scaled = dpi_event->Scale(wxSize(size.width, size.height));
+    wxd_Size result = { scaled.GetWidth(), scaled.GetHeight() };
+    return result;
+}
+
 // Modifier key functions for keyboard events
 WXD_EXPORTED bool wxd_KeyEvent_ControlDown(wxd_Event_t* event) {
     if (!event) return false;
@@ -1510,4 +1946,69 @@ WXD_EXPORTED wxd_Point wxd_ContextMenuEvent_GetPosition(wxd_Event_t* event) {
     }
 
     return result;
-}
\ No newline at end of file
+}
+
+// --- Custom Event API ---
+
+WXD_EXPORTED WXDEventTypeCEnum wxd_Event_RegisterCustomType(const char* name) {
+    if (!name) return WXD_EVENT_TYPE_NULL;
+
+    CustomEventTypeRegistry& registry = GetCustomEventTypeRegistry();
+    std::lock_guard<std::mutex> lock(registry.mutex);
+
+    auto it = registry.by_name.find(name);
+    if (it != registry.by_name.end()) {
+        return static_cast<WXDEventTypeCEnum>(it->second);
+    }
+
+    int c_value = WXD_EVENT_TYPE_CUSTOM_BASE + static_cast<int>(registry.by_name.size());
+    wxEventType wx_type = wxNewEventType();
+    registry.by_name[name] = c_value;
+    registry.c_to_wx[c_value] = wx_type;
+    registry.wx_to_c[wx_type] = c_value;
+    return static_cast<WXDEventTypeCEnum>(c_value);
+}
+
+WXD_EXPORTED uint64_t wxd_EvtHandler_GetGeneration(wxd_EvtHandler_t* handler) {
+    wxEvtHandler* wx_handler = reinterpret_cast<wxEvtHandler*>(handler);
+    WxdEventHandler* customHandler = GetOrCreateEventHandler(wx_handler, handler);
+    return customHandler ? customHandler->generation : 0;
+}
+
+WXD_EXPORTED bool wxd_EvtHandler_QueueCustomEvent(wxd_EvtHandler_t* handler, uint64_t generation, WXDEventTypeCEnum eventTypeC, void* rust_payload_ptr) {
+    // Take ownership of the payload first so it is released on every early return
+    auto payload = std::make_shared<WxdRustPayload>(rust_payload_ptr);
+
+    wxEvtHandler* target = reinterpret_cast<wxEvtHandler*>(handler);
+    if (!target || generation == 0 || !wxTheApp) return false;
+
+    wxEventType wx_type = LookupCustomWxEventType(static_cast<int>(eventTypeC));
+    if (wx_type == wxEVT_NULL) {
+        wxLogWarning("wxd_EvtHandler_QueueCustomEvent: Unknown custom event type %d.", (int)eventTypeC);
+        return false;
+    }
+
+    // CallAfter queues onto the application object (QueueEvent is thread-safe) and wakes up the
+    // idle loop. The target is only touched on the GUI thread, after checking it still exists.
+    wxTheApp->CallAfter([target, generation, wx_type, payload]() {
+        auto live = GetLiveEventHandlers().find(target);
+        if (live == GetLiveEventHandlers().end() || live->second != generation) {
+            return;
+        }
+        WxdCustomEvent event(wx_type, payload);
+        wxWindow* window = wxDynamicCast(target, wxWindow);
+        if (window) {
+            event.SetId(window->GetId());
+            event.SetEventObject(window);
+        }
+        target->ProcessEvent(event);
+    });
+    return true;
+}
+
+WXD_EXPORTED void* wxd_CustomEvent_GetPayload(wxd_Event_t* event) {
+    if (!event) return nullptr;
+    WxdCustomEvent* custom_event = dynamic_cast<WxdCustomEvent*>(reinterpret_cast<wxEvent*>(event));
+    if (!custom_event) return nullptr;
+    return custom_event->GetPayload();
+}
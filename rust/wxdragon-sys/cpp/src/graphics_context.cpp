@@ -0,0 +1,425 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/graphics.h>
+#include <wx/dcmemory.h>
+#include <vector>
+
+// A context together with the memory DC it draws through when created from a bitmap
+struct WxdGraphicsContext {
+    wxGraphicsContext* gc;
+    wxMemoryDC* owned_dc;
+};
+
+static wxGraphicsContext* ToWx(wxd_GraphicsContext_t* gc) {
+    return gc ? reinterpret_cast<WxdGraphicsContext*>(gc)->gc : nullptr;
+}
+
+static wxGraphicsPath* ToWx(wxd_GraphicsPath_t* path) {
+    return reinterpret_cast<wxGraphicsPath*>(path);
+}
+
+static const wxGraphicsPath* ToWx(const wxd_GraphicsPath_t* path) {
+    return reinterpret_cast<const wxGraphicsPath*>(path);
+}
+
+static wxColour ToWxColour(const wxd_Colour_t& colour) {
+    return wxColour(colour.r, colour.g, colour.b, colour.a);
+}
+
+static wxPolygonFillMode ToFillMode(int fill_style) {
+    return fill_style == wxWINDING_RULE ? wxWINDING_RULE : wxODDEVEN_RULE;
+}
+
+static std::vector<wxPoint2DDouble> ToWxPoints(const wxd_Point2DDouble* points, int count) {
+    std::vector<wxPoint2DDouble> wx_points;
+    for (int i = 0; points && i < count; ++i) {
+        wx_points.emplace_back(points[i].x, points[i].y);
+    }
+    return wx_points;
+}
+
+static wxGraphicsGradientStops ToStops(const wxd_Colour_t* colours, const float* positions, int count) {
+    if (!colours || count < 1) return wxGraphicsGradientStops();
+    wxGraphicsGradientStops stops(ToWxColour(colours[0]), ToWxColour(colours[count - 1]));
+    for (int i = 1; positions && i < count - 1; ++i) {
+        stops.Add(ToWxColour(colours[i]), positions[i]);
+    }
+    return stops;
+}
+
+static wxd_GraphicsContext_t* Wrap(wxGraphicsContext* gc, wxMemoryDC* owned_dc) {
+    if (!gc) {
+        delete owned_dc;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_GraphicsContext_t*>(new WxdGraphicsContext{gc, owned_dc});
+}
+
+extern "C" {
+
+// --- Context creation ---
+
+WXD_EXPORTED wxd_GraphicsContext_t* wxd_GraphicsContext_CreateFromDC(wxd_DC_t* dc) {
+    wxDC* wx_dc = reinterpret_cast<wxDC*>(dc);
+    if (!wx_dc) return nullptr;
+    // Paint and client DCs are window DCs
+    if (wxWindowDC* window_dc = dynamic_cast<wxWindowDC*>(wx_dc)) {
+        return Wrap(wxGraphicsContext::Create(*window_dc), nullptr);
+    }
+    if (wxMemoryDC* memory_dc = dynamic_cast<wxMemoryDC*>(wx_dc)) {
+        return Wrap(wxGraphicsContext::Create(*memory_dc), nullptr);
+    }
+    return nullptr;
+}
+
+WXD_EXPORTED wxd_GraphicsContext_t* wxd_GraphicsContext_CreateFromBitmap(wxd_Bitmap_t* bitmap) {
+    wxBitmap* wx_bitmap = reinterpret_cast<wxBitmap*>(bitmap);
+    if (!wx_bitmap || !wx_bitmap->IsOk()) return nullptr;
+    wxMemoryDC* dc = new wxMemoryDC(*wx_bitmap);
+    return Wrap(wxGraphicsContext::Create(*dc), dc);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_Destroy(wxd_GraphicsContext_t* gc) {
+    if (!gc) return;
+    WxdGraphicsContext* wrapper = reinterpret_cast<WxdGraphicsContext*>(gc);
+    wrapper->gc->Flush();
+    delete wrapper->gc;
+    if (wrapper->owned_dc) {
+        // Deselecting stores the drawing in the bitmap
+        wrapper->owned_dc->SelectObject(wxNullBitmap);
+        delete wrapper->owned_dc;
+    }
+    delete wrapper;
+}
+
+// --- Pens, brushes and fonts ---
+
+WXD_EXPORTED wxd_GraphicsPen_t* wxd_GraphicsContext_CreatePen(wxd_GraphicsContext_t* gc, wxd_Colour_t colour, double width,
+                                                           int style, wxd_PenJoin join, wxd_PenCap cap) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc) return nullptr;
+
+    wxPenJoin wx_join = wxJOIN_ROUND;
+    switch (join) {
+        case WXD_JOIN_BEVEL: wx_join = wxJOIN_BEVEL; break;
+        case WXD_JOIN_MITER: wx_join = wxJOIN_MITER; break;
+        case WXD_JOIN_ROUND: wx_join = wxJOIN_ROUND; break;
+    }
+    wxPenCap wx_cap = wxCAP_ROUND;
+    switch (cap) {
+        case WXD_CAP_ROUND: wx_cap = wxCAP_ROUND; break;
+        case WXD_CAP_PROJECTING: wx_cap = wxCAP_PROJECTING; break;
+        case WXD_CAP_BUTT: wx_cap = wxCAP_BUTT; break;
+    }
+
+    wxGraphicsPenInfo info(ToWxColour(colour), width, static_cast<wxPenStyle>(style));
+    info.Join(wx_join).Cap(wx_cap);
+    return reinterpret_cast<wxd_GraphicsPen_t*>(new wxGraphicsPen(wx_gc->CreatePen(info)));
+}
+
+WXD_EXPORTED void wxd_GraphicsPen_Destroy(wxd_GraphicsPen_t* pen) {
+    delete reinterpret_cast<wxGraphicsPen*>(pen);
+}
+
+WXD_EXPORTED wxd_GraphicsBrush_t* wxd_GraphicsContext_CreateBrush(wxd_GraphicsContext_t* gc, wxd_Colour_t colour) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc) return nullptr;
+    wxBrush brush(ToWxColour(colour));
+    return reinterpret_cast<wxd_GraphicsBrush_t*>(new wxGraphicsBrush(wx_gc->CreateBrush(brush)));
+}
+
+WXD_EXPORTED wxd_GraphicsBrush_t* wxd_GraphicsContext_CreateLinearGradientBrush(wxd_GraphicsContext_t* gc,
+                                                                             double x1, double y1, double x2, double y2,
+                                                                             const wxd_Colour_t* colours, const float* positions, int count) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc || !colours || count < 2) return nullptr;
+    wxGraphicsBrush brush = wx_gc->CreateLinearGradientBrush(x1, y1, x2, y2, ToStops(colours, positions, count));
+    return reinterpret_cast<wxd_GraphicsBrush_t*>(new wxGraphicsBrush(brush));
+}
+
+WXD_EXPORTED wxd_GraphicsBrush_t* wxd_GraphicsContext_CreateRadialGradientBrush(wxd_GraphicsContext_t* gc,
+                                                                             double start_x, double start_y,
+                                                                             double end_x, double end_y, double radius,
+                                                                             const wxd_Colour_t* colours, const float* positions, int count) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc || !colours || count < 2) return nullptr;
+    wxGraphicsBrush brush = wx_gc->CreateRadialGradientBrush(start_x, start_y, end_x, end_y, radius,
+                                                             ToStops(colours, positions, count));
+    return reinterpret_cast<wxd_GraphicsBrush_t*>(new wxGraphicsBrush(brush));
+}
+
+WXD_EXPORTED void wxd_GraphicsBrush_Destroy(wxd_GraphicsBrush_t* brush) {
+    delete reinterpret_cast<wxGraphicsBrush*>(brush);
+}
+
+WXD_EXPORTED wxd_GraphicsFont_t* wxd_GraphicsContext_CreateFont(wxd_GraphicsContext_t* gc, const wxd_Font_t* font, wxd_Colour_t colour) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    const wxFont* wx_font = reinterpret_cast<const wxFont*>(font);
+    if (!wx_gc || !wx_font) return nullptr;
+    return reinterpret_cast<wxd_GraphicsFont_t*>(new wxGraphicsFont(wx_gc->CreateFont(*wx_font, ToWxColour(colour))));
+}
+
+WXD_EXPORTED void wxd_GraphicsFont_Destroy(wxd_GraphicsFont_t* font) {
+    delete reinterpret_cast<wxGraphicsFont*>(font);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_SetPen(wxd_GraphicsContext_t* gc, const wxd_GraphicsPen_t* pen) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc) return;
+    wx_gc->SetPen(pen ? *reinterpret_cast<const wxGraphicsPen*>(pen) : wxNullGraphicsPen);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_SetBrush(wxd_GraphicsContext_t* gc, const wxd_GraphicsBrush_t* brush) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc) return;
+    wx_gc->SetBrush(brush ? *reinterpret_cast<const wxGraphicsBrush*>(brush) : wxNullGraphicsBrush);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_SetFont(wxd_GraphicsContext_t* gc, const wxd_GraphicsFont_t* font) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc || !font) return;
+    wx_gc->SetFont(*reinterpret_cast<const wxGraphicsFont*>(font));
+}
+
+// --- Paths ---
+
+WXD_EXPORTED wxd_GraphicsPath_t* wxd_GraphicsContext_CreatePath(wxd_GraphicsContext_t* gc) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc) return nullptr;
+    return reinterpret_cast<wxd_GraphicsPath_t*>(new wxGraphicsPath(wx_gc->CreatePath()));
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_Destroy(wxd_GraphicsPath_t* path) {
+    delete ToWx(path);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_MoveToPoint(wxd_GraphicsPath_t* path, double x, double y) {
+    if (path) ToWx(path)->MoveToPoint(x, y);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddLineToPoint(wxd_GraphicsPath_t* path, double x, double y) {
+    if (path) ToWx(path)->AddLineToPoint(x, y);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddCurveToPoint(wxd_GraphicsPath_t* path, double cx1, double cy1,
+                                                   double cx2, double cy2, double x, double y) {
+    if (path) ToWx(path)->AddCurveToPoint(cx1, cy1, cx2, cy2, x, y);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddQuadCurveToPoint(wxd_GraphicsPath_t* path, double cx, double cy, double x, double y) {
+    if (path) ToWx(path)->AddQuadCurveToPoint(cx, cy, x, y);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddArc(wxd_GraphicsPath_t* path, double x, double y, double r,
+                                          double start_angle, double end_angle, bool clockwise) {
+    if (path) ToWx(path)->AddArc(x, y, r, start_angle, end_angle, clockwise);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddArcToPoint(wxd_GraphicsPath_t* path, double x1, double y1, double x2, double y2, double r) {
+    if (path) ToWx(path)->AddArcToPoint(x1, y1, x2, y2, r);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddRectangle(wxd_GraphicsPath_t* path, double x, double y, double w, double h) {
+    if (path) ToWx(path)->AddRectangle(x, y, w, h);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddRoundedRectangle(wxd_GraphicsPath_t* path, double x, double y, double w, double h, double radius) {
+    if (path) ToWx(path)->AddRoundedRectangle(x, y, w, h, radius);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddCircle(wxd_GraphicsPath_t* path, double x, double y, double r) {
+    if (path) ToWx(path)->AddCircle(x, y, r);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddEllipse(wxd_GraphicsPath_t* path, double x, double y, double w, double h) {
+    if (path) ToWx(path)->AddEllipse(x, y, w, h);
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_AddPath(wxd_GraphicsPath_t* path, const wxd_GraphicsPath_t* other) {
+    if (path && other) ToWx(path)->AddPath(*ToWx(other));
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_CloseSubpath(wxd_GraphicsPath_t* path) {
+    if (path) ToWx(path)->CloseSubpath();
+}
+
+WXD_EXPORTED wxd_Point2DDouble wxd_GraphicsPath_GetCurrentPoint(const wxd_GraphicsPath_t* path) {
+    wxd_Point2DDouble point = {0.0, 0.0};
+    if (path) {
+        wxPoint2DDouble current = ToWx(path)->GetCurrentPoint();
+        point.x = current.m_x;
+        point.y = current.m_y;
+    }
+    return point;
+}
+
+WXD_EXPORTED void wxd_GraphicsPath_GetBox(const wxd_GraphicsPath_t* path, double* x, double* y, double* w, double* h) {
+    wxDouble bx = 0, by = 0, bw = 0, bh = 0;
+    if (path) ToWx(path)->GetBox(&bx, &by, &bw, &bh);
+    if (x) *x = bx;
+    if (y) *y = by;
+    if (w) *w = bw;
+    if (h) *h = bh;
+}
+
+WXD_EXPORTED bool wxd_GraphicsPath_Contains(const wxd_GraphicsPath_t* path, double x, double y, int fill_style) {
+    return path && ToWx(path)->Contains(x, y, ToFillMode(fill_style));
+}
+
+// --- Drawing ---
+
+WXD_EXPORTED void wxd_GraphicsContext_StrokePath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc && path) wx_gc->StrokePath(*ToWx(path));
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_FillPath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path, int fill_style) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc && path) wx_gc->FillPath(*ToWx(path), ToFillMode(fill_style));
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_DrawPath(wxd_GraphicsContext_t* gc, const wxd_GraphicsPath_t* path, int fill_style) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc && path) wx_gc->DrawPath(*ToWx(path), ToFillMode(fill_style));
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_StrokeLine(wxd_GraphicsContext_t* gc, double x1, double y1, double x2, double y2) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->StrokeLine(x1, y1, x2, y2);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_StrokeLines(wxd_GraphicsContext_t* gc, const wxd_Point2DDouble* points, int count) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc || !points || count < 2) return;
+    std::vector<wxPoint2DDouble> wx_points = ToWxPoints(points, count);
+    wx_gc->StrokeLines(wx_points.size(), wx_points.data());
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_DrawLines(wxd_GraphicsContext_t* gc, const wxd_Point2DDouble* points, int count, int fill_style) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc || !points || count < 2) return;
+    std::vector<wxPoint2DDouble> wx_points = ToWxPoints(points, count);
+    wx_gc->DrawLines(wx_points.size(), wx_points.data(), ToFillMode(fill_style));
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_DrawRectangle(wxd_GraphicsContext_t* gc, double x, double y, double w, double h) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->DrawRectangle(x, y, w, h);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_DrawRoundedRectangle(wxd_GraphicsContext_t* gc, double x, double y, double w, double h, double radius) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->DrawRoundedRectangle(x, y, w, h, radius);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_DrawEllipse(wxd_GraphicsContext_t* gc, double x, double y, double w, double h) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->DrawEllipse(x, y, w, h);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_DrawBitmap(wxd_GraphicsContext_t* gc, const wxd_Bitmap_t* bitmap, double x, double y, double w, double h) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (wx_gc && wx_bitmap && wx_bitmap->IsOk()) wx_gc->DrawBitmap(*wx_bitmap, x, y, w, h);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_DrawText(wxd_GraphicsContext_t* gc, const char* text, double x, double y, double angle) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (!wx_gc || !text) return;
+    wxString wx_text = wxString::FromUTF8(text);
+    if (angle == 0.0) {
+        wx_gc->DrawText(wx_text, x, y);
+    } else {
+        wx_gc->DrawText(wx_text, x, y, angle);
+    }
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_GetTextExtent(wxd_GraphicsContext_t* gc, const char* text, double* width, double* height,
+                                                    double* descent, double* external_leading) {
+    wxDouble w = 0, h = 0, d = 0, e = 0;
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc && text) wx_gc->GetTextExtent(wxString::FromUTF8(text), &w, &h, &d, &e);
+    if (width) *width = w;
+    if (height) *height = h;
+    if (descent) *descent = d;
+    if (external_leading) *external_leading = e;
+}
+
+// --- State and transforms ---
+
+WXD_EXPORTED void wxd_GraphicsContext_PushState(wxd_GraphicsContext_t* gc) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->PushState();
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_PopState(wxd_GraphicsContext_t* gc) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->PopState();
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_Translate(wxd_GraphicsContext_t* gc, double dx, double dy) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->Translate(dx, dy);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_Rotate(wxd_GraphicsContext_t* gc, double angle) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->Rotate(angle);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_Scale(wxd_GraphicsContext_t* gc, double x_scale, double y_scale) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->Scale(x_scale, y_scale);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_ConcatTransform(wxd_GraphicsContext_t* gc, double a, double b, double c, double d,
+                                                      double tx, double ty) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->ConcatTransform(wx_gc->CreateMatrix(a, b, c, d, tx, ty));
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_ResetTransform(wxd_GraphicsContext_t* gc) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->SetTransform(wx_gc->CreateMatrix());
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_Clip(wxd_GraphicsContext_t* gc, double x, double y, double w, double h) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->Clip(x, y, w, h);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_ResetClip(wxd_GraphicsContext_t* gc) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->ResetClip();
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_BeginLayer(wxd_GraphicsContext_t* gc, double opacity) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->BeginLayer(opacity);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_EndLayer(wxd_GraphicsContext_t* gc) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->EndLayer();
+}
+
+WXD_EXPORTED bool wxd_GraphicsContext_SetAntialias(wxd_GraphicsContext_t* gc, bool enabled) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    return wx_gc && wx_gc->SetAntialiasMode(enabled ? wxANTIALIAS_DEFAULT : wxANTIALIAS_NONE);
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_GetSize(wxd_GraphicsContext_t* gc, double* width, double* height) {
+    wxDouble w = 0, h = 0;
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->GetSize(&w, &h);
+    if (width) *width = w;
+    if (height) *height = h;
+}
+
+WXD_EXPORTED void wxd_GraphicsContext_Flush(wxd_GraphicsContext_t* gc) {
+    wxGraphicsContext* wx_gc = ToWx(gc);
+    if (wx_gc) wx_gc->Flush();
+}
+
+} // extern "C"
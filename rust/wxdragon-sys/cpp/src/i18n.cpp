@@ -0,0 +1,161 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include "wxd_utils.h"
+#include <wx/intl.h>
+#include <wx/translation.h>
+#include <wx/uilocale.h>
+#include <wx/numformatter.h>
+#include <wx/datetime.h>
+#include <map>
+#include <utility>
+#include <vector>
+
+// Serves catalogs registered from memory, falling back to .mo files on disk
+class WxdTranslationsLoader : public wxTranslationsLoader {
+public:
+    void AddEmbedded(const wxString& domain, const wxString& lang, const uint8_t* data, size_t len) {
+        m_embedded[std::make_pair(domain, lang)].assign(data, data + len);
+    }
+
+    virtual wxMsgCatalog* LoadCatalog(const wxString& domain, const wxString& lang) override {
+        auto it = m_embedded.find(std::make_pair(domain, lang));
+        if (it == m_embedded.end())
+            return m_files.LoadCatalog(domain, lang);
+        // The catalog copies the messages, so the buffer needn't outlive it
+        return wxMsgCatalog::CreateFromData(
+            wxScopedCharBuffer::CreateNonOwned(it->second.data(), it->second.size()), domain);
+    }
+
+    virtual wxArrayString GetAvailableTranslations(const wxString& domain) const override {
+        wxArrayString langs = m_files.GetAvailableTranslations(domain);
+        for (const auto& entry : m_embedded) {
+            if (entry.first.first == domain && langs.Index(entry.first.second) == wxNOT_FOUND)
+                langs.Add(entry.first.second);
+        }
+        return langs;
+    }
+
+private:
+    wxFileTranslationsLoader m_files;
+    std::map<std::pair<wxString, wxString>, std::vector<char>> m_embedded;
+};
+
+// Owned by the wxTranslations object it was set on
+static WxdTranslationsLoader* s_loader = nullptr;
+
+static wxTranslations* GetTranslations() {
+    wxd_Translations_Init();
+    return wxTranslations::Get();
+}
+
+static wxDateTime ToWxDateTime(const wxd_DateTime_t* dt) {
+    if (!dt || dt->year <= 0 || dt->month >= 12 || dt->day <= 0)
+        return wxDateTime();
+    return wxDateTime(static_cast<wxDateTime::wxDateTime_t>(dt->day),
+                      static_cast<wxDateTime::Month>(dt->month), dt->year,
+                      static_cast<wxDateTime::wxDateTime_t>(dt->hour),
+                      static_cast<wxDateTime::wxDateTime_t>(dt->minute),
+                      static_cast<wxDateTime::wxDateTime_t>(dt->second));
+}
+
+extern "C" {
+
+// --- UI Locale ---
+
+WXD_EXPORTED bool wxd_UILocale_UseDefault(void) {
+    return wxUILocale::UseDefault();
+}
+
+WXD_EXPORTED char* wxd_UILocale_GetName(void) {
+    return wxd_cpp_utils::to_c_string(wxUILocale::GetCurrent().GetName());
+}
+
+// --- Translations ---
+
+WXD_EXPORTED void wxd_Translations_Init(void) {
+    wxTranslations* translations = wxTranslations::Get();
+    if (!translations) {
+        translations = new wxTranslations();
+        wxTranslations::Set(translations);
+    }
+    if (!s_loader) {
+        s_loader = new WxdTranslationsLoader();
+        translations->SetLoader(s_loader);
+    }
+}
+
+WXD_EXPORTED void wxd_Translations_SetLanguage(const char* language) {
+    GetTranslations()->SetLanguage(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(language));
+}
+
+WXD_EXPORTED void wxd_Translations_AddCatalogLookupPath(const char* dir) {
+    if (!dir) return;
+    wxFileTranslationsLoader::AddCatalogLookupPathPrefix(wxString::FromUTF8(dir));
+}
+
+WXD_EXPORTED void wxd_Translations_AddEmbeddedCatalog(const char* domain, const char* language, const uint8_t* data, size_t len) {
+    if (!domain || !language || !data) return;
+    GetTranslations();
+    s_loader->AddEmbedded(wxString::FromUTF8(domain), wxString::FromUTF8(language), data, len);
+}
+
+WXD_EXPORTED bool wxd_Translations_AddCatalog(const char* domain) {
+    if (!domain) return false;
+    return GetTranslations()->AddCatalog(wxString::FromUTF8(domain));
+}
+
+WXD_EXPORTED bool wxd_Translations_AddStdCatalog(void) {
+    return GetTranslations()->AddStdCatalog();
+}
+
+WXD_EXPORTED char* wxd_GetTranslation(const char* str, const char* domain) {
+    if (!str) return strdup("");
+    return wxd_cpp_utils::to_c_string(
+        wxGetTranslation(wxString::FromUTF8(str), WXD_STR_TO_WX_STRING_UTF8_NULL_OK(domain)));
+}
+
+WXD_EXPORTED char* wxd_GetTranslationPlural(const char* singular, const char* plural, unsigned int n, const char* domain) {
+    if (!singular || !plural) return strdup("");
+    return wxd_cpp_utils::to_c_string(wxGetTranslation(wxString::FromUTF8(singular), wxString::FromUTF8(plural), n,
+                                                       WXD_STR_TO_WX_STRING_UTF8_NULL_OK(domain)));
+}
+
+// --- Locale-aware Formatting ---
+
+WXD_EXPORTED char* wxd_Locale_FormatNumber(double value, int precision, bool thousands_sep) {
+    int style = thousands_sep ? wxNumberFormatter::Style_WithThousandsSep : wxNumberFormatter::Style_None;
+    if (precision < 0) {
+        // Enough digits for any double, with the zeroes this leaves removed
+        precision = 15;
+        style |= wxNumberFormatter::Style_NoTrailingZeroes;
+    }
+    return wxd_cpp_utils::to_c_string(wxNumberFormatter::ToString(value, precision, style));
+}
+
+WXD_EXPORTED char* wxd_Locale_FormatInteger(int64_t value, bool thousands_sep) {
+    int style = thousands_sep ? wxNumberFormatter::Style_WithThousandsSep : wxNumberFormatter::Style_None;
+    return wxd_cpp_utils::to_c_string(wxNumberFormatter::ToString(static_cast<wxLongLong_t>(value), style));
+}
+
+WXD_EXPORTED bool wxd_Locale_ParseNumber(const char* str, double* out_value) {
+    if (!str || !out_value) return false;
+    return wxNumberFormatter::FromString(wxString::FromUTF8(str), out_value);
+}
+
+WXD_EXPORTED char* wxd_Locale_FormatDateTime(const wxd_DateTime_t* datetime, wxd_DateFormat format) {
+    wxDateTime dt = ToWxDateTime(datetime);
+    if (!dt.IsValid()) return strdup("");
+
+    wxLocaleInfo info;
+    switch (format) {
+        case WXD_DATE_FORMAT_LONG_DATE: info = wxLOCALE_LONG_DATE_FMT; break;
+        case WXD_DATE_FORMAT_TIME: info = wxLOCALE_TIME_FMT; break;
+        case WXD_DATE_FORMAT_DATE_TIME: info = wxLOCALE_DATE_TIME_FMT; break;
+        case WXD_DATE_FORMAT_SHORT_DATE:
+        default: info = wxLOCALE_SHORT_DATE_FMT; break;
+    }
+    return wxd_cpp_utils::to_c_string(dt.Format(wxUILocale::GetCurrent().GetInfo(info)));
+}
+
+} // extern "C"
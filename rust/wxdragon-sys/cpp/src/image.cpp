@@ -0,0 +1,360 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/image.h>
+#include <wx/mstream.h>
+#include <cstdlib>
+
+static wxImage* ToWx(wxd_Image_t* image) {
+    return reinterpret_cast<wxImage*>(image);
+}
+
+static const wxImage* ToWx(const wxd_Image_t* image) {
+    return reinterpret_cast<const wxImage*>(image);
+}
+
+static bool IsOk(const wxd_Image_t* image) {
+    return image && ToWx(image)->IsOk();
+}
+
+// Returns a heap copy sharing the image data, or NULL if the image isn't valid
+static wxd_Image_t* Wrap(const wxImage& image) {
+    if (!image.IsOk()) return nullptr;
+    return reinterpret_cast<wxd_Image_t*>(new wxImage(image));
+}
+
+static wxBitmapType ToBitmapType(wxd_ImageType type) {
+    switch (type) {
+        case WXD_IMAGE_TYPE_PNG: return wxBITMAP_TYPE_PNG;
+        case WXD_IMAGE_TYPE_JPEG: return wxBITMAP_TYPE_JPEG;
+        case WXD_IMAGE_TYPE_BMP: return wxBITMAP_TYPE_BMP;
+        case WXD_IMAGE_TYPE_GIF: return wxBITMAP_TYPE_GIF;
+        case WXD_IMAGE_TYPE_ICO: return wxBITMAP_TYPE_ICO;
+        case WXD_IMAGE_TYPE_TIFF: return wxBITMAP_TYPE_TIFF;
+        default: return wxBITMAP_TYPE_ANY;
+    }
+}
+
+static wxd_ImageType FromBitmapType(wxBitmapType type) {
+    switch (type) {
+        case wxBITMAP_TYPE_PNG: return WXD_IMAGE_TYPE_PNG;
+        case wxBITMAP_TYPE_JPEG: return WXD_IMAGE_TYPE_JPEG;
+        case wxBITMAP_TYPE_BMP: return WXD_IMAGE_TYPE_BMP;
+        case wxBITMAP_TYPE_GIF: return WXD_IMAGE_TYPE_GIF;
+        case wxBITMAP_TYPE_ICO: return WXD_IMAGE_TYPE_ICO;
+        case wxBITMAP_TYPE_TIFF: return WXD_IMAGE_TYPE_TIFF;
+        default: return WXD_IMAGE_TYPE_ANY;
+    }
+}
+
+static wxImageResizeQuality ToResizeQuality(wxd_ImageResizeQuality quality) {
+    switch (quality) {
+        case WXD_IMAGE_QUALITY_HIGH: return wxIMAGE_QUALITY_HIGH;
+        case WXD_IMAGE_QUALITY_NEAREST: return wxIMAGE_QUALITY_NEAREST;
+        case WXD_IMAGE_QUALITY_BILINEAR: return wxIMAGE_QUALITY_BILINEAR;
+        case WXD_IMAGE_QUALITY_BICUBIC: return wxIMAGE_QUALITY_BICUBIC;
+        case WXD_IMAGE_QUALITY_BOX_AVERAGE: return wxIMAGE_QUALITY_BOX_AVERAGE;
+        default: return wxIMAGE_QUALITY_NORMAL;
+    }
+}
+
+// The app registers the handlers at startup; this covers images loaded or
+// saved before it runs, such as in tests
+static void EnsureImageHandlers() {
+    if (!wxImage::FindHandler(wxBITMAP_TYPE_PNG)) wxInitAllImageHandlers();
+}
+
+static bool Contains(const wxImage& image, int x, int y) {
+    return x >= 0 && y >= 0 && x < image.GetWidth() && y < image.GetHeight();
+}
+
+extern "C" {
+
+// --- Creation ---
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_Create(int width, int height) {
+    if (width <= 0 || height <= 0) return nullptr;
+    return Wrap(wxImage(width, height, true));
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_CreateFromRGBA(const unsigned char* data, int width, int height) {
+    if (!data || width <= 0 || height <= 0) return nullptr;
+
+    // wxImage frees its buffers with free(), so they must come from malloc
+    size_t num_pixels = static_cast<size_t>(width) * static_cast<size_t>(height);
+    unsigned char* rgb_data = static_cast<unsigned char*>(malloc(num_pixels * 3));
+    unsigned char* alpha_data = static_cast<unsigned char*>(malloc(num_pixels));
+    if (!rgb_data || !alpha_data) {
+        free(rgb_data);
+        free(alpha_data);
+        return nullptr;
+    }
+
+    for (size_t i = 0; i < num_pixels; ++i) {
+        rgb_data[i * 3 + 0] = data[i * 4 + 0];
+        rgb_data[i * 3 + 1] = data[i * 4 + 1];
+        rgb_data[i * 3 + 2] = data[i * 4 + 2];
+        alpha_data[i] = data[i * 4 + 3];
+    }
+
+    return Wrap(wxImage(width, height, rgb_data, alpha_data));
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_CreateFromFile(const char* path, wxd_ImageType type) {
+    if (!path) return nullptr;
+    // Don't show an error message box for unreadable files
+    wxLogNull no_log;
+    EnsureImageHandlers();
+    wxImage image;
+    if (!image.LoadFile(wxString::FromUTF8(path), ToBitmapType(type))) return nullptr;
+    return Wrap(image);
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_CreateFromData(const unsigned char* data, size_t len, wxd_ImageType type) {
+    if (!data || len == 0) return nullptr;
+    wxLogNull no_log;
+    EnsureImageHandlers();
+    wxMemoryInputStream stream(data, len);
+    wxImage image;
+    if (!image.LoadFile(stream, ToBitmapType(type))) return nullptr;
+    return Wrap(image);
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_CreateFromBitmap(const wxd_Bitmap_t* bitmap) {
+    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!wx_bitmap || !wx_bitmap->IsOk()) return nullptr;
+    return Wrap(wx_bitmap->ConvertToImage());
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_Clone(const wxd_Image_t* image) {
+    if (!IsOk(image)) return nullptr;
+    return Wrap(ToWx(image)->Copy());
+}
+
+WXD_EXPORTED void wxd_Image_Destroy(wxd_Image_t* image) {
+    delete ToWx(image);
+}
+
+WXD_EXPORTED wxd_Bitmap_t* wxd_Bitmap_CreateFromImage(const wxd_Image_t* image) {
+    if (!IsOk(image)) return nullptr;
+    wxBitmap* bitmap = new wxBitmap(*ToWx(image));
+    if (!bitmap->IsOk()) {
+        delete bitmap;
+        return nullptr;
+    }
+    return reinterpret_cast<wxd_Bitmap_t*>(bitmap);
+}
+
+// --- Saving ---
+
+WXD_EXPORTED bool wxd_Image_SaveFile(const wxd_Image_t* image, const char* path, wxd_ImageType type) {
+    if (!IsOk(image) || !path) return false;
+    wxLogNull no_log;
+    EnsureImageHandlers();
+    if (type == WXD_IMAGE_TYPE_ANY) {
+        return ToWx(image)->SaveFile(wxString::FromUTF8(path));
+    }
+    return ToWx(image)->SaveFile(wxString::FromUTF8(path), ToBitmapType(type));
+}
+
+WXD_EXPORTED unsigned char* wxd_Image_SaveToData(const wxd_Image_t* image, wxd_ImageType type, size_t* len) {
+    if (len) *len = 0;
+    if (!IsOk(image) || !len || type == WXD_IMAGE_TYPE_ANY) return nullptr;
+    wxLogNull no_log;
+    EnsureImageHandlers();
+    wxMemoryOutputStream stream;
+    if (!ToWx(image)->SaveFile(stream, ToBitmapType(type))) return nullptr;
+
+    size_t size = stream.GetSize();
+    unsigned char* data = static_cast<unsigned char*>(malloc(size > 0 ? size : 1));
+    if (!data) return nullptr;
+    *len = stream.CopyTo(data, size);
+    return data;
+}
+
+WXD_EXPORTED void wxd_Image_FreeData(unsigned char* data) {
+    free(data);
+}
+
+// --- Properties ---
+
+WXD_EXPORTED bool wxd_Image_IsOk(const wxd_Image_t* image) {
+    return IsOk(image);
+}
+
+WXD_EXPORTED int wxd_Image_GetWidth(const wxd_Image_t* image) {
+    return IsOk(image) ? ToWx(image)->GetWidth() : 0;
+}
+
+WXD_EXPORTED int wxd_Image_GetHeight(const wxd_Image_t* image) {
+    return IsOk(image) ? ToWx(image)->GetHeight() : 0;
+}
+
+WXD_EXPORTED wxd_ImageType wxd_Image_GetType(const wxd_Image_t* image) {
+    return IsOk(image) ? FromBitmapType(ToWx(image)->GetType()) : WXD_IMAGE_TYPE_ANY;
+}
+
+// --- Pixels ---
+
+WXD_EXPORTED unsigned char* wxd_Image_GetRGBAData(const wxd_Image_t* image) {
+    if (!IsOk(image)) return nullptr;
+    const wxImage& wx_image = *ToWx(image);
+    size_t num_pixels = static_cast<size_t>(wx_image.GetWidth()) * static_cast<size_t>(wx_image.GetHeight());
+    unsigned char* rgba_data = static_cast<unsigned char*>(malloc(num_pixels * 4));
+    if (!rgba_data) return nullptr;
+
+    const unsigned char* rgb_data = wx_image.GetData();
+    const unsigned char* alpha_data = wx_image.HasAlpha() ? wx_image.GetAlpha() : nullptr;
+    for (size_t i = 0; i < num_pixels; ++i) {
+        unsigned char r = rgb_data[i * 3 + 0];
+        unsigned char g = rgb_data[i * 3 + 1];
+        unsigned char b = rgb_data[i * 3 + 2];
+        rgba_data[i * 4 + 0] = r;
+        rgba_data[i * 4 + 1] = g;
+        rgba_data[i * 4 + 2] = b;
+        if (alpha_data) {
+            rgba_data[i * 4 + 3] = alpha_data[i];
+        } else if (wx_image.HasMask() && r == wx_image.GetMaskRed() && g == wx_image.GetMaskGreen() &&
+                   b == wx_image.GetMaskBlue()) {
+            rgba_data[i * 4 + 3] = 0;
+        } else {
+            rgba_data[i * 4 + 3] = 255;
+        }
+    }
+    return rgba_data;
+}
+
+WXD_EXPORTED wxd_Colour_t wxd_Image_GetPixel(const wxd_Image_t* image, int x, int y) {
+    wxd_Colour_t colour = {0, 0, 0, 0};
+    if (!IsOk(image) || !Contains(*ToWx(image), x, y)) return colour;
+    const wxImage& wx_image = *ToWx(image);
+    colour.r = wx_image.GetRed(x, y);
+    colour.g = wx_image.GetGreen(x, y);
+    colour.b = wx_image.GetBlue(x, y);
+    colour.a = wx_image.HasAlpha() ? wx_image.GetAlpha(x, y) : 255;
+    return colour;
+}
+
+WXD_EXPORTED void wxd_Image_SetPixel(wxd_Image_t* image, int x, int y, wxd_Colour_t colour) {
+    if (!IsOk(image) || !Contains(*ToWx(image), x, y)) return;
+    wxImage& wx_image = *ToWx(image);
+    wx_image.SetRGB(x, y, colour.r, colour.g, colour.b);
+    if (wx_image.HasAlpha()) {
+        wx_image.SetAlpha(x, y, colour.a);
+    }
+}
+
+// --- Transformations ---
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_Scale(const wxd_Image_t* image, int width, int height, wxd_ImageResizeQuality quality) {
+    if (!IsOk(image) || width <= 0 || height <= 0) return nullptr;
+    return Wrap(ToWx(image)->Scale(width, height, ToResizeQuality(quality)));
+}
+
+WXD_EXPORTED void wxd_Image_Rescale(wxd_Image_t* image, int width, int height, wxd_ImageResizeQuality quality) {
+    if (!IsOk(image) || width <= 0 || height <= 0) return;
+    ToWx(image)->Rescale(width, height, ToResizeQuality(quality));
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_Rotate90(const wxd_Image_t* image, bool clockwise) {
+    if (!IsOk(image)) return nullptr;
+    return Wrap(ToWx(image)->Rotate90(clockwise));
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_Rotate180(const wxd_Image_t* image) {
+    if (!IsOk(image)) return nullptr;
+    return Wrap(ToWx(image)->Rotate180());
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_Rotate(const wxd_Image_t* image, double angle, bool interpolating) {
+    if (!IsOk(image)) return nullptr;
+    const wxImage& wx_image = *ToWx(image);
+    wxPoint centre(wx_image.GetWidth() / 2, wx_image.GetHeight() / 2);
+    return Wrap(wx_image.Rotate(angle, centre, interpolating));
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_Mirror(const wxd_Image_t* image, bool horizontally) {
+    if (!IsOk(image)) return nullptr;
+    return Wrap(ToWx(image)->Mirror(horizontally));
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_GetSubImage(const wxd_Image_t* image, wxd_Rect rect) {
+    if (!IsOk(image) || rect.width <= 0 || rect.height <= 0) return nullptr;
+    const wxImage& wx_image = *ToWx(image);
+    wxRect wx_rect(rect.x, rect.y, rect.width, rect.height);
+    if (!wxRect(wx_image.GetSize()).Contains(wx_rect)) return nullptr;
+    return Wrap(wx_image.GetSubImage(wx_rect));
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_ConvertToGreyscale(const wxd_Image_t* image) {
+    if (!IsOk(image)) return nullptr;
+    return Wrap(ToWx(image)->ConvertToGreyscale());
+}
+
+WXD_EXPORTED wxd_Image_t* wxd_Image_ConvertToDisabled(const wxd_Image_t* image, unsigned char brightness) {
+    if (!IsOk(image)) return nullptr;
+    return Wrap(ToWx(image)->ConvertToDisabled(brightness));
+}
+
+WXD_EXPORTED void wxd_Image_Paste(wxd_Image_t* image, const wxd_Image_t* other, int x, int y) {
+    if (!IsOk(image) || !IsOk(other)) return;
+    ToWx(image)->Paste(*ToWx(other), x, y);
+}
+
+// --- Alpha and mask ---
+
+WXD_EXPORTED bool wxd_Image_HasAlpha(const wxd_Image_t* image) {
+    return IsOk(image) && ToWx(image)->HasAlpha();
+}
+
+WXD_EXPORTED void wxd_Image_InitAlpha(wxd_Image_t* image) {
+    if (!IsOk(image) || ToWx(image)->HasAlpha()) return;
+    ToWx(image)->InitAlpha();
+}
+
+WXD_EXPORTED void wxd_Image_ClearAlpha(wxd_Image_t* image) {
+    if (!IsOk(image)) return;
+    ToWx(image)->ClearAlpha();
+}
+
+WXD_EXPORTED unsigned char wxd_Image_GetAlpha(const wxd_Image_t* image, int x, int y) {
+    if (!IsOk(image) || !Contains(*ToWx(image), x, y)) return 0;
+    return ToWx(image)->HasAlpha() ? ToWx(image)->GetAlpha(x, y) : 255;
+}
+
+WXD_EXPORTED void wxd_Image_SetAlpha(wxd_Image_t* image, int x, int y, unsigned char alpha) {
+    if (!IsOk(image) || !Contains(*ToWx(image), x, y)) return;
+    wxImage& wx_image = *ToWx(image);
+    if (!wx_image.HasAlpha()) wx_image.InitAlpha();
+    wx_image.SetAlpha(x, y, alpha);
+}
+
+WXD_EXPORTED bool wxd_Image_HasMask(const wxd_Image_t* image) {
+    return IsOk(image) && ToWx(image)->HasMask();
+}
+
+WXD_EXPORTED void wxd_Image_SetMask(wxd_Image_t* image, bool has_mask) {
+    if (!IsOk(image)) return;
+    ToWx(image)->SetMask(has_mask);
+}
+
+WXD_EXPORTED void wxd_Image_SetMaskColour(wxd_Image_t* image, wxd_Colour_t colour) {
+    if (!IsOk(image)) return;
+    ToWx(image)->SetMaskColour(colour.r, colour.g, colour.b);
+}
+
+WXD_EXPORTED wxd_Colour_t wxd_Image_GetMaskColour(const wxd_Image_t* image) {
+    wxd_Colour_t colour = {0, 0, 0, 255};
+    if (!IsOk(image)) return colour;
+    colour.r = ToWx(image)->GetMaskRed();
+    colour.g = ToWx(image)->GetMaskGreen();
+    colour.b = ToWx(image)->GetMaskBlue();
+    return colour;
+}
+
+WXD_EXPORTED bool wxd_Image_ConvertAlphaToMask(wxd_Image_t* image, unsigned char threshold) {
+    if (!IsOk(image)) return false;
+    return ToWx(image)->ConvertAlphaToMask(threshold);
+}
+
+} // extern "C"
@@ -0,0 +1,115 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/log.h>
+
+// Passes every record to a callback set from Rust. A log window that was the active
+// target is kept alive and chained, since Rust may still hold a handle to it.
+class WxdCallbackLog : public wxLog {
+public:
+    WxdCallbackLog(wxd_LogCallback callback, wxLog* chained) : m_callback(callback), m_chained(chained) {}
+    virtual ~WxdCallbackLog() { delete m_chained; }
+
+    // Gives up ownership of the chained log window
+    wxLog* DetachChained() {
+        wxLog* chained = m_chained;
+        m_chained = nullptr;
+        return chained;
+    }
+
+protected:
+    virtual void DoLogRecord(wxLogLevel level, const wxString& msg, const wxLogRecordInfo& info) override {
+        // The callback may log again, e.g. through a Rust logger that forwards to wx.
+        // The chained window is fed inside the guard too, so that a callback target
+        // it passes messages on to does not see them twice.
+        static thread_local bool in_callback = false;
+        if (in_callback)
+            return;
+        in_callback = true;
+        m_callback(static_cast<int>(level), msg.utf8_str(), info.component ? info.component : "",
+                   info.filename, info.line);
+        if (m_chained)
+            m_chained->LogRecord(level, msg, info);
+        in_callback = false;
+    }
+
+private:
+    wxd_LogCallback m_callback;
+    wxLog* m_chained;
+};
+
+// Takes the log window out of the active target, if there is one, so it is not deleted
+// along with the target
+static wxLog* DetachLogWindow(wxLog* target) {
+    if (WxdCallbackLog* callback_log = dynamic_cast<WxdCallbackLog*>(target))
+        return callback_log->DetachChained();
+    if (dynamic_cast<wxLogWindow*>(target))
+        return target;
+    return nullptr;
+}
+
+extern "C" {
+
+// --- Log Target ---
+
+WXD_EXPORTED void wxd_Log_SetCallbackTarget(wxd_LogCallback callback) {
+    wxLog* active = wxLog::GetActiveTarget();
+    if (callback) {
+        wxLog* log_window = DetachLogWindow(active);
+        wxLog::SetActiveTarget(new WxdCallbackLog(callback, log_window));
+        if (active != log_window)
+            delete active;
+    } else if (dynamic_cast<WxdCallbackLog*>(active)) {
+        // Without a log window, wxWidgets creates the default target again when it is next needed
+        wxLog::SetActiveTarget(DetachLogWindow(active));
+        delete active;
+    }
+}
+
+WXD_EXPORTED void wxd_Log_SetLogLevel(int level) {
+    wxLog::SetLogLevel(static_cast<wxLogLevel>(level));
+}
+
+WXD_EXPORTED void wxd_Log_SetVerbose(bool verbose) {
+    wxLog::SetVerbose(verbose);
+}
+
+WXD_EXPORTED bool wxd_Log_GetVerbose(void) {
+    return wxLog::GetVerbose();
+}
+
+WXD_EXPORTED void wxd_Log_LogText(int level, const char* message) {
+    if (!message) return;
+    wxLogGeneric(static_cast<wxLogLevel>(level), "%s", wxString::FromUTF8(message));
+}
+
+WXD_EXPORTED void wxd_Log_SetFrameStatus(wxd_Frame_t* frame, const char* message) {
+    wxFrame* wx_frame = reinterpret_cast<wxFrame*>(frame);
+    if (!wx_frame || !message) return;
+    // The frame may have been destroyed since the message was queued
+    if (!wxTopLevelWindows.Find(wx_frame) || wx_frame->IsBeingDeleted()) return;
+    if (wx_frame->GetStatusBar())
+        wx_frame->SetStatusText(wxString::FromUTF8(message));
+}
+
+// --- Log Window ---
+
+WXD_EXPORTED wxd_LogWindow_t* wxd_LogWindow_Create(wxd_Window_t* parent, const char* title, bool show, bool pass_to_old) {
+    wxWindow* wx_parent = reinterpret_cast<wxWindow*>(parent);
+    wxString wx_title = title ? wxString::FromUTF8(title) : wxString("Log");
+    // Installs itself as the active target
+    wxLogWindow* log_window = new wxLogWindow(wx_parent, wx_title, show, pass_to_old);
+    return reinterpret_cast<wxd_LogWindow_t*>(log_window);
+}
+
+WXD_EXPORTED void wxd_LogWindow_Show(wxd_LogWindow_t* log_window, bool show) {
+    if (!log_window) return;
+    reinterpret_cast<wxLogWindow*>(log_window)->Show(show);
+}
+
+WXD_EXPORTED wxd_Frame_t* wxd_LogWindow_GetFrame(wxd_LogWindow_t* log_window) {
+    if (!log_window) return nullptr;
+    return reinterpret_cast<wxd_Frame_t*>(reinterpret_cast<wxLogWindow*>(log_window)->GetFrame());
+}
+
+} // extern "C"
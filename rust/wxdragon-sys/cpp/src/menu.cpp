@@ -100,4 +100,17 @@ WXD_EXPORTED bool wxd_MenuItem_IsChecked(wxd_MenuItem_t* item) {
     return wx_item->IsChecked();
 }
 
+WXD_EXPORTED bool wxd_MenuItem_GetAccel(wxd_MenuItem_t* item, wxd_AcceleratorEntry* out_entry) {
+    if (!item || !out_entry) return false;
+    wxMenuItem* wx_item = reinterpret_cast<wxMenuItem*>(item);
+    // GetAccel() parses the "\t" suffix of the label and returns a new entry
+    wxAcceleratorEntry* accel = wx_item->GetAccel();
+    if (!accel) return false;
+    *out_entry = wxd_cpp_utils::from_wx(*accel);
+    // The parsed entry has no command ID; it's the item's
+    out_entry->id = wx_item->GetId();
+    delete accel;
+    return true;
+}
+
 } // extern "C" 
\ No newline at end of file
@@ -0,0 +1,193 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <algorithm>
+#include <iterator>
+#include <list>
+#include <vector>
+
+static wxPen* ToWx(wxd_Pen_t* pen) {
+    return reinterpret_cast<wxPen*>(pen);
+}
+
+static const wxPen* ToWx(const wxd_Pen_t* pen) {
+    return reinterpret_cast<const wxPen*>(pen);
+}
+
+static wxd_Colour_t FromWxColour(const wxColour& wx_colour) {
+    wxd_Colour_t colour = {0, 0, 0, 255};
+    if (wx_colour.IsOk()) {
+        colour.r = wx_colour.Red();
+        colour.g = wx_colour.Green();
+        colour.b = wx_colour.Blue();
+        colour.a = wx_colour.Alpha();
+    }
+    return colour;
+}
+
+// wxPen keeps a pointer to its dash array rather than a copy, and copies of the
+// pen (such as the one a DC keeps) share it. Each array is therefore stored here
+// along with copies of the pens using it, and released once those copies are the
+// only pens left that refer to it.
+struct DashPattern {
+    std::vector<wxDash> dashes;
+    std::vector<wxPen> users;
+};
+
+// Never destroyed, so the pens it holds don't outlive wxWidgets' cleanup
+static std::list<DashPattern>& GetDashPatterns() {
+    static std::list<DashPattern>* patterns = new std::list<DashPattern>();
+    return *patterns;
+}
+
+static void ReleaseUnusedDashes() {
+    std::list<DashPattern>& patterns = GetDashPatterns();
+    for (auto it = patterns.begin(); it != patterns.end();) {
+        std::vector<wxPen>& users = it->users;
+        users.erase(std::remove_if(users.begin(), users.end(), [](const wxPen& user) {
+            return user.GetRefData()->GetRefCount() == 1;
+        }), users.end());
+        it = users.empty() ? patterns.erase(it) : std::next(it);
+    }
+}
+
+// Changing a shared pen gives it its own copy of the pen data, still pointing
+// at the same dash array, so pens are tracked again after every change.
+static void TrackDashes(const wxPen& pen) {
+    if (!pen.IsOk() || !pen.GetDash()) return;
+    for (DashPattern& pattern : GetDashPatterns()) {
+        if (pattern.dashes.data() != pen.GetDash()) continue;
+        for (const wxPen& user : pattern.users) {
+            if (user.GetRefData() == pen.GetRefData()) return;
+        }
+        pattern.users.push_back(pen);
+        return;
+    }
+}
+
+extern "C" {
+
+WXD_EXPORTED wxd_Pen_t* wxd_Pen_Create(wxd_Colour_t colour, int width, int style) {
+    wxColour wx_colour(colour.r, colour.g, colour.b, colour.a);
+    return reinterpret_cast<wxd_Pen_t*>(new wxPen(wx_colour, width, static_cast<wxPenStyle>(style)));
+}
+
+WXD_EXPORTED wxd_Pen_t* wxd_Pen_Clone(const wxd_Pen_t* pen) {
+    if (!pen) return nullptr;
+    return reinterpret_cast<wxd_Pen_t*>(new wxPen(*ToWx(pen)));
+}
+
+WXD_EXPORTED void wxd_Pen_Destroy(wxd_Pen_t* pen) {
+    delete ToWx(pen);
+}
+
+WXD_EXPORTED bool wxd_Pen_IsOk(const wxd_Pen_t* pen) {
+    return pen && ToWx(pen)->IsOk();
+}
+
+WXD_EXPORTED void wxd_Pen_SetColour(wxd_Pen_t* pen, wxd_Colour_t colour) {
+    if (!pen) return;
+    ToWx(pen)->SetColour(wxColour(colour.r, colour.g, colour.b, colour.a));
+    TrackDashes(*ToWx(pen));
+}
+
+WXD_EXPORTED wxd_Colour_t wxd_Pen_GetColour(const wxd_Pen_t* pen) {
+    return pen ? FromWxColour(ToWx(pen)->GetColour()) : FromWxColour(wxNullColour);
+}
+
+WXD_EXPORTED void wxd_Pen_SetWidth(wxd_Pen_t* pen, int width) {
+    if (!pen) return;
+    ToWx(pen)->SetWidth(width);
+    TrackDashes(*ToWx(pen));
+}
+
+WXD_EXPORTED int wxd_Pen_GetWidth(const wxd_Pen_t* pen) {
+    return pen ? ToWx(pen)->GetWidth() : 0;
+}
+
+WXD_EXPORTED void wxd_Pen_SetStyle(wxd_Pen_t* pen, int style) {
+    if (!pen) return;
+    ToWx(pen)->SetStyle(static_cast<wxPenStyle>(style));
+    TrackDashes(*ToWx(pen));
+}
+
+WXD_EXPORTED int wxd_Pen_GetStyle(const wxd_Pen_t* pen) {
+    return pen ? static_cast<int>(ToWx(pen)->GetStyle()) : static_cast<int>(wxPENSTYLE_SOLID);
+}
+
+WXD_EXPORTED void wxd_Pen_SetJoin(wxd_Pen_t* pen, wxd_PenJoin join) {
+    if (!pen) return;
+    switch (join) {
+        case WXD_JOIN_BEVEL: ToWx(pen)->SetJoin(wxJOIN_BEVEL); break;
+        case WXD_JOIN_MITER: ToWx(pen)->SetJoin(wxJOIN_MITER); break;
+        case WXD_JOIN_ROUND: ToWx(pen)->SetJoin(wxJOIN_ROUND); break;
+    }
+    TrackDashes(*ToWx(pen));
+}
+
+WXD_EXPORTED wxd_PenJoin wxd_Pen_GetJoin(const wxd_Pen_t* pen) {
+    if (!pen) return WXD_JOIN_ROUND;
+    switch (ToWx(pen)->GetJoin()) {
+        case wxJOIN_BEVEL: return WXD_JOIN_BEVEL;
+        case wxJOIN_MITER: return WXD_JOIN_MITER;
+        default: return WXD_JOIN_ROUND;
+    }
+}
+
+WXD_EXPORTED void wxd_Pen_SetCap(wxd_Pen_t* pen, wxd_PenCap cap) {
+    if (!pen) return;
+    switch (cap) {
+        case WXD_CAP_ROUND: ToWx(pen)->SetCap(wxCAP_ROUND); break;
+        case WXD_CAP_PROJECTING: ToWx(pen)->SetCap(wxCAP_PROJECTING); break;
+        case WXD_CAP_BUTT: ToWx(pen)->SetCap(wxCAP_BUTT); break;
+    }
+    TrackDashes(*ToWx(pen));
+}
+
+WXD_EXPORTED wxd_PenCap wxd_Pen_GetCap(const wxd_Pen_t* pen) {
+    if (!pen) return WXD_CAP_ROUND;
+    switch (ToWx(pen)->GetCap()) {
+        case wxCAP_PROJECTING: return WXD_CAP_PROJECTING;
+        case wxCAP_BUTT: return WXD_CAP_BUTT;
+        default: return WXD_CAP_ROUND;
+    }
+}
+
+WXD_EXPORTED void wxd_Pen_SetDashes(wxd_Pen_t* pen, const int* dashes, int count) {
+    if (!pen) return;
+    if (!dashes || count <= 0) {
+        ToWx(pen)->SetDashes(0, nullptr);
+        // A user dash pen without dashes can't be drawn with
+        if (ToWx(pen)->GetStyle() == wxPENSTYLE_USER_DASH) ToWx(pen)->SetStyle(wxPENSTYLE_SOLID);
+        return;
+    }
+    std::vector<wxDash> pattern;
+    for (int i = 0; i < count; ++i) {
+        pattern.push_back(static_cast<wxDash>(dashes[i]));
+    }
+    ReleaseUnusedDashes();
+    GetDashPatterns().push_back({pattern, {}});
+    ToWx(pen)->SetDashes(count, GetDashPatterns().back().dashes.data());
+    ToWx(pen)->SetStyle(wxPENSTYLE_USER_DASH);
+    TrackDashes(*ToWx(pen));
+}
+
+WXD_EXPORTED int wxd_Pen_GetDashes(const wxd_Pen_t* pen, int* dashes, int max_count) {
+    if (!pen || !ToWx(pen)->IsOk()) return 0;
+    int count = ToWx(pen)->GetDashCount();
+    const wxDash* pattern = ToWx(pen)->GetDash();
+    for (int i = 0; dashes && pattern && i < count && i < max_count; ++i) {
+        dashes[i] = static_cast<int>(pattern[i]);
+    }
+    return count;
+}
+
+WXD_EXPORTED void wxd_Pen_SetStipple(wxd_Pen_t* pen, const wxd_Bitmap_t* bitmap) {
+    const wxBitmap* wx_bitmap = reinterpret_cast<const wxBitmap*>(bitmap);
+    if (!pen || !wx_bitmap || !wx_bitmap->IsOk()) return;
+    ToWx(pen)->SetStipple(*wx_bitmap);
+    ToWx(pen)->SetStyle(wxPENSTYLE_STIPPLE);
+    TrackDashes(*ToWx(pen));
+}
+
+} // extern "C"
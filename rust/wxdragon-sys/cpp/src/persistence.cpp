@@ -0,0 +1,345 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/persist.h>
+#include <wx/persist/window.h>
+#include <wx/persist/toplevel.h>
+#include <wx/persist/bookctrl.h>
+#include <wx/persist/treebook.h>
+#include <wx/persist/dataview.h>
+#include <wx/splitter.h>
+#include <wx/listctrl.h>
+#include <wx/fileconf.h>
+#include <wx/tokenzr.h>
+
+// Functions implemented in Rust (persistence.rs)
+extern "C" {
+    bool rust_persistence_store_save(void* store, const char* key, const char* value);
+    // Returns NULL if the key is not in the store
+    char* rust_persistence_store_restore(void* store, const char* key);
+    void rust_persistence_store_free_string(char* str);
+    void rust_persistence_store_drop(void* store);
+}
+
+// Saves the sash position. Only restored if the window is already split.
+class WxdPersistentSplitter : public wxPersistentWindow<wxSplitterWindow> {
+public:
+    explicit WxdPersistentSplitter(wxSplitterWindow* splitter)
+        : wxPersistentWindow<wxSplitterWindow>(splitter) {}
+
+    virtual wxString GetKind() const override { return "Splitter"; }
+
+    virtual void Save() const override {
+        const wxSplitterWindow* const splitter = Get();
+        if (splitter->IsSplit())
+            SaveValue("SashPosition", splitter->GetSashPosition());
+    }
+
+    virtual bool Restore() override {
+        int pos;
+        if (!RestoreValue("SashPosition", &pos))
+            return false;
+        wxSplitterWindow* const splitter = Get();
+        if (splitter->IsSplit())
+            splitter->SetSashPosition(pos);
+        return true;
+    }
+};
+
+// Saves column widths of a report mode list control, and the column order
+// where the platform supports reordering.
+class WxdPersistentListCtrl : public wxPersistentWindow<wxListCtrl> {
+public:
+    explicit WxdPersistentListCtrl(wxListCtrl* list)
+        : wxPersistentWindow<wxListCtrl>(list) {}
+
+    virtual wxString GetKind() const override { return "ListCtrl"; }
+
+    virtual void Save() const override {
+        const wxListCtrl* const list = Get();
+        if (!list->InReportView())
+            return;
+        const int count = list->GetColumnCount();
+        SaveValue("ColumnCount", count);
+        for (int i = 0; i < count; ++i)
+            SaveValue(wxString::Format("Column%d.Width", i), list->GetColumnWidth(i));
+#ifdef wxHAS_LISTCTRL_COLUMN_ORDER
+        wxString order;
+        for (int index : list->GetColumnsOrder()) {
+            if (!order.empty())
+                order += ",";
+            order << index;
+        }
+        SaveValue("ColumnsOrder", order);
+#endif
+    }
+
+    virtual bool Restore() override {
+        wxListCtrl* const list = Get();
+        int count;
+        // Column layout saved for a different set of columns doesn't apply
+        if (!list->InReportView() || !RestoreValue("ColumnCount", &count) ||
+            count != list->GetColumnCount())
+            return false;
+        for (int i = 0; i < count; ++i) {
+            int width;
+            if (RestoreValue(wxString::Format("Column%d.Width", i), &width))
+                list->SetColumnWidth(i, width);
+        }
+#ifdef wxHAS_LISTCTRL_COLUMN_ORDER
+        wxString order;
+        if (RestoreValue("ColumnsOrder", &order)) {
+            wxArrayInt indices;
+            wxStringTokenizer tokens(order, ",");
+            while (tokens.HasMoreTokens()) {
+                long index;
+                if (tokens.GetNextToken().ToLong(&index))
+                    indices.push_back(static_cast<int>(index));
+            }
+            if (static_cast<int>(indices.size()) == count)
+                list->SetColumnsOrder(indices);
+        }
+#endif
+        return true;
+    }
+};
+
+// Adds the column order to what wxWidgets saves (widths, visibility and sorting).
+// The order can only be restored by the generic implementation used on Windows.
+class WxdPersistentDataViewCtrl : public wxPersistentDataViewCtrl {
+public:
+    explicit WxdPersistentDataViewCtrl(wxDataViewCtrl* control)
+        : wxPersistentDataViewCtrl(control) {}
+
+    virtual void Save() const override {
+        wxPersistentDataViewCtrl::Save();
+        const wxDataViewCtrl* const control = Get();
+        wxString order;
+        for (unsigned int i = 0; i < control->GetColumnCount(); ++i) {
+            if (!order.empty())
+                order += ",";
+            order << control->GetColumnPosition(control->GetColumn(i));
+        }
+        SaveValue("ColumnsOrder", order);
+    }
+
+    virtual bool Restore() override {
+        const bool restored = wxPersistentDataViewCtrl::Restore();
+#ifdef wxHAS_GENERIC_DATAVIEWCTRL
+        wxDataViewCtrl* const control = Get();
+        wxString order;
+        if (RestoreValue("ColumnsOrder", &order)) {
+            // Saved as the position of each column, in column index order
+            wxVector<wxDataViewColumn*> byPosition(control->GetColumnCount(), nullptr);
+            wxStringTokenizer tokens(order, ",");
+            for (unsigned int i = 0; i < byPosition.size() && tokens.HasMoreTokens(); ++i) {
+                unsigned long pos;
+                if (tokens.GetNextToken().ToULong(&pos) && pos < byPosition.size())
+                    byPosition[pos] = control->GetColumn(i);
+            }
+            for (unsigned int pos = 0; pos < byPosition.size(); ++pos) {
+                if (byPosition[pos])
+                    control->ColumnMoved(byPosition[pos], pos);
+            }
+        }
+#endif
+        return restored;
+    }
+};
+
+// Routes values to the store chosen from Rust: the default wxConfig, a
+// wxFileConfig or a Rust store that keeps everything as strings.
+class WxdPersistenceManager : public wxPersistenceManager {
+public:
+    using wxPersistenceManager::SaveValue;
+    using wxPersistenceManager::RestoreValue;
+
+    void UseDefaultConfig() { Reset(); }
+
+    void UseConfigFile(const wxString& path) {
+        Reset();
+        m_fileConfig = new wxFileConfig(wxEmptyString, wxEmptyString, path, wxEmptyString,
+                                        wxCONFIG_USE_LOCAL_FILE);
+    }
+
+    void UseRustStore(void* store) {
+        Reset();
+        m_rustStore = store;
+    }
+
+    virtual wxConfigBase* GetConfig() const override {
+        return m_fileConfig ? m_fileConfig : wxPersistenceManager::GetConfig();
+    }
+
+    virtual bool SaveValue(const wxPersistentObject& who, const wxString& name, bool value) override {
+        if (m_rustStore)
+            return SaveString(who, name, value ? "1" : "0");
+        return Flushed(wxPersistenceManager::SaveValue(who, name, value));
+    }
+
+    virtual bool SaveValue(const wxPersistentObject& who, const wxString& name, int value) override {
+        if (m_rustStore)
+            return SaveString(who, name, wxString::Format("%d", value));
+        return Flushed(wxPersistenceManager::SaveValue(who, name, value));
+    }
+
+    virtual bool SaveValue(const wxPersistentObject& who, const wxString& name, long value) override {
+        if (m_rustStore)
+            return SaveString(who, name, wxString::Format("%ld", value));
+        return Flushed(wxPersistenceManager::SaveValue(who, name, value));
+    }
+
+    virtual bool SaveValue(const wxPersistentObject& who, const wxString& name, wxString value) override {
+        if (m_rustStore)
+            return SaveString(who, name, value);
+        return Flushed(wxPersistenceManager::SaveValue(who, name, value));
+    }
+
+    virtual bool RestoreValue(const wxPersistentObject& who, const wxString& name, bool* value) override {
+        if (!m_rustStore)
+            return wxPersistenceManager::RestoreValue(who, name, value);
+        wxString text;
+        if (!RestoreString(who, name, text))
+            return false;
+        *value = text == "1" || text.IsSameAs("true", false);
+        return true;
+    }
+
+    virtual bool RestoreValue(const wxPersistentObject& who, const wxString& name, int* value) override {
+        if (!m_rustStore)
+            return wxPersistenceManager::RestoreValue(who, name, value);
+        wxString text;
+        long number;
+        if (!RestoreString(who, name, text) || !text.ToLong(&number))
+            return false;
+        *value = static_cast<int>(number);
+        return true;
+    }
+
+    virtual bool RestoreValue(const wxPersistentObject& who, const wxString& name, long* value) override {
+        if (!m_rustStore)
+            return wxPersistenceManager::RestoreValue(who, name, value);
+        wxString text;
+        return RestoreString(who, name, text) && text.ToLong(value);
+    }
+
+    virtual bool RestoreValue(const wxPersistentObject& who, const wxString& name, wxString* value) override {
+        if (!m_rustStore)
+            return wxPersistenceManager::RestoreValue(who, name, value);
+        return RestoreString(who, name, *value);
+    }
+
+private:
+    void Reset() {
+        if (m_rustStore) {
+            rust_persistence_store_drop(m_rustStore);
+            m_rustStore = nullptr;
+        }
+        delete m_fileConfig; // Flushes
+        m_fileConfig = nullptr;
+    }
+
+    // The manager lives until the process exits, so write file changes right away
+    bool Flushed(bool saved) const {
+        if (saved && m_fileConfig)
+            m_fileConfig->Flush();
+        return saved;
+    }
+
+    bool SaveString(const wxPersistentObject& who, const wxString& name, const wxString& value) const {
+        return rust_persistence_store_save(m_rustStore, GetKey(who, name).utf8_str(), value.utf8_str());
+    }
+
+    bool RestoreString(const wxPersistentObject& who, const wxString& name, wxString& out) const {
+        char* text = rust_persistence_store_restore(m_rustStore, GetKey(who, name).utf8_str());
+        if (!text)
+            return false;
+        out = wxString::FromUTF8(text);
+        rust_persistence_store_free_string(text);
+        return true;
+    }
+
+    wxFileConfig* m_fileConfig = nullptr;
+    void* m_rustStore = nullptr;
+};
+
+// Installed on first use; never destroyed, as wxWidgets keeps a reference to it
+static WxdPersistenceManager& GetManager() {
+    static WxdPersistenceManager* manager = nullptr;
+    if (!manager) {
+        manager = new WxdPersistenceManager();
+        wxPersistenceManager::Set(*manager);
+    }
+    return *manager;
+}
+
+static wxPersistentObject* CreatePersistentObject(wxWindow* window) {
+    if (wxTopLevelWindow* tlw = wxDynamicCast(window, wxTopLevelWindow))
+        return new wxPersistentTLW(tlw);
+    if (wxTreebook* treebook = wxDynamicCast(window, wxTreebook))
+        return new wxPersistentTreeBookCtrl(treebook);
+    if (wxBookCtrlBase* book = wxDynamicCast(window, wxBookCtrlBase))
+        return new wxPersistentBookCtrl(book);
+    if (wxSplitterWindow* splitter = wxDynamicCast(window, wxSplitterWindow))
+        return new WxdPersistentSplitter(splitter);
+    if (wxListCtrl* list = wxDynamicCast(window, wxListCtrl))
+        return new WxdPersistentListCtrl(list);
+    if (wxDataViewCtrl* dataview = wxDynamicCast(window, wxDataViewCtrl))
+        return new WxdPersistentDataViewCtrl(dataview);
+    return nullptr;
+}
+
+extern "C" {
+
+WXD_EXPORTED int wxd_Persistence_RegisterAndRestore(wxd_Window_t* window, const char* name) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window || !name || !*name)
+        return WXD_PERSISTENCE_UNSUPPORTED;
+
+    WxdPersistenceManager& manager = GetManager();
+    // Registering again, e.g. under a new name, replaces the old registration
+    if (manager.Find(wx_window))
+        manager.Unregister(wx_window);
+
+    wx_window->SetName(wxString::FromUTF8(name));
+    wxPersistentObject* po = CreatePersistentObject(wx_window);
+    if (!po)
+        return WXD_PERSISTENCE_UNSUPPORTED;
+    return manager.RegisterAndRestore(wx_window, po) ? 1 : 0;
+}
+
+WXD_EXPORTED void wxd_Persistence_Save(wxd_Window_t* window) {
+    if (!window) return;
+    GetManager().Save(reinterpret_cast<wxWindow*>(window));
+}
+
+WXD_EXPORTED bool wxd_Persistence_Restore(wxd_Window_t* window) {
+    if (!window) return false;
+    return GetManager().Restore(reinterpret_cast<wxWindow*>(window));
+}
+
+WXD_EXPORTED void wxd_Persistence_Unregister(wxd_Window_t* window) {
+    if (!window) return;
+    GetManager().Unregister(reinterpret_cast<wxWindow*>(window));
+}
+
+WXD_EXPORTED bool wxd_Persistence_IsRegistered(wxd_Window_t* window) {
+    if (!window) return false;
+    return GetManager().Find(reinterpret_cast<wxWindow*>(window)) != nullptr;
+}
+
+WXD_EXPORTED void wxd_Persistence_UseDefaultConfig(void) {
+    GetManager().UseDefaultConfig();
+}
+
+WXD_EXPORTED void wxd_Persistence_UseConfigFile(const char* path) {
+    if (!path) return;
+    GetManager().UseConfigFile(wxString::FromUTF8(path));
+}
+
+WXD_EXPORTED void wxd_Persistence_UseRustStore(void* rust_store) {
+    if (!rust_store) return;
+    GetManager().UseRustStore(rust_store);
+}
+
+} // extern "C"
@@ -0,0 +1,512 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include "wxd_utils.h"
+#include <wx/print.h>
+#include <wx/printdlg.h>
+#include <wx/cmndata.h>
+#include <wx/prntbase.h>
+
+// Implemented in Rust
+extern "C" {
+    void rust_printout_on_prepare_printing(void* handler, wxd_Printout_t* printout);
+    bool rust_printout_has_page(void* handler, int page);
+    void rust_printout_get_page_info(void* handler, int* min_page, int* max_page, int* from_page, int* to_page);
+    bool rust_printout_on_print_page(void* handler, wxd_Printout_t* printout, int page);
+    void rust_printout_drop(void* handler);
+}
+
+// A printout whose pages are drawn by a Rust Printout
+class WxdPrintout : public wxPrintout {
+public:
+    WxdPrintout(void* handler, const wxString& title)
+        : wxPrintout(title), m_handler(handler) {}
+
+    virtual ~WxdPrintout() {
+        rust_printout_drop(m_handler);
+    }
+
+    virtual void OnPreparePrinting() override {
+        rust_printout_on_prepare_printing(m_handler, reinterpret_cast<wxd_Printout_t*>(this));
+    }
+
+    virtual bool HasPage(int page) override {
+        return rust_printout_has_page(m_handler, page);
+    }
+
+    virtual void GetPageInfo(int* min_page, int* max_page, int* from_page, int* to_page) override {
+        rust_printout_get_page_info(m_handler, min_page, max_page, from_page, to_page);
+    }
+
+    virtual bool OnPrintPage(int page) override {
+        return rust_printout_on_print_page(m_handler, reinterpret_cast<wxd_Printout_t*>(this), page);
+    }
+
+private:
+    void* m_handler;
+};
+
+static wxPrintData* ToWx(wxd_PrintData_t* data) {
+    return reinterpret_cast<wxPrintData*>(data);
+}
+
+static const wxPrintData* ToWx(const wxd_PrintData_t* data) {
+    return reinterpret_cast<const wxPrintData*>(data);
+}
+
+static wxPrintDialogData* ToWx(wxd_PrintDialogData_t* data) {
+    return reinterpret_cast<wxPrintDialogData*>(data);
+}
+
+static const wxPrintDialogData* ToWx(const wxd_PrintDialogData_t* data) {
+    return reinterpret_cast<const wxPrintDialogData*>(data);
+}
+
+static wxPageSetupDialogData* ToWx(wxd_PageSetupDialogData_t* data) {
+    return reinterpret_cast<wxPageSetupDialogData*>(data);
+}
+
+static const wxPageSetupDialogData* ToWx(const wxd_PageSetupDialogData_t* data) {
+    return reinterpret_cast<const wxPageSetupDialogData*>(data);
+}
+
+static wxPrintout* ToWx(wxd_Printout_t* printout) {
+    return reinterpret_cast<wxPrintout*>(printout);
+}
+
+static wxd_PrintData_t* Wrap(const wxPrintData& data) {
+    return reinterpret_cast<wxd_PrintData_t*>(new wxPrintData(data));
+}
+
+static wxd_Size ToSize(const wxSize& size) {
+    return wxd_Size{size.x, size.y};
+}
+
+static wxd_Rect ToRect(const wxRect& rect) {
+    return wxd_Rect{rect.x, rect.y, rect.width, rect.height};
+}
+
+static wxd_Size ToSize(int width, int height) {
+    return wxd_Size{width, height};
+}
+
+extern "C" {
+
+// --- Print data ---
+
+WXD_EXPORTED wxd_PrintData_t* wxd_PrintData_Create(void) {
+    return Wrap(wxPrintData());
+}
+
+WXD_EXPORTED wxd_PrintData_t* wxd_PrintData_Clone(const wxd_PrintData_t* data) {
+    if (!data) return nullptr;
+    return Wrap(*ToWx(data));
+}
+
+WXD_EXPORTED void wxd_PrintData_Destroy(wxd_PrintData_t* data) {
+    delete ToWx(data);
+}
+
+WXD_EXPORTED bool wxd_PrintData_IsOk(const wxd_PrintData_t* data) {
+    return data && ToWx(data)->IsOk();
+}
+
+WXD_EXPORTED void wxd_PrintData_SetOrientation(wxd_PrintData_t* data, wxd_PrintOrientation orientation) {
+    if (!data) return;
+    ToWx(data)->SetOrientation(orientation == WXD_PRINT_LANDSCAPE ? wxLANDSCAPE : wxPORTRAIT);
+}
+
+WXD_EXPORTED wxd_PrintOrientation wxd_PrintData_GetOrientation(const wxd_PrintData_t* data) {
+    if (!data) return WXD_PRINT_PORTRAIT;
+    return ToWx(data)->GetOrientation() == wxLANDSCAPE ? WXD_PRINT_LANDSCAPE : WXD_PRINT_PORTRAIT;
+}
+
+WXD_EXPORTED void wxd_PrintData_SetPrintMode(wxd_PrintData_t* data, wxd_PrintMode mode) {
+    if (!data) return;
+    switch (mode) {
+        case WXD_PRINT_MODE_PREVIEW: ToWx(data)->SetPrintMode(wxPRINT_MODE_PREVIEW); break;
+        case WXD_PRINT_MODE_FILE: ToWx(data)->SetPrintMode(wxPRINT_MODE_FILE); break;
+        case WXD_PRINT_MODE_PRINTER: ToWx(data)->SetPrintMode(wxPRINT_MODE_PRINTER); break;
+        default: ToWx(data)->SetPrintMode(wxPRINT_MODE_NONE); break;
+    }
+}
+
+WXD_EXPORTED wxd_PrintMode wxd_PrintData_GetPrintMode(const wxd_PrintData_t* data) {
+    if (!data) return WXD_PRINT_MODE_NONE;
+    switch (ToWx(data)->GetPrintMode()) {
+        case wxPRINT_MODE_PREVIEW: return WXD_PRINT_MODE_PREVIEW;
+        case wxPRINT_MODE_FILE: return WXD_PRINT_MODE_FILE;
+        case wxPRINT_MODE_PRINTER: return WXD_PRINT_MODE_PRINTER;
+        default: return WXD_PRINT_MODE_NONE;
+    }
+}
+
+WXD_EXPORTED void wxd_PrintData_SetFilename(wxd_PrintData_t* data, const char* filename) {
+    if (!data) return;
+    ToWx(data)->SetFilename(WXD_STR_TO_WX_STRING_UTF8_NULL_OK(filename));
+}
+
+WXD_EXPORTED char* wxd_PrintData_GetFilename(const wxd_PrintData_t* data) {
+    if (!data) return strdup("");
+    return wxd_cpp_utils::to_c_string(ToWx(data)->GetFilename());
+}
+
+WXD_EXPORTED void wxd_PrintData_SetNoCopies(wxd_PrintData_t* data, int copies) {
+    if (!data) return;
+    ToWx(data)->SetNoCopies(copies);
+}
+
+WXD_EXPORTED int wxd_PrintData_GetNoCopies(const wxd_PrintData_t* data) {
+    return data ? ToWx(data)->GetNoCopies() : 1;
+}
+
+WXD_EXPORTED void wxd_PrintData_SetColour(wxd_PrintData_t* data, bool colour) {
+    if (!data) return;
+    ToWx(data)->SetColour(colour);
+}
+
+WXD_EXPORTED bool wxd_PrintData_GetColour(const wxd_PrintData_t* data) {
+    return data && ToWx(data)->GetColour();
+}
+
+// --- Print dialog data ---
+
+WXD_EXPORTED wxd_PrintDialogData_t* wxd_PrintDialogData_Create(const wxd_PrintData_t* data) {
+    wxPrintDialogData* dialog_data = data ? new wxPrintDialogData(*ToWx(data)) : new wxPrintDialogData();
+    return reinterpret_cast<wxd_PrintDialogData_t*>(dialog_data);
+}
+
+WXD_EXPORTED wxd_PrintDialogData_t* wxd_PrintDialogData_Clone(const wxd_PrintDialogData_t* data) {
+    if (!data) return nullptr;
+    return reinterpret_cast<wxd_PrintDialogData_t*>(new wxPrintDialogData(*ToWx(data)));
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_Destroy(wxd_PrintDialogData_t* data) {
+    delete ToWx(data);
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_SetFromPage(wxd_PrintDialogData_t* data, int page) {
+    if (!data) return;
+    ToWx(data)->SetFromPage(page);
+}
+
+WXD_EXPORTED int wxd_PrintDialogData_GetFromPage(const wxd_PrintDialogData_t* data) {
+    return data ? ToWx(data)->GetFromPage() : 0;
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_SetToPage(wxd_PrintDialogData_t* data, int page) {
+    if (!data) return;
+    ToWx(data)->SetToPage(page);
+}
+
+WXD_EXPORTED int wxd_PrintDialogData_GetToPage(const wxd_PrintDialogData_t* data) {
+    return data ? ToWx(data)->GetToPage() : 0;
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_SetMinPage(wxd_PrintDialogData_t* data, int page) {
+    if (!data) return;
+    ToWx(data)->SetMinPage(page);
+}
+
+WXD_EXPORTED int wxd_PrintDialogData_GetMinPage(const wxd_PrintDialogData_t* data) {
+    return data ? ToWx(data)->GetMinPage() : 0;
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_SetMaxPage(wxd_PrintDialogData_t* data, int page) {
+    if (!data) return;
+    ToWx(data)->SetMaxPage(page);
+}
+
+WXD_EXPORTED int wxd_PrintDialogData_GetMaxPage(const wxd_PrintDialogData_t* data) {
+    return data ? ToWx(data)->GetMaxPage() : 0;
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_SetNoCopies(wxd_PrintDialogData_t* data, int copies) {
+    if (!data) return;
+    ToWx(data)->SetNoCopies(copies);
+}
+
+WXD_EXPORTED int wxd_PrintDialogData_GetNoCopies(const wxd_PrintDialogData_t* data) {
+    return data ? ToWx(data)->GetNoCopies() : 1;
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_SetAllPages(wxd_PrintDialogData_t* data, bool all_pages) {
+    if (!data) return;
+    ToWx(data)->SetAllPages(all_pages);
+}
+
+WXD_EXPORTED bool wxd_PrintDialogData_GetAllPages(const wxd_PrintDialogData_t* data) {
+    return data && ToWx(data)->GetAllPages();
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_SetSelection(wxd_PrintDialogData_t* data, bool selection) {
+    if (!data) return;
+    ToWx(data)->SetSelection(selection);
+}
+
+WXD_EXPORTED bool wxd_PrintDialogData_GetSelection(const wxd_PrintDialogData_t* data) {
+    return data && ToWx(data)->GetSelection();
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_EnableSelection(wxd_PrintDialogData_t* data, bool enable) {
+    if (!data) return;
+    ToWx(data)->EnableSelection(enable);
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_EnablePageNumbers(wxd_PrintDialogData_t* data, bool enable) {
+    if (!data) return;
+    ToWx(data)->EnablePageNumbers(enable);
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_EnablePrintToFile(wxd_PrintDialogData_t* data, bool enable) {
+    if (!data) return;
+    ToWx(data)->EnablePrintToFile(enable);
+}
+
+WXD_EXPORTED wxd_PrintData_t* wxd_PrintDialogData_GetPrintData(const wxd_PrintDialogData_t* data) {
+    if (!data) return nullptr;
+    return Wrap(ToWx(data)->GetPrintData());
+}
+
+WXD_EXPORTED void wxd_PrintDialogData_SetPrintData(wxd_PrintDialogData_t* data, const wxd_PrintData_t* print_data) {
+    if (!data || !print_data) return;
+    ToWx(data)->SetPrintData(*ToWx(print_data));
+}
+
+// --- Page setup data ---
+
+WXD_EXPORTED wxd_PageSetupDialogData_t* wxd_PageSetupDialogData_Create(const wxd_PrintData_t* data) {
+    wxPageSetupDialogData* setup_data = data ? new wxPageSetupDialogData(*ToWx(data)) : new wxPageSetupDialogData();
+    return reinterpret_cast<wxd_PageSetupDialogData_t*>(setup_data);
+}
+
+WXD_EXPORTED wxd_PageSetupDialogData_t* wxd_PageSetupDialogData_Clone(const wxd_PageSetupDialogData_t* data) {
+    if (!data) return nullptr;
+    return reinterpret_cast<wxd_PageSetupDialogData_t*>(new wxPageSetupDialogData(*ToWx(data)));
+}
+
+WXD_EXPORTED void wxd_PageSetupDialogData_Destroy(wxd_PageSetupDialogData_t* data) {
+    delete ToWx(data);
+}
+
+WXD_EXPORTED void wxd_PageSetupDialogData_SetMarginTopLeft(wxd_PageSetupDialogData_t* data, wxd_Point margin) {
+    if (!data) return;
+    ToWx(data)->SetMarginTopLeft(wxPoint(margin.x, margin.y));
+}
+
+WXD_EXPORTED wxd_Point wxd_PageSetupDialogData_GetMarginTopLeft(const wxd_PageSetupDialogData_t* data) {
+    if (!data) return wxd_Point{0, 0};
+    wxPoint margin = ToWx(data)->GetMarginTopLeft();
+    return wxd_Point{margin.x, margin.y};
+}
+
+WXD_EXPORTED void wxd_PageSetupDialogData_SetMarginBottomRight(wxd_PageSetupDialogData_t* data, wxd_Point margin) {
+    if (!data) return;
+    ToWx(data)->SetMarginBottomRight(wxPoint(margin.x, margin.y));
+}
+
+WXD_EXPORTED wxd_Point wxd_PageSetupDialogData_GetMarginBottomRight(const wxd_PageSetupDialogData_t* data) {
+    if (!data) return wxd_Point{0, 0};
+    wxPoint margin = ToWx(data)->GetMarginBottomRight();
+    return wxd_Point{margin.x, margin.y};
+}
+
+WXD_EXPORTED wxd_Size wxd_PageSetupDialogData_GetPaperSize(const wxd_PageSetupDialogData_t* data) {
+    if (!data) return wxd_Size{0, 0};
+    return ToSize(ToWx(data)->GetPaperSize());
+}
+
+WXD_EXPORTED wxd_PrintData_t* wxd_PageSetupDialogData_GetPrintData(const wxd_PageSetupDialogData_t* data) {
+    if (!data) return nullptr;
+    return Wrap(ToWx(data)->GetPrintData());
+}
+
+WXD_EXPORTED void wxd_PageSetupDialogData_SetPrintData(wxd_PageSetupDialogData_t* data, const wxd_PrintData_t* print_data) {
+    if (!data || !print_data) return;
+    ToWx(data)->SetPrintData(*ToWx(print_data));
+}
+
+// --- Dialogs ---
+
+WXD_EXPORTED bool wxd_PrintDialog_ShowModal(wxd_Window_t* parent, wxd_PrintDialogData_t* data) {
+    if (!data) return false;
+    wxPrintDialog dialog(reinterpret_cast<wxWindow*>(parent), ToWx(data));
+    if (dialog.ShowModal() != wxID_OK) return false;
+    *ToWx(data) = dialog.GetPrintDialogData();
+    return true;
+}
+
+WXD_EXPORTED bool wxd_PageSetupDialog_ShowModal(wxd_Window_t* parent, wxd_PageSetupDialogData_t* data) {
+    if (!data) return false;
+    wxPageSetupDialog dialog(reinterpret_cast<wxWindow*>(parent), ToWx(data));
+    if (dialog.ShowModal() != wxID_OK) return false;
+    *ToWx(data) = dialog.GetPageSetupData();
+    return true;
+}
+
+// --- Printout ---
+
+WXD_EXPORTED wxd_Printout_t* wxd_Printout_Create(void* handler, const char* title) {
+    wxString wx_title = title ? wxString::FromUTF8(title) : wxString("Printout");
+    return reinterpret_cast<wxd_Printout_t*>(new WxdPrintout(handler, wx_title));
+}
+
+WXD_EXPORTED void wxd_Printout_Destroy(wxd_Printout_t* printout) {
+    delete ToWx(printout);
+}
+
+WXD_EXPORTED wxd_DC_t* wxd_Printout_GetDC(wxd_Printout_t* printout) {
+    if (!printout) return nullptr;
+    return reinterpret_cast<wxd_DC_t*>(ToWx(printout)->GetDC());
+}
+
+WXD_EXPORTED bool wxd_Printout_IsPreview(wxd_Printout_t* printout) {
+    return printout && ToWx(printout)->IsPreview();
+}
+
+WXD_EXPORTED void wxd_Printout_FitThisSizeToPaper(wxd_Printout_t* printout, wxd_Size size) {
+    if (!printout || !ToWx(printout)->GetDC()) return;
+    ToWx(printout)->FitThisSizeToPaper(wxSize(size.width, size.height));
+}
+
+WXD_EXPORTED void wxd_Printout_FitThisSizeToPage(wxd_Printout_t* printout, wxd_Size size) {
+    if (!printout || !ToWx(printout)->GetDC()) return;
+    ToWx(printout)->FitThisSizeToPage(wxSize(size.width, size.height));
+}
+
+WXD_EXPORTED void wxd_Printout_FitThisSizeToPageMargins(wxd_Printout_t* printout, wxd_Size size,
+                                                        const wxd_PageSetupDialogData_t* page_setup) {
+    if (!printout || !page_setup || !ToWx(printout)->GetDC()) return;
+    ToWx(printout)->FitThisSizeToPageMargins(wxSize(size.width, size.height), *ToWx(page_setup));
+}
+
+WXD_EXPORTED void wxd_Printout_MapScreenSizeToPaper(wxd_Printout_t* printout) {
+    if (!printout || !ToWx(printout)->GetDC()) return;
+    ToWx(printout)->MapScreenSizeToPaper();
+}
+
+WXD_EXPORTED void wxd_Printout_MapScreenSizeToPage(wxd_Printout_t* printout) {
+    if (!printout || !ToWx(printout)->GetDC()) return;
+    ToWx(printout)->MapScreenSizeToPage();
+}
+
+WXD_EXPORTED void wxd_Printout_MapScreenSizeToPageMargins(wxd_Printout_t* printout,
+                                                          const wxd_PageSetupDialogData_t* page_setup) {
+    if (!printout || !page_setup || !ToWx(printout)->GetDC()) return;
+    ToWx(printout)->MapScreenSizeToPageMargins(*ToWx(page_setup));
+}
+
+WXD_EXPORTED void wxd_Printout_MapScreenSizeToDevice(wxd_Printout_t* printout) {
+    if (!printout || !ToWx(printout)->GetDC()) return;
+    ToWx(printout)->MapScreenSizeToDevice();
+}
+
+WXD_EXPORTED wxd_Rect wxd_Printout_GetLogicalPaperRect(wxd_Printout_t* printout) {
+    if (!printout || !ToWx(printout)->GetDC()) return wxd_Rect{0, 0, 0, 0};
+    return ToRect(ToWx(printout)->GetLogicalPaperRect());
+}
+
+WXD_EXPORTED wxd_Rect wxd_Printout_GetLogicalPageRect(wxd_Printout_t* printout) {
+    if (!printout || !ToWx(printout)->GetDC()) return wxd_Rect{0, 0, 0, 0};
+    return ToRect(ToWx(printout)->GetLogicalPageRect());
+}
+
+WXD_EXPORTED wxd_Rect wxd_Printout_GetLogicalPageMarginsRect(wxd_Printout_t* printout,
+                                                            const wxd_PageSetupDialogData_t* page_setup) {
+    if (!printout || !page_setup || !ToWx(printout)->GetDC()) return wxd_Rect{0, 0, 0, 0};
+    return ToRect(ToWx(printout)->GetLogicalPageMarginsRect(*ToWx(page_setup)));
+}
+
+WXD_EXPORTED void wxd_Printout_OffsetLogicalOrigin(wxd_Printout_t* printout, int dx, int dy) {
+    if (!printout || !ToWx(printout)->GetDC()) return;
+    ToWx(printout)->OffsetLogicalOrigin(dx, dy);
+}
+
+WXD_EXPORTED wxd_Size wxd_Printout_GetPageSizePixels(wxd_Printout_t* printout) {
+    int width = 0, height = 0;
+    if (printout) ToWx(printout)->GetPageSizePixels(&width, &height);
+    return ToSize(width, height);
+}
+
+WXD_EXPORTED wxd_Size wxd_Printout_GetPageSizeMM(wxd_Printout_t* printout) {
+    int width = 0, height = 0;
+    if (printout) ToWx(printout)->GetPageSizeMM(&width, &height);
+    return ToSize(width, height);
+}
+
+WXD_EXPORTED wxd_Size wxd_Printout_GetPPIPrinter(wxd_Printout_t* printout) {
+    int x = 0, y = 0;
+    if (printout) ToWx(printout)->GetPPIPrinter(&x, &y);
+    return ToSize(x, y);
+}
+
+WXD_EXPORTED wxd_Size wxd_Printout_GetPPIScreen(wxd_Printout_t* printout) {
+    int x = 0, y = 0;
+    if (printout) ToWx(printout)->GetPPIScreen(&x, &y);
+    return ToSize(x, y);
+}
+
+// --- Printer ---
+
+WXD_EXPORTED wxd_Printer_t* wxd_Printer_Create(const wxd_PrintDialogData_t* data) {
+    wxPrinter* printer = new wxPrinter(data ? const_cast<wxPrintDialogData*>(ToWx(data)) : nullptr);
+    return reinterpret_cast<wxd_Printer_t*>(printer);
+}
+
+WXD_EXPORTED void wxd_Printer_Destroy(wxd_Printer_t* printer) {
+    delete reinterpret_cast<wxPrinter*>(printer);
+}
+
+WXD_EXPORTED bool wxd_Printer_Print(wxd_Printer_t* printer, wxd_Window_t* parent, wxd_Printout_t* printout, bool prompt) {
+    if (!printer || !printout) return false;
+    return reinterpret_cast<wxPrinter*>(printer)->Print(reinterpret_cast<wxWindow*>(parent), ToWx(printout), prompt);
+}
+
+WXD_EXPORTED wxd_PrinterError wxd_Printer_GetLastError(void) {
+    switch (wxPrinter::GetLastError()) {
+        case wxPRINTER_NO_ERROR: return WXD_PRINTER_NO_ERROR;
+        case wxPRINTER_CANCELLED: return WXD_PRINTER_CANCELLED;
+        default: return WXD_PRINTER_ERROR;
+    }
+}
+
+WXD_EXPORTED wxd_PrintDialogData_t* wxd_Printer_GetPrintDialogData(wxd_Printer_t* printer) {
+    if (!printer) return nullptr;
+    const wxPrintDialogData& data = reinterpret_cast<wxPrinter*>(printer)->GetPrintDialogData();
+    return reinterpret_cast<wxd_PrintDialogData_t*>(new wxPrintDialogData(data));
+}
+
+// --- Print preview ---
+
+WXD_EXPORTED bool wxd_PrintPreview_Show(wxd_Printout_t* preview_printout, wxd_Printout_t* print_printout,
+                                        const wxd_PrintDialogData_t* data, wxd_Window_t* parent,
+                                        const char* title, wxd_Size size) {
+    if (!preview_printout) {
+        delete ToWx(print_printout);
+        return false;
+    }
+
+    // The preview copies the data and owns the printouts from here on
+    wxPrintPreview* preview = new wxPrintPreview(ToWx(preview_printout), ToWx(print_printout),
+                                                 data ? const_cast<wxPrintDialogData*>(ToWx(data)) : nullptr);
+    if (!preview->IsOk()) {
+        delete preview;
+        return false;
+    }
+
+    wxString wx_title = title ? wxString::FromUTF8(title) : wxString("Print Preview");
+    wxPreviewFrame* frame = new wxPreviewFrame(preview, reinterpret_cast<wxWindow*>(parent), wx_title,
+                                               wxDefaultPosition, wxSize(size.width, size.height));
+    frame->Centre(wxBOTH);
+    frame->Initialize();
+    frame->Show(true);
+    return true;
+}
+
+WXD_EXPORTED void wxd_Printing_UsePostScript(void) {
+#ifdef __WXGTK__
+    wxPrintFactory::SetPrintFactory(new wxNativePrintFactory);
+#endif
+}
+
+} // extern "C"
@@ -0,0 +1,270 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/process.h>
+#include <wx/utils.h>
+#include <wx/timer.h>
+#include <wx/stream.h>
+#include <wx/wfstream.h>
+#include <string>
+#include <vector>
+
+// Implemented in Rust
+extern "C" {
+    void rust_process_on_output(void* handler, int stream, const char* data, size_t len);
+    void rust_process_on_exit(void* handler, int status);
+    void rust_process_drop(void* handler);
+}
+
+#ifdef __UNIX__
+#include <fcntl.h>
+#include <unistd.h>
+#include <errno.h>
+#endif
+
+// How often output is checked for (and queued input written) while the process runs
+static const int POLL_INTERVAL_MS = 50;
+
+// wxProcess doesn't report available output, so the pipes are polled with a timer.
+// Input is queued and written from the same timer as far as the pipe accepts it.
+class WxdProcess : public wxProcess {
+public:
+    explicit WxdProcess(void* handler)
+        : wxProcess(wxPROCESS_REDIRECT), m_handler(handler), m_timer(this) {
+        Bind(wxEVT_TIMER, &WxdProcess::OnPoll, this);
+    }
+
+    virtual ~WxdProcess() {
+        m_timer.Stop();
+        rust_process_drop(m_handler);
+    }
+
+    // Called around wxExecute, as the process may end before it returns
+    void Starting() { m_running = true; }
+
+    void Started(bool success) {
+        if (!success) {
+            m_running = false;
+        } else if (m_running) {
+            m_timer.Start(POLL_INTERVAL_MS);
+        }
+    }
+
+    bool IsRunning() const { return m_running; }
+
+    bool QueueInput(const uint8_t* data, size_t len) {
+        if (!m_running || m_close_input || !GetOutputStream()) return false;
+        m_input_pending.append(reinterpret_cast<const char*>(data), len);
+        WriteInput();
+        return true;
+    }
+
+    // Closes standard input once the queued input has been written
+    void CloseInputWhenWritten() {
+        m_close_input = true;
+        WriteInput();
+    }
+
+    // The handle was dropped; delete now unless still running or calling back
+    void Release() {
+        m_released = true;
+        if (!m_running && m_callback_depth == 0) {
+            delete this;
+        }
+    }
+
+    virtual void OnTerminate(int WXUNUSED(pid), int status) override {
+        m_timer.Stop();
+        m_running = false;
+        m_input_pending.clear();
+
+        ++m_callback_depth;
+        ReadAvailable(true);
+        rust_process_on_exit(m_handler, status);
+        --m_callback_depth;
+
+        if (m_released) {
+            delete this;
+        }
+    }
+
+private:
+    void OnPoll(wxTimerEvent& WXUNUSED(event)) {
+        if (!m_running) return;
+        WriteInput();
+        ++m_callback_depth;
+        ReadAvailable(false);
+        --m_callback_depth;
+    }
+
+    // Writes as much of the queued input as the pipe takes without blocking
+    void WriteInput() {
+        wxOutputStream* out = GetOutputStream();
+        if (!out) {
+            m_input_pending.clear();
+            return;
+        }
+        while (!m_input_pending.empty()) {
+            bool failed = false;
+            size_t written = WriteWithoutBlocking(out, m_input_pending.data(), m_input_pending.size(), failed);
+            if (failed) {
+                // The process closed its end; the rest can't be delivered
+                m_input_pending.clear();
+                break;
+            }
+            if (written == 0) break;
+            m_input_pending.erase(0, written);
+        }
+        if (m_close_input && m_input_pending.empty()) {
+            CloseOutput();
+        }
+    }
+
+    size_t WriteWithoutBlocking(wxOutputStream* out, const char* data, size_t len, bool& failed) {
+#ifdef __UNIX__
+        // The pipe is a blocking file descriptor; wxFile would also log EAGAIN as an error
+        wxFileOutputStream* file_out = dynamic_cast<wxFileOutputStream*>(out);
+        if (file_out && file_out->GetFile() && file_out->GetFile()->IsOpened()) {
+            int fd = file_out->GetFile()->fd();
+            if (!m_input_nonblocking) {
+                fcntl(fd, F_SETFL, fcntl(fd, F_GETFL) | O_NONBLOCK);
+                m_input_nonblocking = true;
+            }
+            ssize_t written = ::write(fd, data, len);
+            if (written < 0) {
+                failed = errno != EAGAIN && errno != EWOULDBLOCK && errno != EINTR;
+                return 0;
+            }
+            return static_cast<size_t>(written);
+        }
+#endif
+        // wxMSW already opens the pipe in non-blocking mode and returns partial writes
+        out->Write(data, len);
+        failed = out->GetLastError() != wxSTREAM_NO_ERROR;
+        return out->LastWrite();
+    }
+
+    void ReadAvailable(bool flush) {
+        ReadStream(GetInputStream(), m_stdout_pending, WXD_PROCESS_STDOUT, flush);
+        ReadStream(GetErrorStream(), m_stderr_pending, WXD_PROCESS_STDERR, flush);
+    }
+
+    // Reads without blocking and passes on each complete line
+    void ReadStream(wxInputStream* in, std::string& pending, int stream, bool flush) {
+        char buffer[4096];
+        while (in && in->CanRead()) {
+            in->Read(buffer, sizeof(buffer));
+            size_t count = in->LastRead();
+            if (count == 0) break;
+            pending.append(buffer, count);
+        }
+
+        size_t start = 0;
+        size_t newline;
+        while ((newline = pending.find('\n', start)) != std::string::npos) {
+            size_t end = newline;
+            if (end > start && pending[end - 1] == '\r') --end;
+            rust_process_on_output(m_handler, stream, pending.data() + start, end - start);
+            start = newline + 1;
+        }
+        pending.erase(0, start);
+
+        // A last line without a line break once the process has ended
+        if (flush && !pending.empty()) {
+            rust_process_on_output(m_handler, stream, pending.data(), pending.size());
+            pending.clear();
+        }
+    }
+
+    void* m_handler;
+    wxTimer m_timer;
+    std::string m_stdout_pending;
+    std::string m_stderr_pending;
+    std::string m_input_pending;
+    bool m_close_input = false;
+    bool m_input_nonblocking = false;
+    bool m_running = false;
+    bool m_released = false;
+    int m_callback_depth = 0;
+};
+
+static WxdProcess* ToWx(wxd_Process_t* process) {
+    return reinterpret_cast<WxdProcess*>(process);
+}
+
+extern "C" {
+
+WXD_EXPORTED wxd_Process_t* wxd_Process_Create(void* handler) {
+    return reinterpret_cast<wxd_Process_t*>(new WxdProcess(handler));
+}
+
+WXD_EXPORTED long wxd_Process_Execute(wxd_Process_t* process, const char* const* argv, int argc,
+                                      const char* cwd, const char* const* env_names,
+                                      const char* const* env_values, int env_count) {
+    WxdProcess* proc = ToWx(process);
+    if (!proc || !argv || argc <= 0 || proc->IsRunning()) return 0;
+
+    // wxExecute expects a NULL-terminated array
+    std::vector<std::wstring> args_storage;
+    for (int i = 0; i < argc; ++i) {
+        args_storage.push_back(wxString::FromUTF8(argv[i] ? argv[i] : "").ToStdWstring());
+    }
+    std::vector<wchar_t*> args;
+    for (std::wstring& arg : args_storage) {
+        args.push_back(&arg[0]);
+    }
+    args.push_back(nullptr);
+
+    wxExecuteEnv env;
+    if (cwd) env.cwd = wxString::FromUTF8(cwd);
+    if (env_count > 0) {
+        // The variables replace the whole environment, so start from the current one
+        wxGetEnvMap(&env.env);
+        for (int i = 0; i < env_count; ++i) {
+            if (!env_names[i]) continue;
+            env.env[wxString::FromUTF8(env_names[i])] = wxString::FromUTF8(env_values[i] ? env_values[i] : "");
+        }
+    }
+
+    // A process group lets Kill reach the children too
+    int flags = wxEXEC_ASYNC | wxEXEC_HIDE_CONSOLE | wxEXEC_MAKE_GROUP_LEADER;
+    proc->Starting();
+    long pid = wxExecute(args.data(), flags, proc, &env);
+    proc->Started(pid != 0);
+    return pid;
+}
+
+WXD_EXPORTED long wxd_Process_GetPid(wxd_Process_t* process) {
+    WxdProcess* proc = ToWx(process);
+    return proc ? proc->GetPid() : 0;
+}
+
+WXD_EXPORTED bool wxd_Process_IsRunning(wxd_Process_t* process) {
+    WxdProcess* proc = ToWx(process);
+    return proc && proc->IsRunning();
+}
+
+WXD_EXPORTED bool wxd_Process_WriteStdin(wxd_Process_t* process, const uint8_t* data, size_t len) {
+    WxdProcess* proc = ToWx(process);
+    if (!proc || !data) return false;
+    return proc->QueueInput(data, len);
+}
+
+WXD_EXPORTED void wxd_Process_CloseStdin(wxd_Process_t* process) {
+    WxdProcess* proc = ToWx(process);
+    if (proc) proc->CloseInputWhenWritten();
+}
+
+WXD_EXPORTED bool wxd_Process_Kill(wxd_Process_t* process, int signal) {
+    WxdProcess* proc = ToWx(process);
+    if (!proc || !proc->IsRunning()) return false;
+    return wxProcess::Kill(static_cast<int>(proc->GetPid()), static_cast<wxSignal>(signal),
+                           wxKILL_CHILDREN) == wxKILL_OK;
+}
+
+WXD_EXPORTED void wxd_Process_Release(wxd_Process_t* process) {
+    WxdProcess* proc = ToWx(process);
+    if (proc) proc->Release();
+}
+
+} // extern "C"
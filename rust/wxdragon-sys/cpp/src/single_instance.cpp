@@ -0,0 +1,109 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/snglinst.h>
+#include <wx/ipc.h>
+#include <wx/filename.h>
+#include <wx/utils.h>
+
+// Implemented in Rust
+extern "C" {
+    void rust_single_instance_on_args(void* handler, const char* data, size_t len);
+    void rust_single_instance_drop(void* handler);
+}
+
+static const char* const ARGS_TOPIC = "wxdragon-args";
+
+// Names are per user, so that different users can each run an instance
+static wxString UniqueName(const char* name) {
+    return wxString::FromUTF8(name) + "-" + wxGetUserId();
+}
+
+// DDE service name on Windows, Unix domain socket path elsewhere
+static wxString ServiceName(const char* name) {
+#ifdef __WINDOWS__
+    return UniqueName(name);
+#else
+    return wxFileName(wxFileName::GetTempDir(), UniqueName(name) + ".sock").GetFullPath();
+#endif
+}
+
+class WxdArgsConnection : public wxConnection {
+public:
+    explicit WxdArgsConnection(void* handler) : m_handler(handler) {}
+
+    virtual bool OnExecute(const wxString& WXUNUSED(topic), const void* data, size_t size,
+                           wxIPCFormat WXUNUSED(format)) override {
+        rust_single_instance_on_args(m_handler, static_cast<const char*>(data), size);
+        return true;
+    }
+
+private:
+    void* m_handler;
+};
+
+class WxdArgsServer : public wxServer {
+public:
+    explicit WxdArgsServer(void* handler) : m_handler(handler) {}
+
+    virtual ~WxdArgsServer() {
+        rust_single_instance_drop(m_handler);
+    }
+
+    virtual wxConnectionBase* OnAcceptConnection(const wxString& topic) override {
+        if (topic != ARGS_TOPIC) return nullptr;
+        return new WxdArgsConnection(m_handler);
+    }
+
+private:
+    void* m_handler;
+};
+
+static wxSingleInstanceChecker* s_checker = nullptr;
+static WxdArgsServer* s_server = nullptr;
+
+extern "C" {
+
+WXD_EXPORTED bool wxd_SingleInstance_IsAnotherRunning(const char* name) {
+    if (!name) return false;
+    if (!s_checker) {
+        s_checker = new wxSingleInstanceChecker();
+        if (!s_checker->Create(UniqueName(name))) {
+            delete s_checker;
+            s_checker = nullptr;
+            return false;
+        }
+    }
+    return s_checker->IsAnotherRunning();
+}
+
+WXD_EXPORTED bool wxd_SingleInstance_Forward(const char* name, const char* data, size_t len) {
+    if (!name || !data) return false;
+    wxClient client;
+    wxConnectionBase* connection = client.MakeConnection("localhost", ServiceName(name), ARGS_TOPIC);
+    if (!connection) return false;
+    bool sent = connection->Execute(data, len);
+    connection->Disconnect();
+    delete connection;
+    return sent;
+}
+
+WXD_EXPORTED bool wxd_SingleInstance_Listen(const char* name, void* handler) {
+    delete s_server;
+    s_server = new WxdArgsServer(handler);
+    if (!name || !s_server->Create(ServiceName(name))) {
+        delete s_server;
+        s_server = nullptr;
+        return false;
+    }
+    return true;
+}
+
+WXD_EXPORTED void wxd_SingleInstance_Release(void) {
+    delete s_server;
+    s_server = nullptr;
+    delete s_checker;
+    s_checker = nullptr;
+}
+
+} // extern "C"
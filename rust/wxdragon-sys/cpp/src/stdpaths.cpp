@@ -0,0 +1,72 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include "wxd_utils.h"
+#include <wx/stdpaths.h>
+#include <wx/fileconf.h>
+
+// wxStandardPaths relies on the application traits, so it can't be used before the app exists
+template <typename F>
+static char* GetPath(F get) {
+    if (!wxTheApp) return strdup("");
+    return wxd_cpp_utils::to_c_string(get(wxStandardPaths::Get()));
+}
+
+extern "C" {
+
+WXD_EXPORTED char* wxd_StandardPaths_GetUserConfigDir(void) {
+    return GetPath([](wxStandardPaths& paths) { return paths.GetUserConfigDir(); });
+}
+
+WXD_EXPORTED char* wxd_StandardPaths_GetUserDataDir(void) {
+    return GetPath([](wxStandardPaths& paths) { return paths.GetUserDataDir(); });
+}
+
+WXD_EXPORTED char* wxd_StandardPaths_GetUserLocalDataDir(void) {
+    return GetPath([](wxStandardPaths& paths) { return paths.GetUserLocalDataDir(); });
+}
+
+WXD_EXPORTED char* wxd_StandardPaths_GetUserCacheDir(void) {
+    return GetPath([](wxStandardPaths& paths) { return paths.GetUserDir(wxStandardPaths::Dir_Cache); });
+}
+
+WXD_EXPORTED char* wxd_StandardPaths_GetDocumentsDir(void) {
+    return GetPath([](wxStandardPaths& paths) { return paths.GetDocumentsDir(); });
+}
+
+WXD_EXPORTED char* wxd_StandardPaths_GetTempDir(void) {
+    return GetPath([](wxStandardPaths& paths) { return paths.GetTempDir(); });
+}
+
+WXD_EXPORTED char* wxd_StandardPaths_GetExecutablePath(void) {
+    return GetPath([](wxStandardPaths& paths) { return paths.GetExecutablePath(); });
+}
+
+WXD_EXPORTED char* wxd_StandardPaths_GetResourcesDir(void) {
+    return GetPath([](wxStandardPaths& paths) { return paths.GetResourcesDir(); });
+}
+
+WXD_EXPORTED char* wxd_StandardPaths_GetDataDir(void) {
+    return GetPath([](wxStandardPaths& paths) { return paths.GetDataDir(); });
+}
+
+WXD_EXPORTED char* wxd_StandardPaths_GetConfigFilePath(void) {
+    if (!wxTheApp) return strdup("");
+    return wxd_cpp_utils::to_c_string(wxFileConfig::GetLocalFileName(wxTheApp->GetAppName(), wxCONFIG_USE_LOCAL_FILE));
+}
+
+WXD_EXPORTED void wxd_StandardPaths_SetFileLayout(wxd_FileLayout layout) {
+    if (!wxTheApp) return;
+    wxStandardPaths::Get().SetFileLayout(layout == WXD_FILE_LAYOUT_XDG
+                                             ? wxStandardPaths::FileLayout_XDG
+                                             : wxStandardPaths::FileLayout_Classic);
+}
+
+WXD_EXPORTED wxd_FileLayout wxd_StandardPaths_GetFileLayout(void) {
+    if (!wxTheApp) return WXD_FILE_LAYOUT_CLASSIC;
+    return wxStandardPaths::Get().GetFileLayout() == wxStandardPaths::FileLayout_XDG
+               ? WXD_FILE_LAYOUT_XDG
+               : WXD_FILE_LAYOUT_CLASSIC;
+}
+
+} // extern "C"
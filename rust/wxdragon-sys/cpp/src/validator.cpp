@@ -0,0 +1,330 @@
+#include <wx/wxprec.h>
+#include <wx/wx.h>
+#include "../include/wxdragon.h"
+#include <wx/valtext.h>
+#include <wx/valnum.h>
+#include <wx/spinctrl.h>
+#include <wx/regex.h>
+
+// Functions implemented in Rust (validator.rs)
+extern "C" {
+    void* rust_validator_clone(void* data);
+    void rust_validator_drop(void* data);
+    // Returns false and sets `out_message` (freed with rust_validator_free_string) if `text` is invalid
+    bool rust_validator_check(void* data, const char* text, char** out_message);
+    // Returns NULL if no text value is bound
+    char* rust_validator_get_text(void* data);
+    void rust_validator_set_text(void* data, const char* text);
+    bool rust_validator_get_integer(void* data, int64_t* out_value);
+    void rust_validator_set_integer(void* data, int64_t value);
+    bool rust_validator_get_float(void* data, double* out_value);
+    void rust_validator_set_float(void* data, double value);
+    void rust_validator_free_string(char* str);
+}
+
+// Reads the value of the controls validators are usually attached to as text
+static bool GetControlText(wxWindow* control, wxString& out) {
+    if (wxTextEntry* entry = dynamic_cast<wxTextEntry*>(control)) {
+        out = entry->GetValue();
+        return true;
+    }
+    if (wxSpinCtrl* spin = wxDynamicCast(control, wxSpinCtrl)) {
+        out = wxString::Format("%d", spin->GetValue());
+        return true;
+    }
+    if (wxSpinCtrlDouble* spin = wxDynamicCast(control, wxSpinCtrlDouble)) {
+        out = wxString::FromCDouble(spin->GetValue());
+        return true;
+    }
+    if (wxItemContainer* items = dynamic_cast<wxItemContainer*>(control)) {
+        out = items->GetStringSelection();
+        return true;
+    }
+    return false;
+}
+
+static void SetControlText(wxWindow* control, const wxString& text) {
+    if (wxTextEntry* entry = dynamic_cast<wxTextEntry*>(control)) {
+        entry->ChangeValue(text);
+    } else if (wxSpinCtrl* spin = wxDynamicCast(control, wxSpinCtrl)) {
+        spin->SetValue(text);
+    } else if (wxSpinCtrlDouble* spin = wxDynamicCast(control, wxSpinCtrlDouble)) {
+        spin->SetValue(text);
+    } else if (wxItemContainer* items = dynamic_cast<wxItemContainer*>(control)) {
+        items->SetStringSelection(text);
+    }
+}
+
+// Owns a reference to the Rust validator data; copies take a new reference
+class RustValidatorLink {
+public:
+    explicit RustValidatorLink(void* data) : m_data(data) {}
+    RustValidatorLink(const RustValidatorLink& other)
+        : m_data(other.m_data ? rust_validator_clone(other.m_data) : nullptr) {}
+    ~RustValidatorLink() {
+        if (m_data) rust_validator_drop(m_data);
+    }
+    RustValidatorLink& operator=(const RustValidatorLink&) = delete;
+
+    void* GetData() const { return m_data; }
+
+    // Runs the Rust check on the control's text, reporting a failure like the
+    // built-in validators do
+    bool Check(wxWindow* control, wxWindow* parent) const {
+        if (!m_data || !control || !control->IsEnabled()) return true;
+        wxString text;
+        if (!GetControlText(control, text)) return true;
+
+        char* message = nullptr;
+        if (rust_validator_check(m_data, text.utf8_str(), &message)) return true;
+
+        wxString error = message ? wxString::FromUTF8(message) : wxString::Format(_("'%s' is invalid"), text);
+        if (message) rust_validator_free_string(message);
+        if (!wxValidator::IsSilent()) {
+            wxMessageBox(error, _("Validation conflict"), wxOK | wxICON_EXCLAMATION, parent);
+        }
+        control->SetFocus();
+        return false;
+    }
+
+    void LoadText(wxWindow* control) const {
+        if (!m_data || !control) return;
+        char* text = rust_validator_get_text(m_data);
+        if (!text) return;
+        SetControlText(control, wxString::FromUTF8(text));
+        rust_validator_free_string(text);
+    }
+
+    void StoreText(wxWindow* control) const {
+        if (!m_data || !control) return;
+        wxString text;
+        if (GetControlText(control, text)) {
+            rust_validator_set_text(m_data, text.utf8_str());
+        }
+    }
+
+private:
+    void* m_data;
+};
+
+class WxdTextValidator : public wxTextValidator {
+public:
+    WxdTextValidator(long style, void* rust_data) : wxTextValidator(style), m_link(rust_data) {}
+    WxdTextValidator(const WxdTextValidator& other) : wxTextValidator(other), m_link(other.m_link) {}
+
+    wxObject* Clone() const override { return new WxdTextValidator(*this); }
+
+    bool Validate(wxWindow* parent) override {
+        return wxTextValidator::Validate(parent) && m_link.Check(GetWindow(), parent);
+    }
+
+    bool TransferToWindow() override {
+        m_link.LoadText(GetWindow());
+        return true;
+    }
+
+    bool TransferFromWindow() override {
+        m_link.StoreText(GetWindow());
+        return true;
+    }
+
+private:
+    RustValidatorLink m_link;
+};
+
+// The numeric validators transfer through a value of their own, which copies
+// must not share, so copies are constructed afresh rather than copied
+class WxdIntegerValidator : public wxIntegerValidator<wxLongLong_t> {
+public:
+    WxdIntegerValidator(int style, wxLongLong_t min, wxLongLong_t max, void* rust_data)
+        : wxIntegerValidator<wxLongLong_t>(&m_value, style), m_value(0), m_style(style), m_link(rust_data) {
+        SetRange(min, max);
+    }
+    WxdIntegerValidator(const WxdIntegerValidator& other)
+        : wxIntegerValidator<wxLongLong_t>(&m_value, other.m_style), m_value(other.m_value),
+          m_style(other.m_style), m_link(other.m_link) {
+        SetRange(other.GetMin(), other.GetMax());
+    }
+
+    wxObject* Clone() const override { return new WxdIntegerValidator(*this); }
+
+    void SetNumStyle(int style) {
+        m_style = style;
+        SetStyle(style);
+    }
+
+    bool Validate(wxWindow* parent) override {
+        return wxIntegerValidator<wxLongLong_t>::Validate(parent) && m_link.Check(GetWindow(), parent);
+    }
+
+    bool TransferToWindow() override {
+        int64_t value = 0;
+        if (!m_link.GetData() || !rust_validator_get_integer(m_link.GetData(), &value)) return true;
+        m_value = value;
+        return wxIntegerValidator<wxLongLong_t>::TransferToWindow();
+    }
+
+    bool TransferFromWindow() override {
+        if (!wxIntegerValidator<wxLongLong_t>::TransferFromWindow()) return false;
+        if (m_link.GetData()) rust_validator_set_integer(m_link.GetData(), m_value);
+        return true;
+    }
+
+private:
+    wxLongLong_t m_value;
+    int m_style;
+    RustValidatorLink m_link;
+};
+
+class WxdFloatValidator : public wxFloatingPointValidator<double> {
+public:
+    WxdFloatValidator(int style, int precision, double min, double max, void* rust_data)
+        : wxFloatingPointValidator<double>(precision, &m_value, style), m_value(0.0), m_style(style),
+          m_precision(precision), m_link(rust_data) {
+        SetRange(min, max);
+    }
+    WxdFloatValidator(const WxdFloatValidator& other)
+        : wxFloatingPointValidator<double>(other.m_precision, &m_value, other.m_style), m_value(other.m_value),
+          m_style(other.m_style), m_precision(other.m_precision), m_link(other.m_link) {
+        SetRange(other.GetMin(), other.GetMax());
+    }
+
+    wxObject* Clone() const override { return new WxdFloatValidator(*this); }
+
+    void SetNumStyle(int style) {
+        m_style = style;
+        SetStyle(style);
+    }
+
+    bool Validate(wxWindow* parent) override {
+        return wxFloatingPointValidator<double>::Validate(parent) && m_link.Check(GetWindow(), parent);
+    }
+
+    bool TransferToWindow() override {
+        double value = 0.0;
+        if (!m_link.GetData() || !rust_validator_get_float(m_link.GetData(), &value)) return true;
+        m_value = value;
+        return wxFloatingPointValidator<double>::TransferToWindow();
+    }
+
+    bool TransferFromWindow() override {
+        if (!wxFloatingPointValidator<double>::TransferFromWindow()) return false;
+        if (m_link.GetData()) rust_validator_set_float(m_link.GetData(), m_value);
+        return true;
+    }
+
+private:
+    double m_value;
+    int m_style;
+    int m_precision;
+    RustValidatorLink m_link;
+};
+
+class WxdCustomValidator : public wxValidator {
+public:
+    explicit WxdCustomValidator(void* rust_data) : m_link(rust_data) {}
+    WxdCustomValidator(const WxdCustomValidator& other) : wxValidator(), m_link(other.m_link) {
+        Copy(other);
+    }
+
+    wxObject* Clone() const override { return new WxdCustomValidator(*this); }
+
+    bool Validate(wxWindow* parent) override {
+        return m_link.Check(GetWindow(), parent);
+    }
+
+    bool TransferToWindow() override {
+        m_link.LoadText(GetWindow());
+        return true;
+    }
+
+    bool TransferFromWindow() override {
+        m_link.StoreText(GetWindow());
+        return true;
+    }
+
+private:
+    RustValidatorLink m_link;
+};
+
+extern "C" {
+
+WXD_EXPORTED wxd_Validator_t* wxd_Validator_CreateText(int64_t style, void* rust_data) {
+    return reinterpret_cast<wxd_Validator_t*>(new WxdTextValidator(static_cast<long>(style), rust_data));
+}
+
+WXD_EXPORTED wxd_Validator_t* wxd_Validator_CreateInteger(int style, int64_t min, int64_t max, void* rust_data) {
+    return reinterpret_cast<wxd_Validator_t*>(new WxdIntegerValidator(style, min, max, rust_data));
+}
+
+WXD_EXPORTED wxd_Validator_t* wxd_Validator_CreateFloat(int style, int precision, double min, double max, void* rust_data) {
+    return reinterpret_cast<wxd_Validator_t*>(new WxdFloatValidator(style, precision, min, max, rust_data));
+}
+
+WXD_EXPORTED wxd_Validator_t* wxd_Validator_CreateCustom(void* rust_data) {
+    return reinterpret_cast<wxd_Validator_t*>(new WxdCustomValidator(rust_data));
+}
+
+WXD_EXPORTED void wxd_Validator_Destroy(wxd_Validator_t* validator) {
+    if (!validator) return;
+    delete reinterpret_cast<wxValidator*>(validator);
+}
+
+WXD_EXPORTED void wxd_TextValidator_SetCharIncludes(wxd_Validator_t* validator, const char* chars) {
+    wxTextValidator* text_validator = dynamic_cast<wxTextValidator*>(reinterpret_cast<wxValidator*>(validator));
+    if (!text_validator) return;
+    text_validator->SetCharIncludes(wxString::FromUTF8(chars ? chars : ""));
+}
+
+WXD_EXPORTED void wxd_TextValidator_SetCharExcludes(wxd_Validator_t* validator, const char* chars) {
+    wxTextValidator* text_validator = dynamic_cast<wxTextValidator*>(reinterpret_cast<wxValidator*>(validator));
+    if (!text_validator) return;
+    text_validator->SetCharExcludes(wxString::FromUTF8(chars ? chars : ""));
+}
+
+WXD_EXPORTED void wxd_NumValidator_SetStyle(wxd_Validator_t* validator, int style) {
+    wxValidator* wx_validator = reinterpret_cast<wxValidator*>(validator);
+    if (WxdIntegerValidator* int_validator = dynamic_cast<WxdIntegerValidator*>(wx_validator)) {
+        int_validator->SetNumStyle(style);
+    } else if (WxdFloatValidator* float_validator = dynamic_cast<WxdFloatValidator*>(wx_validator)) {
+        float_validator->SetNumStyle(style);
+    }
+}
+
+WXD_EXPORTED int wxd_Validator_RegexMatches(const char* pattern, const char* text) {
+    if (!pattern || !text) return -1;
+    // Anchor the pattern so that it has to match the whole text
+    wxRegEx regex("^(" + wxString::FromUTF8(pattern) + ")$", wxRE_ADVANCED);
+    if (!regex.IsValid()) return -1;
+    return regex.Matches(wxString::FromUTF8(text)) ? 1 : 0;
+}
+
+WXD_EXPORTED bool wxd_Window_SetValidator(wxd_Window_t* window, wxd_Validator_t* validator) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window || !validator) return false;
+    wxValidator* wx_validator = reinterpret_cast<wxValidator*>(validator);
+    // wxTextValidator and wxNumValidator assert on anything but a wxTextEntry,
+    // which wxChoice and, on MSW, wxSpinCtrl aren't
+    bool needs_text_entry = dynamic_cast<wxTextValidator*>(wx_validator) ||
+                            dynamic_cast<wxNumValidatorBase*>(wx_validator);
+    if (needs_text_entry && !dynamic_cast<wxTextEntry*>(wx_window)) return false;
+    wx_window->SetValidator(*wx_validator);
+    return true;
+}
+
+WXD_EXPORTED bool wxd_Window_Validate(wxd_Window_t* window) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    return wx_window ? wx_window->Validate() : false;
+}
+
+WXD_EXPORTED bool wxd_Window_TransferDataToWindow(wxd_Window_t* window) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    return wx_window ? wx_window->TransferDataToWindow() : false;
+}
+
+WXD_EXPORTED bool wxd_Window_TransferDataFromWindow(wxd_Window_t* window) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    return wx_window ? wx_window->TransferDataFromWindow() : false;
+}
+
+} // extern "C"
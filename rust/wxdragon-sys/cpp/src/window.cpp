@@ -366,6 +366,13 @@ WXD_EXPORTED int64_t wxd_Window_GetExtraStyle(wxd_Window_t* window) {
     return 0; // Default fallback
 }
 
+WXD_EXPORTED void wxd_Window_UpdateWindowUI(wxd_Window_t* window) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (wx_window) {
+        wx_window->UpdateWindowUI(wxUPDATE_UI_RECURSE);
+    }
+}
+
 // Color management functions
 WXD_EXPORTED void wxd_Window_SetForegroundColor(wxd_Window_t* window, wxd_Colour_t color) {
     wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
@@ -526,6 +533,62 @@ WXD_EXPORTED wxd_Cursor_t* wxd_Window_GetCursor(wxd_Window_t* window) {
     return nullptr;
 }
 
+// --- Accelerator Functions ---
+WXD_EXPORTED void wxd_Window_SetAcceleratorTable(wxd_Window_t* window, wxd_AcceleratorTable_t* table) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window) return;
+    if (table) {
+        wx_window->SetAcceleratorTable(*reinterpret_cast<wxAcceleratorTable*>(table));
+    } else {
+        wx_window->SetAcceleratorTable(wxNullAcceleratorTable);
+    }
+}
+
+// --- DPI Functions ---
+WXD_EXPORTED wxd_Size wxd_Window_FromDIPSize(wxd_Window_t* window, wxd_Size size) {
+    wxSize result = wxWindow::FromDIP(wxSize(size.width, size.height), reinterpret_cast<wxWindow*>(window));
+    return { result.GetWidth(), result.GetHeight() };
+}
+
+WXD_EXPORTED wxd_Size wxd_Window_ToDIPSize(wxd_Window_t* window, wxd_Size size) {
+    wxSize result = wxWindow::ToDIP(wxSize(size.width, size.height), reinterpret_cast<wxWindow*>(window));
+    return { result.GetWidth(), result.GetHeight() };
+}
+
+WXD_EXPORTED wxd_Point wxd_Window_FromDIPPoint(wxd_Window_t* window, wxd_Point point) {
+    wxPoint result = wxWindow::FromDIP(wxPoint(point.x, point.y), reinterpret_cast<wxWindow*>(window));
+    return { result.x, result.y };
+}
+
+WXD_EXPORTED wxd_Point wxd_Window_ToDIPPoint(wxd_Window_t* window, wxd_Point point) {
+    wxPoint result = wxWindow::ToDIP(wxPoint(point.x, point.y), reinterpret_cast<wxWindow*>(window));
+    return { result.x, result.y };
+}
+
+WXD_EXPORTED int wxd_Window_FromDIPInt(wxd_Window_t* window, int value) {
+    return wxWindow::FromDIP(value, reinterpret_cast<wxWindow*>(window));
+}
+
+WXD_EXPORTED int wxd_Window_ToDIPInt(wxd_Window_t* window, int value) {
+    return wxWindow::ToDIP(value, reinterpret_cast<wxWindow*>(window));
+}
+
+WXD_EXPORTED double wxd_Window_GetDPIScaleFactor(wxd_Window_t* window) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window) return 1.0;
+    return wx_window->GetDPIScaleFactor();
+}
+
+WXD_EXPORTED wxd_Size wxd_Window_GetDPI(wxd_Window_t* window) {
+    wxd_Size result = { 0, 0 };
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window) return result;
+    wxSize dpi = wx_window->GetDPI();
+    result.width = dpi.GetWidth();
+    result.height = dpi.GetHeight();
+    return result;
+}
+
 // --- Z-Order Management Functions ---
 WXD_EXPORTED void wxd_Window_Raise(wxd_Window_t* window) {
     wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
@@ -852,6 +915,26 @@ WXD_EXPORTED void wxd_Window_MoveBeforeInTabOrder(wxd_Window_t* window, wxd_Wind
     }
 }
 
+WXD_EXPORTED bool wxd_Window_IsKindOf(wxd_Window_t* window, const char* class_name) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window || !class_name) return false;
+    wxClassInfo* info = wxClassInfo::FindClass(wxString::FromUTF8(class_name));
+    return info && wx_window->IsKindOf(info);
+}
+
+WXD_EXPORTED int wxd_Window_GetChildren(wxd_Window_t* window, wxd_Window_t** out_children, int max_count) {
+    wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
+    if (!wx_window) return 0;
+    const wxWindowList& children = wx_window->GetChildren();
+    if (out_children && max_count > 0) {
+        int i = 0;
+        for (wxWindowList::const_iterator it = children.begin(); it != children.end() && i < max_count; ++it, ++i) {
+            out_children[i] = reinterpret_cast<wxd_Window_t*>(*it);
+        }
+    }
+    return static_cast<int>(children.GetCount());
+}
+
 WXD_EXPORTED wxd_Window_t* wxd_Window_GetNextSibling(wxd_Window_t* window) {
     wxWindow* wx_window = reinterpret_cast<wxWindow*>(window);
     if (wx_window) {
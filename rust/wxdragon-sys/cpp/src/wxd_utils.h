@@ -5,6 +5,8 @@
 #include <wx/string.h> // For wxString
 #include "../include/wxd_types.h" // For wxd_Point, wxd_Size (CHANGED from wxdragon.h)
 #include <wx/colour.h> // For wxColour type
+#include <wx/accel.h> // For wxAcceleratorEntry
+#include <cstring> // For strdup
 
 #ifdef __cplusplus
 // Helper macro to convert const char* to wxString, handling nulls and UTF-8
@@ -77,6 +79,17 @@ inline wxSize to_wx(const wxd_Size& s) {
  */
 size_t copy_wxstring_to_buffer(const wxString& str, char* buffer, size_t buffer_len);
 
+// Returns a UTF-8 copy of str, to be freed with wxd_free_string
+inline char* to_c_string(const wxString& str) {
+    const wxScopedCharBuffer utf8_buf = str.ToUTF8();
+    return strdup(utf8_buf.data() ? utf8_buf.data() : "");
+}
+
+// Converts between wxAcceleratorEntry and wxd_AcceleratorEntry, whose modifiers
+// are wxMOD_* flags rather than wxACCEL_* flags. Implemented in accelerator.cpp.
+wxAcceleratorEntry to_wx(const wxd_AcceleratorEntry& entry);
+wxd_AcceleratorEntry from_wx(const wxAcceleratorEntry& entry);
+
 }
 
 // Helper to convert wxd_Colour_t representation (unsigned long RGBA) to wxColour
@@ -24,6 +24,23 @@ extern "C" WXD_EXPORTED void wxd_XmlResource_InitAllHandlers(wxd_XmlResource_t*
     }
 }
 
+// Set the message catalog domain used to translate labels
+extern "C" WXD_EXPORTED void wxd_XmlResource_SetDomain(wxd_XmlResource_t* self, const char* domain) {
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    if (resource) {
+        resource->SetDomain(domain ? wxString::FromUTF8(domain) : wxString());
+    }
+}
+
+// Enable or disable translation of labels
+extern "C" WXD_EXPORTED void wxd_XmlResource_SetUseLocale(wxd_XmlResource_t* self, bool use_locale) {
+    wxXmlResource* resource = reinterpret_cast<wxXmlResource*>(self);
+    if (resource) {
+        int flags = resource->GetFlags();
+        resource->SetFlags(use_locale ? (flags | wxXRC_USE_LOCALE) : (flags & ~wxXRC_USE_LOCALE));
+    }
+}
+
 // Load XRC from file
 extern "C" WXD_EXPORTED bool wxd_XmlResource_LoadFromFile(wxd_XmlResource_t* self, const char* filemask) {
     if (!self || !filemask) return false;
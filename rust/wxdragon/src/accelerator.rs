@@ -0,0 +1,155 @@
+//! Keyboard accelerators.
+//!
+//! An [`AcceleratorTable`] maps key combinations to command IDs. Pressing one
+//! of them in a window using the table sends a menu event with that ID, exactly
+//! as if a menu item with that ID had been chosen.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! const ID_SAVE: i32 = ID_HIGHEST + 1;
+//! const ID_PALETTE: i32 = ID_HIGHEST + 2;
+//!
+//! let frame = Frame::builder().with_title("Editor").build();
+//! let table = AcceleratorTable::new(&[
+//!     AcceleratorEntry::new(KeyModifiers::CMD, KeyCode::Char('S'), ID_SAVE),
+//!     AcceleratorEntry::parse("Ctrl+Shift+P", ID_PALETTE).unwrap(),
+//! ]);
+//! frame.set_accelerator_table(Some(&table));
+//! frame.on_menu(|event| println!("Command {}", event.get_id()));
+//! ```
+
+use crate::event::{KeyCode, KeyModifiers};
+use crate::id::Id;
+use crate::utils::take_string;
+use std::ffi::CString;
+use std::fmt;
+use wxdragon_sys as ffi;
+
+/// A key combination bound to a command ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AcceleratorEntry {
+    /// Modifier keys that must be held down
+    pub modifiers: KeyModifiers,
+    /// The key to press
+    pub key_code: KeyCode,
+    /// The ID of the menu event sent when the accelerator is used
+    pub id: Id,
+}
+
+impl AcceleratorEntry {
+    /// Creates a new entry. Letters should be given in upper case, e.g. `KeyCode::Char('S')`.
+    pub fn new(modifiers: KeyModifiers, key_code: KeyCode, id: Id) -> Self {
+        AcceleratorEntry {
+            modifiers,
+            key_code,
+            id,
+        }
+    }
+
+    /// Parses a string such as `"Ctrl+Shift+P"` or `"Alt+F4"`.
+    ///
+    /// A menu label with a tab-separated accelerator (`"&Save\tCtrl+S"`) is
+    /// accepted too. Returns `None` if the string doesn't describe an accelerator.
+    pub fn parse(accel: &str, id: Id) -> Option<Self> {
+        let c_accel = CString::new(accel).ok()?;
+        let mut raw = ffi::wxd_AcceleratorEntry {
+            modifiers: 0,
+            key_code: 0,
+            id,
+        };
+        if unsafe { ffi::wxd_AcceleratorEntry_FromString(c_accel.as_ptr(), &mut raw) } {
+            Some(Self::from_raw(&raw))
+        } else {
+            None
+        }
+    }
+
+    /// Formats the entry using untranslated key names.
+    ///
+    /// Unlike the `Display` output, which uses the current UI language, the
+    /// result can be stored and read back with [`parse`](Self::parse) in any locale.
+    pub fn to_raw_string(&self) -> String {
+        let raw = self.to_raw();
+        unsafe { take_string(ffi::wxd_AcceleratorEntry_ToRawString(&raw)) }
+    }
+
+    pub(crate) fn from_raw(raw: &ffi::wxd_AcceleratorEntry) -> Self {
+        AcceleratorEntry {
+            modifiers: KeyModifiers::from_bits_truncate(raw.modifiers),
+            key_code: KeyCode::from_raw(raw.key_code),
+            id: raw.id,
+        }
+    }
+
+    fn to_raw(self) -> ffi::wxd_AcceleratorEntry {
+        ffi::wxd_AcceleratorEntry {
+            modifiers: self.modifiers.bits(),
+            key_code: self.key_code.to_raw(),
+            id: self.id,
+        }
+    }
+}
+
+/// Formats the entry as shown in menus, e.g. `"Ctrl+Shift+P"`
+impl fmt::Display for AcceleratorEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let raw = self.to_raw();
+        let text = unsafe { take_string(ffi::wxd_AcceleratorEntry_ToString(&raw)) };
+        f.write_str(&text)
+    }
+}
+
+/// A table of keyboard accelerators, installed with
+/// [`WxWidget::set_accelerator_table`](crate::window::WxWidget::set_accelerator_table)
+///
+/// The window keeps its own copy, so the table can be dropped once installed.
+pub struct AcceleratorTable {
+    ptr: *mut ffi::wxd_AcceleratorTable_t,
+    entries: Vec<AcceleratorEntry>,
+}
+
+impl AcceleratorTable {
+    /// Creates a table from the given entries
+    pub fn new(entries: &[AcceleratorEntry]) -> Self {
+        let raw: Vec<ffi::wxd_AcceleratorEntry> = entries.iter().map(|e| e.to_raw()).collect();
+        let ptr = unsafe { ffi::wxd_AcceleratorTable_Create(raw.as_ptr(), raw.len() as i32) };
+        AcceleratorTable {
+            ptr,
+            entries: entries.to_vec(),
+        }
+    }
+
+    /// Gets the entries the table was created from
+    pub fn entries(&self) -> &[AcceleratorEntry] {
+        &self.entries
+    }
+
+    /// Returns true if the table is valid
+    pub fn is_ok(&self) -> bool {
+        !self.ptr.is_null() && unsafe { ffi::wxd_AcceleratorTable_IsOk(self.ptr) }
+    }
+
+    /// Returns the raw underlying table pointer.
+    pub fn as_ptr(&self) -> *mut ffi::wxd_AcceleratorTable_t {
+        self.ptr
+    }
+}
+
+impl fmt::Debug for AcceleratorTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AcceleratorTable")
+            .field("entries", &self.entries)
+            .finish()
+    }
+}
+
+impl Drop for AcceleratorTable {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::wxd_AcceleratorTable_Destroy(self.ptr) };
+        }
+    }
+}
@@ -2,10 +2,24 @@
 // Currently, the main application logic is driven by the C wxd_Main function.
 // This module might later contain wrappers for App-specific functions if needed.
 
+use crate::dialogs::message_dialog::{MessageDialog, MessageDialogStyle};
+use crate::event::EventType;
+use crate::i18n::LocaleSetup;
+use crate::utils::take_string;
+use crate::window::Window;
 use lazy_static::lazy_static;
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
 use std::ffi::{c_char, c_void, CString};
-use std::sync::{Arc, Mutex};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Condvar, Mutex, Once};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
 use wxdragon_sys as ffi; // Import Window and WxWidget trait
 
 // Type alias to reduce complexity
@@ -19,7 +33,8 @@ lazy_static! {
 /// Schedules a callback to be executed on the main thread.
 ///
 /// This is useful when you need to update UI elements from a background thread.
-/// The callback will be executed during the next event loop iteration.
+/// The event loop is woken if it is idle, and the callback runs during its next
+/// iteration.
 ///
 /// # Example
 /// ```
@@ -35,8 +50,159 @@ pub fn call_after<F>(callback: Box<F>)
 where
     F: FnOnce() + Send + 'static,
 {
-    let mut queue = MAIN_THREAD_QUEUE.lock().unwrap();
-    queue.push_back(callback);
+    MAIN_THREAD_QUEUE.lock().unwrap().push_back(callback);
+    unsafe { ffi::wxd_WakeUpIdle() };
+}
+
+/// Schedules a callback on the main thread and returns a handle to its result.
+///
+/// Like [`call_after`], but the closure's return value is delivered through the
+/// returned [`CallAfterHandle`], which a worker thread can block on with
+/// [`wait`](CallAfterHandle::wait) or `.await` from async code.
+///
+/// Never block on the handle from the main thread: the callback can only run once
+/// the main thread returns to the event loop.
+///
+/// # Example
+/// ```no_run
+/// std::thread::spawn(move || {
+///     let rows = vec!["a".to_string(), "b".to_string()];
+///     // Hand the rows to the UI and wait until they have been added
+///     let handle = wxdragon::app::call_after_with_result(Box::new(move || {
+///         // ... insert `rows` into a list control ...
+///         rows.len()
+///     }));
+///     if let Some(added) = handle.wait() {
+///         println!("UI now shows {added} new rows");
+///     }
+/// });
+/// ```
+pub fn call_after_with_result<F, R>(callback: Box<F>) -> CallAfterHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let shared = Arc::new(CallResultShared {
+        state: Mutex::new(CallResultState::Pending(None)),
+        done: Condvar::new(),
+    });
+    let sender = CallResultSender(shared.clone());
+    call_after(Box::new(move || sender.complete(callback())));
+    CallAfterHandle { shared }
+}
+
+enum CallResultState<R> {
+    // Holds the waker of an async caller, if any
+    Pending(Option<Waker>),
+    Ready(R),
+    // The callback was dropped without running or panicked
+    Abandoned,
+    Taken,
+}
+
+struct CallResultShared<R> {
+    state: Mutex<CallResultState<R>>,
+    done: Condvar,
+}
+
+impl<R> CallResultShared<R> {
+    fn finish(&self, new_state: CallResultState<R>) {
+        let mut state = self.state.lock().unwrap();
+        // Keep the first outcome; the sender's Drop runs after a successful complete
+        let CallResultState::Pending(waker) = &mut *state else {
+            return;
+        };
+        if let Some(waker) = waker.take() {
+            waker.wake();
+        }
+        *state = new_state;
+        drop(state);
+        self.done.notify_all();
+    }
+}
+
+// Moved into the queued closure; marks the result abandoned if dropped unused.
+struct CallResultSender<R>(Arc<CallResultShared<R>>);
+
+impl<R> CallResultSender<R> {
+    fn complete(&self, value: R) {
+        self.0.finish(CallResultState::Ready(value));
+    }
+}
+
+impl<R> Drop for CallResultSender<R> {
+    fn drop(&mut self) {
+        self.0.finish(CallResultState::Abandoned);
+    }
+}
+
+/// Handle to the result of a callback scheduled with [`call_after_with_result`].
+///
+/// The result is `None` if the callback never produced a value, because it
+/// panicked or because the application exited before it ran.
+pub struct CallAfterHandle<R> {
+    shared: Arc<CallResultShared<R>>,
+}
+
+impl<R> CallAfterHandle<R> {
+    /// Blocks the current thread until the callback has run and returns its result.
+    pub fn wait(self) -> Option<R> {
+        let mut state = self.shared.state.lock().unwrap();
+        while matches!(*state, CallResultState::Pending(_)) {
+            state = self.shared.done.wait(state).unwrap();
+        }
+        Self::take(&mut state)
+    }
+
+    /// Like [`wait`](Self::wait), but gives up after `timeout`.
+    ///
+    /// Returns `Err(self)` on timeout so the caller can keep waiting later.
+    pub fn wait_timeout(self, timeout: Duration) -> Result<Option<R>, Self> {
+        let deadline = Instant::now() + timeout;
+        let mut state = self.shared.state.lock().unwrap();
+        while matches!(*state, CallResultState::Pending(_)) {
+            let now = Instant::now();
+            if now >= deadline {
+                drop(state);
+                return Err(self);
+            }
+            state = self
+                .shared
+                .done
+                .wait_timeout(state, deadline - now)
+                .unwrap()
+                .0;
+        }
+        Ok(Self::take(&mut state))
+    }
+
+    /// Returns true once the callback has run (or was dropped without running).
+    pub fn is_finished(&self) -> bool {
+        !matches!(
+            *self.shared.state.lock().unwrap(),
+            CallResultState::Pending(_)
+        )
+    }
+
+    fn take(state: &mut CallResultState<R>) -> Option<R> {
+        match std::mem::replace(state, CallResultState::Taken) {
+            CallResultState::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl<R> Future for CallAfterHandle<R> {
+    type Output = Option<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        let mut state = self.shared.state.lock().unwrap();
+        if let CallResultState::Pending(waker) = &mut *state {
+            *waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        Poll::Ready(Self::take(&mut state))
+    }
 }
 
 /// Processes pending callbacks queued via `call_after`.
@@ -68,20 +234,26 @@ pub fn process_main_thread_queue() -> bool {
 
     // Execute callbacks outside of the lock
     for callback in callbacks {
-        callback();
+        // Panics must not unwind into the C++ idle handler
+        if let Err(payload) = catch_handler_panic(callback) {
+            handle_handler_panic(payload, None, None);
+        }
     }
 
     true // We processed some callbacks
 }
 
 // This function is called from C++ to process pending callbacks
-// Returns 1 if callbacks were processed, 0 if not
+// Returns 1 if callbacks were processed or tasks polled, 0 if not
 #[no_mangle]
 pub extern "C" fn process_rust_callbacks() -> i32 {
-    if process_main_thread_queue() {
-        1 // Callbacks were processed
+    // Callbacks may wake tasks, so run them first and poll in the same pass
+    let callbacks_processed = process_main_thread_queue();
+    let tasks_polled = crate::executor::run_ready_tasks();
+    if callbacks_processed || tasks_polled {
+        1 // Work was done; more may be pending
     } else {
-        0 // No callbacks processed
+        0 // Nothing to do
     }
 }
 
@@ -108,6 +280,37 @@ where
     }
 }
 
+/// Sets the application name, which names the settings file used by
+/// [`Config`](crate::config::Config) and the per-user directories returned by
+/// [`StandardPaths`](crate::standard_paths::StandardPaths).
+///
+/// Set it before any of those are used, e.g. with [`AppOptions::with_app_name`].
+/// By default it is derived from the program name.
+pub fn set_app_name(name: &str) {
+    let app_ptr = unsafe { ffi::wxd_GetApp() };
+    if let (false, Ok(c_name)) = (app_ptr.is_null(), CString::new(name)) {
+        unsafe { ffi::wxd_App_SetAppName(app_ptr, c_name.as_ptr()) };
+    }
+}
+
+/// Gets the application name
+pub fn app_name() -> String {
+    unsafe { take_string(ffi::wxd_App_GetAppName(ffi::wxd_GetApp())) }
+}
+
+/// Sets the vendor name, which is part of some per-user paths on Windows.
+pub fn set_vendor_name(name: &str) {
+    let app_ptr = unsafe { ffi::wxd_GetApp() };
+    if let (false, Ok(c_name)) = (app_ptr.is_null(), CString::new(name)) {
+        unsafe { ffi::wxd_App_SetVendorName(app_ptr, c_name.as_ptr()) };
+    }
+}
+
+/// Gets the vendor name, empty unless set with [`set_vendor_name`]
+pub fn vendor_name() -> String {
+    unsafe { take_string(ffi::wxd_App_GetVendorName(ffi::wxd_GetApp())) }
+}
+
 /// Gets the current application instance for appearance operations.
 ///
 /// This provides a convenient way to access appearance-related functions
@@ -180,12 +383,237 @@ pub fn set_appearance(
     }
 }
 
+// --- Panic policy for event handlers ---
+
+/// A panic caught while running an event handler closure, a [`call_after`]
+/// callback or a task spawned with [`spawn_local`](crate::executor::spawn_local).
+///
+/// Passed to the active [`PanicPolicy`]. When the policy is
+/// [`PanicPolicy::ExitWithError`], this is also the error returned from [`main`].
+pub struct HandlerPanic {
+    payload: Box<dyn Any + Send>,
+    event_type: Option<EventType>,
+    window: Option<Window>,
+    backtrace: Option<Backtrace>,
+}
+
+impl HandlerPanic {
+    /// Returns the panic message, if the payload was a string (as it is for `panic!`
+    /// with a message).
+    pub fn message(&self) -> Option<&str> {
+        if let Some(s) = self.payload.downcast_ref::<&'static str>() {
+            Some(s)
+        } else {
+            self.payload.downcast_ref::<String>().map(String::as_str)
+        }
+    }
+
+    /// Returns the raw panic payload.
+    pub fn payload(&self) -> &(dyn Any + Send) {
+        &*self.payload
+    }
+
+    /// Returns the type of the event that was being handled, or `None` if the
+    /// panic did not come from an event handler.
+    pub fn event_type(&self) -> Option<EventType> {
+        self.event_type
+    }
+
+    /// Returns the window the event originated from.
+    ///
+    /// Always `None` for the error returned from [`main`], as windows have been
+    /// destroyed by then.
+    pub fn window(&self) -> Option<Window> {
+        self.window
+    }
+
+    /// Returns the backtrace captured at the point of the panic, if available.
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_ref()
+    }
+}
+
+impl fmt::Display for HandlerPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "event handler panicked: {}",
+            self.message().unwrap_or("<non-string panic payload>")
+        )?;
+        if let Some(event_type) = self.event_type {
+            write!(f, " (while handling {event_type:?})")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for HandlerPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HandlerPanic")
+            .field("message", &self.message())
+            .field("event_type", &self.event_type)
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::error::Error for HandlerPanic {}
+
+/// What to do when an event handler closure panics.
+///
+/// Panics never unwind into wxWidgets; they are caught at the handler boundary
+/// and passed to the policy set with [`set_panic_policy`].
+#[derive(Default)]
+pub enum PanicPolicy {
+    /// Print the panic to stderr and keep running. This is the default.
+    #[default]
+    LogAndContinue,
+    /// Show a modal error dialog with the panic message and backtrace, then keep running.
+    ShowErrorDialog,
+    /// Stop the main event loop and return the panic as an error from [`main`].
+    ExitWithError,
+    /// Call a user-supplied hook.
+    Custom(Box<dyn Fn(&HandlerPanic)>),
+}
+
+thread_local! {
+    static PANIC_POLICY: RefCell<Rc<PanicPolicy>> = RefCell::new(Rc::new(PanicPolicy::default()));
+    static LAST_PANIC_BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+    // Nesting depth of catch_handler_panic; backtraces are only captured inside it
+    static HANDLER_CATCH_DEPTH: Cell<u32> = const { Cell::new(0) };
+    static EXIT_ERROR: RefCell<Option<HandlerPanic>> = const { RefCell::new(None) };
+}
+
+/// Sets how panics in event handler closures are handled.
+///
+/// Must be called on the main thread.
+///
+/// # Example
+/// ```no_run
+/// use wxdragon::app::PanicPolicy;
+///
+/// wxdragon::main(|_| {
+///     // Surface handler bugs to the user instead of only on stderr
+///     wxdragon::app::set_panic_policy(PanicPolicy::ShowErrorDialog);
+/// });
+/// ```
+pub fn set_panic_policy(policy: PanicPolicy) {
+    PANIC_POLICY.with(|p| *p.borrow_mut() = Rc::new(policy));
+}
+
+/// Installs a hook that is called whenever an event handler closure panics.
+///
+/// Shorthand for `set_panic_policy(PanicPolicy::Custom(Box::new(hook)))`.
+pub fn set_panic_hook<F>(hook: F)
+where
+    F: Fn(&HandlerPanic) + 'static,
+{
+    set_panic_policy(PanicPolicy::Custom(Box::new(hook)));
+}
+
+// Records a backtrace for panics raised inside catch_handler_panic so it can be
+// attached to the HandlerPanic, then defers to whatever hook was installed before.
+// Other panics are left to the previous hook untouched.
+fn install_backtrace_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            if HANDLER_CATCH_DEPTH.with(Cell::get) > 0 {
+                let backtrace = Backtrace::force_capture();
+                LAST_PANIC_BACKTRACE.with(|bt| *bt.borrow_mut() = Some(backtrace));
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Runs a handler closure, catching a panic so it does not unwind into C++.
+///
+/// Pass the `Err` payload to [`handle_handler_panic`]; the panic's backtrace is
+/// recorded for it.
+pub(crate) fn catch_handler_panic<R>(f: impl FnOnce() -> R) -> std::thread::Result<R> {
+    HANDLER_CATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+    HANDLER_CATCH_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    result
+}
+
+/// Applies the current panic policy to a panic caught in an event handler.
+pub(crate) fn handle_handler_panic(
+    payload: Box<dyn Any + Send>,
+    event_type: Option<EventType>,
+    window: Option<Window>,
+) {
+    let panic = HandlerPanic {
+        payload,
+        event_type,
+        window,
+        backtrace: LAST_PANIC_BACKTRACE.with(|bt| bt.borrow_mut().take()),
+    };
+    // Clone the Rc so a hook can replace the policy while it runs
+    let policy = PANIC_POLICY.with(|p| p.borrow().clone());
+
+    match &*policy {
+        PanicPolicy::LogAndContinue => {
+            eprintln!("wxdragon: {panic}");
+        }
+        PanicPolicy::ShowErrorDialog => {
+            eprintln!("wxdragon: {panic}");
+            show_panic_dialog(&panic);
+        }
+        PanicPolicy::ExitWithError => {
+            eprintln!("wxdragon: {panic}; exiting main loop");
+            EXIT_ERROR.with(|slot| {
+                let mut slot = slot.borrow_mut();
+                // Keep the first panic; later ones are usually fallout from it
+                if slot.is_none() {
+                    *slot = Some(HandlerPanic {
+                        window: None,
+                        ..panic
+                    });
+                }
+            });
+            unsafe { ffi::wxd_App_ExitMainLoop() };
+        }
+        PanicPolicy::Custom(hook) => hook(&panic),
+    }
+}
+
+fn show_panic_dialog(panic: &HandlerPanic) {
+    let mut message = panic.to_string();
+    if let Some(backtrace) = &panic.backtrace {
+        message.push_str(&format!("\n\n{backtrace}"));
+    }
+    // Without a window (e.g. a panic in call_after) the dialog has no parent
+    let parent = panic.window.map_or(std::ptr::null_mut(), |window| window.0);
+    let c_message = CString::new(message.replace('\0', "")).unwrap_or_default();
+    let c_caption = CString::new("Unexpected Error").unwrap_or_default();
+    let style = MessageDialogStyle::OK | MessageDialogStyle::IconError;
+    let ptr = unsafe {
+        ffi::wxd_MessageDialog_Create(
+            parent,
+            c_message.as_ptr(),
+            c_caption.as_ptr(),
+            style.bits() as ffi::wxd_Style_t,
+        )
+    };
+    if !ptr.is_null() {
+        let dialog = unsafe { MessageDialog::from_ptr(ptr) };
+        dialog.show_modal();
+    }
+}
+
 /// Runs the wxWidgets application main loop, providing a safe entry point.
 ///
 /// This function initializes wxWidgets and starts the event loop. It takes a closure
 /// `on_init` that will be called once after basic initialization but before the
 /// main event loop begins.
 ///
+/// # Errors
+/// Returns the [`HandlerPanic`] that stopped the event loop when the panic policy is
+/// [`PanicPolicy::ExitWithError`].
+///
 /// # Panics
 /// Panics if initialization fails or if the program name cannot be converted to a CString.
 ///
@@ -206,6 +634,127 @@ pub fn main<F>(on_init: F) -> Result<(), Box<dyn std::error::Error>>
 where
     F: FnOnce(()) + 'static,
 {
+    main_with_options(AppOptions::new(), on_init)
+}
+
+/// Options for [`main_with_options`]
+#[derive(Default)]
+pub struct AppOptions {
+    app_name: Option<String>,
+    vendor_name: Option<String>,
+    locale: Option<LocaleSetup>,
+    single_instance: Option<(String, Box<dyn FnMut(Vec<String>)>)>,
+}
+
+impl fmt::Debug for AppOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AppOptions")
+            .field("app_name", &self.app_name)
+            .field("vendor_name", &self.vendor_name)
+            .field("locale", &self.locale)
+            .field(
+                "single_instance",
+                &self.single_instance.as_ref().map(|(name, _)| name),
+            )
+            .finish()
+    }
+}
+
+impl AppOptions {
+    /// Creates the default options, which leave the names and locale alone
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the application name; see [`set_app_name`]
+    pub fn with_app_name(mut self, name: &str) -> Self {
+        self.app_name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the vendor name; see [`set_vendor_name`]
+    pub fn with_vendor_name(mut self, name: &str) -> Self {
+        self.vendor_name = Some(name.to_string());
+        self
+    }
+
+    /// Sets up the locale and translations before `on_init` is called.
+    ///
+    /// Catalog domains that have no translation for the selected language are
+    /// reported on stderr; call [`LocaleSetup::apply`] yourself to handle them.
+    pub fn with_locale(mut self, locale: LocaleSetup) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Allows only one instance of the application per user, identified by `name`.
+    ///
+    /// If another instance is running, the command line arguments (without the
+    /// program name) are forwarded to it and the application exits without
+    /// calling `on_init`. Otherwise `on_args` is called with the arguments of
+    /// each later instance. See the [`single_instance`](crate::single_instance) module.
+    pub fn with_single_instance<F>(mut self, name: &str, on_args: F) -> Self
+    where
+        F: FnMut(Vec<String>) + 'static,
+    {
+        self.single_instance = Some((name.to_string(), Box::new(on_args)));
+        self
+    }
+}
+
+/// Runs the application like [`main`], applying `options` first.
+///
+/// # Example
+/// ```no_run
+/// use wxdragon::app::AppOptions;
+/// use wxdragon::i18n::LocaleSetup;
+/// use wxdragon::prelude::*;
+///
+/// let options = AppOptions::new().with_locale(LocaleSetup::new().with_catalog("myapp"));
+/// let _ = wxdragon::app::main_with_options(options, |_| {
+///     let frame = Frame::builder().with_title(&wxdragon::tr!("My App")).build();
+///     frame.show(true);
+/// });
+/// ```
+pub fn main_with_options<F>(
+    options: AppOptions,
+    on_init: F,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    F: FnOnce(()) + 'static,
+{
+    install_backtrace_hook();
+
+    let on_init = move |()| {
+        if let Some((name, on_args)) = options.single_instance {
+            if crate::single_instance::is_another_running(&name) {
+                let args = std::env::args_os()
+                    .skip(1)
+                    .map(|arg| arg.to_string_lossy().into_owned());
+                crate::single_instance::forward_args(&name, args);
+                // No windows are created, so leave the main loop as soon as it runs
+                call_after(Box::new(|| unsafe { ffi::wxd_App_ExitMainLoop() }));
+                return;
+            }
+            crate::single_instance::listen(&name, on_args);
+        }
+        if let Some(name) = &options.app_name {
+            set_app_name(name);
+        }
+        if let Some(name) = &options.vendor_name {
+            set_vendor_name(name);
+        }
+        if let Some(locale) = &options.locale {
+            let missing = locale.apply();
+            if !missing.is_empty() {
+                eprintln!(
+                    "wxdragon: no translation catalog found for: {}",
+                    missing.join(", ")
+                );
+            }
+        }
+        on_init(())
+    };
     let on_init_boxed: Box<Box<dyn FnOnce(())>> = Box::new(Box::new(on_init));
     let user_data_ptr = Box::into_raw(on_init_boxed) as *mut c_void;
 
@@ -231,6 +780,14 @@ where
         panic!("Application exited with code: {exit_code}");
     }
 
+    // Callbacks that never got to run are dropped so waiting threads are released
+    let unprocessed: Vec<_> = MAIN_THREAD_QUEUE.lock().unwrap().drain(..).collect();
+    drop(unprocessed);
+
+    if let Some(panic) = EXIT_ERROR.with(|slot| slot.borrow_mut().take()) {
+        return Err(Box::new(panic));
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,617 @@
+//! Two-way data binding between Rust state and controls.
+//!
+//! A [`Property`] is a shared, observable value. Binding it to a control keeps
+//! the two in sync: changing the property updates the control, and editing the
+//! control updates the property (and through it every other control bound to
+//! the same property).
+//!
+//! Controls whose value has a different type than the property are bound with a
+//! [`Converter`], e.g. [`NumberConverter`] for numbers in a [`TextCtrl`] or
+//! [`EnumConverter`] for an enum shown in a [`Choice`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! #[derive(Clone, Copy, PartialEq)]
+//! enum Quality {
+//!     Low,
+//!     High,
+//! }
+//!
+//! let frame = Frame::builder().with_title("Settings").build();
+//! let panel = Panel::builder(&frame).build();
+//!
+//! let name = Property::new(String::from("untitled"));
+//! let width = Property::new(640u32);
+//! let quality = Property::new(Quality::High);
+//!
+//! name.bind(&TextCtrl::builder(&panel).build());
+//! width.bind_with(&TextCtrl::builder(&panel).build(), NumberConverter::new());
+//! let choice = Choice::builder(&panel).with_choices(vec!["Low".into(), "High".into()]).build();
+//! quality.bind_with(&choice, EnumConverter::new(&[Quality::Low, Quality::High]));
+//!
+//! width.subscribe(|w| println!("Width is now {w}"));
+//! ```
+
+use crate::event::{Event, EventType, WxEvtHandler};
+use crate::widgets::choice::NOT_FOUND;
+use crate::widgets::{
+    CheckBox, Choice, ComboBox, RadioBox, Slider, SpinCtrl, SpinCtrlDouble, TextCtrl, ToggleButton,
+};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::str::FromStr;
+
+/// Identifies an observer registered with [`Property::subscribe`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+type Observer<T> = Rc<dyn Fn(&T)>;
+
+struct PropertyInner<T> {
+    value: RefCell<T>,
+    observers: RefCell<Vec<(SubscriptionId, Observer<T>)>>,
+    next_id: Cell<u64>,
+}
+
+/// A shared value that notifies observers when it changes
+///
+/// Clones refer to the same value. Observers run on the thread that changes the
+/// value, which for bound controls is always the GUI thread.
+pub struct Property<T> {
+    inner: Rc<PropertyInner<T>>,
+}
+
+impl<T> Clone for Property<T> {
+    fn clone(&self) -> Self {
+        Property {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Property<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Property")
+            .field(&*self.inner.value.borrow())
+            .finish()
+    }
+}
+
+impl<T: Default> Default for Property<T> {
+    fn default() -> Self {
+        Property::new(T::default())
+    }
+}
+
+impl<T> Property<T> {
+    /// Creates a property holding `value`
+    pub fn new(value: T) -> Self {
+        Property {
+            inner: Rc::new(PropertyInner {
+                value: RefCell::new(value),
+                observers: RefCell::new(Vec::new()),
+                next_id: Cell::new(0),
+            }),
+        }
+    }
+
+    /// Calls `f` with a reference to the current value
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.inner.value.borrow())
+    }
+
+    /// Registers `observer` to be called with the new value after every change.
+    pub fn subscribe<F>(&self, observer: F) -> SubscriptionId
+    where
+        F: Fn(&T) + 'static,
+    {
+        let id = SubscriptionId(self.inner.next_id.get());
+        self.inner.next_id.set(id.0 + 1);
+        self.inner
+            .observers
+            .borrow_mut()
+            .push((id, Rc::new(observer)));
+        id
+    }
+
+    /// Removes an observer. Returns false if it was already removed.
+    pub fn unsubscribe(&self, id: SubscriptionId) -> bool {
+        let mut observers = self.inner.observers.borrow_mut();
+        let len = observers.len();
+        observers.retain(|(observer_id, _)| *observer_id != id);
+        observers.len() != len
+    }
+
+    /// Returns true if both handles refer to the same value
+    pub fn ptr_eq(&self, other: &Property<T>) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T: Clone> Property<T> {
+    /// Gets a copy of the current value
+    pub fn get(&self) -> T {
+        self.inner.value.borrow().clone()
+    }
+}
+
+impl<T: Clone + PartialEq> Property<T> {
+    /// Sets the value, notifying observers if it differs from the current one.
+    pub fn set(&self, value: T) {
+        {
+            let mut current = self.inner.value.borrow_mut();
+            if *current == value {
+                return;
+            }
+            *current = value;
+        }
+        self.notify();
+    }
+
+    /// Modifies the value in place, notifying observers if it changed.
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        let mut value = self.get();
+        f(&mut value);
+        self.set(value);
+    }
+
+    // Observers may set the property or (un)subscribe, so nothing stays borrowed
+    // while they run
+    fn notify(&self) {
+        let value = self.get();
+        let observers: Vec<Observer<T>> = self
+            .inner
+            .observers
+            .borrow()
+            .iter()
+            .map(|(_, observer)| observer.clone())
+            .collect();
+        for observer in observers {
+            observer(&value);
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> Property<T> {
+    /// Binds the property to a control holding the same type of value.
+    ///
+    /// The control is set to the property's current value straight away.
+    pub fn bind<C>(&self, control: &C) -> Binding
+    where
+        C: BindableControl<Value = T>,
+    {
+        self.bind_with(control, Identity)
+    }
+
+    /// Binds the property to a control, converting values with `converter`.
+    ///
+    /// Edits the converter rejects leave the property unchanged.
+    pub fn bind_with<C, V>(&self, control: &C, converter: V) -> Binding
+    where
+        C: BindableControl,
+        V: Converter<T, C::Value> + 'static,
+    {
+        let converter = Rc::new(converter);
+        // Set while one side is being updated from the other to break the cycle
+        let updating = Rc::new(Cell::new(false));
+        let alive = Rc::new(Cell::new(true));
+
+        let apply = {
+            let control = control.clone();
+            let converter = converter.clone();
+            let updating = updating.clone();
+            let alive = alive.clone();
+            move |value: &T| {
+                if !alive.get() || updating.get() {
+                    return;
+                }
+                updating.set(true);
+                control.set_bound_value(&converter.to_control(value));
+                updating.set(false);
+            }
+        };
+        self.with(&apply);
+        let subscription = self.subscribe(apply);
+
+        let changed = control.bind_internal(control.value_changed_event(), {
+            let property = self.clone();
+            let control = control.clone();
+            let updating = updating.clone();
+            move |event: Event| {
+                // Let the application's own handlers see the event too
+                event.skip(true);
+                if updating.get() {
+                    return;
+                }
+                if let Some(value) = converter.from_control(&control.bound_value()) {
+                    updating.set(true);
+                    property.set(value);
+                    updating.set(false);
+                }
+            }
+        });
+
+        let destroyed = control.bind_internal(EventType::DESTROY, {
+            let property = self.clone();
+            let alive = alive.clone();
+            move |event: Event| {
+                event.skip(true);
+                alive.set(false);
+                property.unsubscribe(subscription);
+            }
+        });
+
+        let control = control.clone();
+        let property = self.clone();
+        Binding {
+            unbind: Some(Box::new(move || {
+                property.unsubscribe(subscription);
+                if alive.replace(false) {
+                    control.unbind(changed);
+                    control.unbind(destroyed);
+                }
+            })),
+        }
+    }
+}
+
+/// A link between a [`Property`] and a control, returned by
+/// [`Property::bind`] and [`Property::bind_with`]
+///
+/// Like [`EventBinding`](crate::event::EventBinding), dropping it does *not* remove the link; it lasts until
+/// [`unbind`](Self::unbind) is called or the control is destroyed.
+pub struct Binding {
+    unbind: Option<Box<dyn FnOnce()>>,
+}
+
+impl Binding {
+    /// Stops syncing the property and the control. Both keep their current values.
+    pub fn unbind(mut self) {
+        if let Some(unbind) = self.unbind.take() {
+            unbind();
+        }
+    }
+}
+
+impl fmt::Debug for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Binding").finish_non_exhaustive()
+    }
+}
+
+/// Converts between a property's value and a control's value
+pub trait Converter<T, C> {
+    /// Converts a property value for display in the control
+    fn to_control(&self, value: &T) -> C;
+
+    /// Converts the control's value, or returns `None` if it is not valid for
+    /// the property
+    fn from_control(&self, value: &C) -> Option<T>;
+}
+
+/// Passes values through unchanged
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Identity;
+
+impl<T: Clone> Converter<T, T> for Identity {
+    fn to_control(&self, value: &T) -> T {
+        value.clone()
+    }
+
+    fn from_control(&self, value: &T) -> Option<T> {
+        Some(value.clone())
+    }
+}
+
+/// Shows a number as text, using `Display` and `FromStr`
+///
+/// Surrounding whitespace is ignored; text that doesn't parse leaves the
+/// property unchanged.
+pub struct NumberConverter<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> NumberConverter<T> {
+    pub fn new() -> Self {
+        NumberConverter {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for NumberConverter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Display + FromStr> Converter<T, String> for NumberConverter<T> {
+    fn to_control(&self, value: &T) -> String {
+        value.to_string()
+    }
+
+    fn from_control(&self, value: &String) -> Option<T> {
+        value.trim().parse().ok()
+    }
+}
+
+/// Lets a control be bound to a property of a different numeric type, e.g. a
+/// `u8` property to a [`SpinCtrl`]. Out-of-range control values are rejected.
+impl<T> Converter<T, i32> for NumberConverter<T>
+where
+    T: Copy + TryFrom<i32> + TryInto<i32>,
+{
+    fn to_control(&self, value: &T) -> i32 {
+        (*value).try_into().unwrap_or_default()
+    }
+
+    fn from_control(&self, value: &i32) -> Option<T> {
+        T::try_from(*value).ok()
+    }
+}
+
+/// Maps enum values to the item indices of a [`Choice`], [`ComboBox`] or
+/// [`RadioBox`]
+///
+/// `variants` lists the values in the same order as the control's items. A
+/// value missing from the list clears the selection.
+pub struct EnumConverter<T> {
+    variants: Vec<T>,
+}
+
+impl<T: Clone + PartialEq> EnumConverter<T> {
+    pub fn new(variants: &[T]) -> Self {
+        EnumConverter {
+            variants: variants.to_vec(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Converter<T, Option<u32>> for EnumConverter<T> {
+    fn to_control(&self, value: &T) -> Option<u32> {
+        self.variants
+            .iter()
+            .position(|variant| variant == value)
+            .map(|index| index as u32)
+    }
+
+    fn from_control(&self, value: &Option<u32>) -> Option<T> {
+        value.and_then(|index| self.variants.get(index as usize).cloned())
+    }
+}
+
+/// Converts with a pair of closures
+pub struct FnConverter<To, From> {
+    to_control: To,
+    from_control: From,
+}
+
+impl<To, From> FnConverter<To, From> {
+    pub fn new(to_control: To, from_control: From) -> Self {
+        FnConverter {
+            to_control,
+            from_control,
+        }
+    }
+}
+
+impl<T, C, To, From> Converter<T, C> for FnConverter<To, From>
+where
+    To: Fn(&T) -> C,
+    From: Fn(&C) -> Option<T>,
+{
+    fn to_control(&self, value: &T) -> C {
+        (self.to_control)(value)
+    }
+
+    fn from_control(&self, value: &C) -> Option<T> {
+        (self.from_control)(value)
+    }
+}
+
+/// A control whose value can be bound to a [`Property`]
+pub trait BindableControl: WxEvtHandler + Clone + 'static {
+    /// The type of value the control edits
+    type Value: Clone;
+
+    /// Gets the control's current value
+    fn bound_value(&self) -> Self::Value;
+
+    /// Sets the control's value
+    fn set_bound_value(&self, value: &Self::Value);
+
+    /// The event the control sends when the user changes its value
+    #[doc(hidden)]
+    fn value_changed_event(&self) -> EventType;
+}
+
+impl BindableControl for TextCtrl {
+    type Value = String;
+
+    fn bound_value(&self) -> String {
+        self.get_value()
+    }
+
+    fn set_bound_value(&self, value: &String) {
+        self.set_value(value);
+    }
+
+    fn value_changed_event(&self) -> EventType {
+        EventType::TEXT
+    }
+}
+
+impl BindableControl for ComboBox {
+    type Value = String;
+
+    fn bound_value(&self) -> String {
+        self.get_value()
+    }
+
+    fn set_bound_value(&self, value: &String) {
+        self.set_value(value);
+    }
+
+    // Also sent when an item is picked from the list
+    fn value_changed_event(&self) -> EventType {
+        EventType::TEXT
+    }
+}
+
+impl BindableControl for CheckBox {
+    type Value = bool;
+
+    fn bound_value(&self) -> bool {
+        self.get_value()
+    }
+
+    fn set_bound_value(&self, value: &bool) {
+        self.set_value(*value);
+    }
+
+    fn value_changed_event(&self) -> EventType {
+        EventType::CHECKBOX
+    }
+}
+
+impl BindableControl for ToggleButton {
+    type Value = bool;
+
+    fn bound_value(&self) -> bool {
+        self.get_value()
+    }
+
+    fn set_bound_value(&self, value: &bool) {
+        self.set_value(*value);
+    }
+
+    fn value_changed_event(&self) -> EventType {
+        EventType::COMMAND_TOGGLEBUTTON_CLICKED
+    }
+}
+
+impl BindableControl for Choice {
+    type Value = Option<u32>;
+
+    fn bound_value(&self) -> Option<u32> {
+        self.get_selection()
+    }
+
+    fn set_bound_value(&self, value: &Option<u32>) {
+        // NOT_FOUND clears the selection
+        self.set_selection(value.unwrap_or(NOT_FOUND as u32));
+    }
+
+    fn value_changed_event(&self) -> EventType {
+        EventType::COMMAND_CHOICE_SELECTED
+    }
+}
+
+impl BindableControl for RadioBox {
+    type Value = Option<u32>;
+
+    fn bound_value(&self) -> Option<u32> {
+        u32::try_from(self.get_selection()).ok()
+    }
+
+    fn set_bound_value(&self, value: &Option<u32>) {
+        // A radio box always has a selection
+        if let Some(index) = value {
+            self.set_selection(*index as i32);
+        }
+    }
+
+    fn value_changed_event(&self) -> EventType {
+        EventType::COMMAND_RADIOBOX_SELECTED
+    }
+}
+
+impl BindableControl for SpinCtrl {
+    type Value = i32;
+
+    fn bound_value(&self) -> i32 {
+        self.get_value()
+    }
+
+    fn set_bound_value(&self, value: &i32) {
+        self.set_value(*value);
+    }
+
+    fn value_changed_event(&self) -> EventType {
+        EventType::SPINCTRL
+    }
+}
+
+impl BindableControl for SpinCtrlDouble {
+    type Value = f64;
+
+    fn bound_value(&self) -> f64 {
+        self.get_value()
+    }
+
+    fn set_bound_value(&self, value: &f64) {
+        self.set_value(*value);
+    }
+
+    fn value_changed_event(&self) -> EventType {
+        EventType::SPINCTRLDOUBLE
+    }
+}
+
+impl BindableControl for Slider {
+    type Value = i32;
+
+    fn bound_value(&self) -> i32 {
+        self.get_value()
+    }
+
+    fn set_bound_value(&self, value: &i32) {
+        self.set_value(*value);
+    }
+
+    fn value_changed_event(&self) -> EventType {
+        EventType::SLIDER
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_notifies_only_on_change() {
+        let property = Property::new(1);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_by_observer = seen.clone();
+        let id = property.subscribe(move |v| seen_by_observer.borrow_mut().push(*v));
+
+        property.set(1);
+        property.set(2);
+        property.update(|v| *v += 1);
+        assert!(property.unsubscribe(id));
+        property.set(4);
+
+        assert_eq!(*seen.borrow(), vec![2, 3]);
+        assert_eq!(property.get(), 4);
+    }
+
+    #[test]
+    fn converters_round_trip() {
+        let numbers = NumberConverter::<u16>::new();
+        assert_eq!(Converter::<u16, String>::to_control(&numbers, &42), "42");
+        assert_eq!(numbers.from_control(&" 7 ".to_string()), Some(7));
+        assert_eq!(numbers.from_control(&"x".to_string()), None);
+        assert_eq!(Converter::<u16, i32>::from_control(&numbers, &-1), None);
+
+        let letters = EnumConverter::new(&['a', 'b']);
+        assert_eq!(letters.to_control(&'b'), Some(1));
+        assert_eq!(letters.to_control(&'z'), None);
+        assert_eq!(letters.from_control(&Some(0)), Some('a'));
+        assert_eq!(letters.from_control(&Some(5)), None);
+    }
+}
@@ -1,6 +1,7 @@
 //!
 //! Safe wrapper for wxBitmap.
 
+use crate::image::Image;
 use std::os::raw::{c_int, c_uchar};
 use wxdragon_sys as ffi;
 
@@ -61,6 +62,21 @@ impl Bitmap {
         }
     }
 
+    /// Creates a bitmap from an [`Image`], for example one loaded from a PNG file.
+    ///
+    /// Returns `None` if the image is invalid.
+    pub fn from_image(image: &Image) -> Option<Self> {
+        let ptr = unsafe { ffi::wxd_Bitmap_CreateFromImage(image.as_ptr()) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Bitmap {
+                ptr,
+                is_owned: true,
+            })
+        }
+    }
+
     /// Creates a bitmap wrapper around an existing bitmap pointer, transferring ownership to Rust.
     /// The bitmap will be destroyed when the wrapper is dropped.
     ///
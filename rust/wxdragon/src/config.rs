@@ -0,0 +1,345 @@
+//! Application settings storage.
+//!
+//! A [`Config`] is a tree of groups holding named entries, stored in an
+//! INI-style file. Keys are relative to the current path, set with
+//! [`Config::set_path`], unless they start with `/`; a key such as
+//! `"Recent/File1"` refers to the entry `File1` in the subgroup `Recent`.
+//!
+//! Any string can be stored, so the output of `AuiManager::save_perspective`
+//! can be kept next to the rest of the settings. Making a config the global one
+//! with [`Config::set_global`] also makes window state saved by the
+//! [`persistence`](crate::persistence) module go to it.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::config::Config;
+//!
+//! let config = Config::new("MyApp", "MyCompany");
+//! config.write("Window/Width", &800);
+//! config.write("LastFile", "notes.txt");
+//!
+//! let width: i32 = config.read_or("Window/Width", 640);
+//! let last_file = config.read::<String>("LastFile");
+//! config.flush();
+//! ```
+
+use crate::utils::{take_string, WxdArrayString};
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::path::Path;
+use wxdragon_sys as ffi;
+
+/// A value type that can be stored in a [`Config`]
+///
+/// Implemented for strings, `bool`, `i32`, `i64`, `u32` and `f64`.
+pub trait ConfigValue {
+    #[doc(hidden)]
+    fn read_config(config: &Config, key: &CStr) -> Option<Self>
+    where
+        Self: Sized;
+
+    #[doc(hidden)]
+    fn write_config(&self, config: &Config, key: &CStr) -> bool;
+}
+
+impl ConfigValue for str {
+    fn write_config(&self, config: &Config, key: &CStr) -> bool {
+        let Ok(c_value) = CString::new(self) else {
+            return false;
+        };
+        unsafe { ffi::wxd_Config_WriteString(config.raw(), key.as_ptr(), c_value.as_ptr()) }
+    }
+}
+
+impl ConfigValue for String {
+    fn read_config(config: &Config, key: &CStr) -> Option<Self> {
+        let mut value: *mut c_char = std::ptr::null_mut();
+        if !unsafe { ffi::wxd_Config_ReadString(config.raw(), key.as_ptr(), &mut value) } {
+            return None;
+        }
+        Some(unsafe { take_string(value) })
+    }
+
+    fn write_config(&self, config: &Config, key: &CStr) -> bool {
+        self.as_str().write_config(config, key)
+    }
+}
+
+impl ConfigValue for bool {
+    fn read_config(config: &Config, key: &CStr) -> Option<Self> {
+        let mut value = false;
+        unsafe { ffi::wxd_Config_ReadBool(config.raw(), key.as_ptr(), &mut value) }.then_some(value)
+    }
+
+    fn write_config(&self, config: &Config, key: &CStr) -> bool {
+        unsafe { ffi::wxd_Config_WriteBool(config.raw(), key.as_ptr(), *self) }
+    }
+}
+
+impl ConfigValue for i64 {
+    fn read_config(config: &Config, key: &CStr) -> Option<Self> {
+        let mut value = 0;
+        unsafe { ffi::wxd_Config_ReadLong(config.raw(), key.as_ptr(), &mut value) }.then_some(value)
+    }
+
+    fn write_config(&self, config: &Config, key: &CStr) -> bool {
+        unsafe { ffi::wxd_Config_WriteLong(config.raw(), key.as_ptr(), *self) }
+    }
+}
+
+// Stored as 64-bit integers; values out of range read as missing
+macro_rules! impl_config_value_via_i64 {
+    ($($ty:ty),*) => {
+        $(
+            impl ConfigValue for $ty {
+                fn read_config(config: &Config, key: &CStr) -> Option<Self> {
+                    i64::read_config(config, key).and_then(|value| <$ty>::try_from(value).ok())
+                }
+
+                fn write_config(&self, config: &Config, key: &CStr) -> bool {
+                    i64::from(*self).write_config(config, key)
+                }
+            }
+        )*
+    };
+}
+
+impl_config_value_via_i64!(i32, u32);
+
+impl ConfigValue for f64 {
+    fn read_config(config: &Config, key: &CStr) -> Option<Self> {
+        let mut value = 0.0;
+        unsafe { ffi::wxd_Config_ReadDouble(config.raw(), key.as_ptr(), &mut value) }
+            .then_some(value)
+    }
+
+    fn write_config(&self, config: &Config, key: &CStr) -> bool {
+        unsafe { ffi::wxd_Config_WriteDouble(config.raw(), key.as_ptr(), *self) }
+    }
+}
+
+/// Settings stored in a configuration file
+///
+/// Changes are written to the file by [`flush`](Self::flush) and when the
+/// config is dropped.
+pub struct Config {
+    ptr: *mut ffi::wxd_Config_t,
+    // Handles from `global` look the global config up on every use, so they
+    // never outlive it when it is replaced
+    global: bool,
+}
+
+impl Config {
+    /// Opens the settings file for the given application and vendor, e.g.
+    /// `~/.MyApp` on Linux or `MyApp.ini` in the user's roaming `AppData`
+    /// directory on Windows.
+    ///
+    /// Empty names stand for those of the running application.
+    pub fn new(app_name: &str, vendor_name: &str) -> Self {
+        let c_app = CString::new(app_name).unwrap_or_default();
+        let c_vendor = CString::new(vendor_name).unwrap_or_default();
+        let ptr = unsafe { ffi::wxd_Config_Create(c_app.as_ptr(), c_vendor.as_ptr()) };
+        Config { ptr, global: false }
+    }
+
+    /// Opens the settings file at `path`, which is created when first flushed.
+    ///
+    /// Useful for portable installs and for tests.
+    pub fn with_file<P: AsRef<Path>>(path: P) -> Self {
+        let c_path = CString::new(path.as_ref().to_string_lossy().as_bytes()).unwrap_or_default();
+        let ptr = unsafe { ffi::wxd_Config_CreateWithFile(c_path.as_ptr()) };
+        Config { ptr, global: false }
+    }
+
+    /// Gets the global config, creating the default one for the application if
+    /// none was set.
+    ///
+    /// The default config uses the registry on Windows. The handle always refers
+    /// to the current global config, also after it is replaced with
+    /// [`set_global`](Self::set_global).
+    pub fn global() -> Config {
+        Config {
+            ptr: std::ptr::null_mut(),
+            global: true,
+        }
+    }
+
+    /// Makes this the global config, replacing (and flushing) the previous one.
+    ///
+    /// wxWidgets owns the global config and flushes it when the application exits.
+    /// Calling this on the handle returned by [`global`](Self::global) does nothing.
+    pub fn set_global(mut self) {
+        unsafe { ffi::wxd_Config_SetGlobal(self.raw()) };
+        self.global = true;
+    }
+
+    fn raw(&self) -> *mut ffi::wxd_Config_t {
+        if self.global {
+            unsafe { ffi::wxd_Config_GetGlobal() }
+        } else {
+            self.ptr
+        }
+    }
+
+    /// Sets the current path. Relative paths are relative to the current one,
+    /// and `".."` moves up a level.
+    pub fn set_path(&self, path: &str) {
+        let c_path = CString::new(path).unwrap_or_default();
+        unsafe { ffi::wxd_Config_SetPath(self.raw(), c_path.as_ptr()) }
+    }
+
+    /// Gets the current path, e.g. `"/Window"`, or an empty string at the root
+    pub fn get_path(&self) -> String {
+        unsafe { take_string(ffi::wxd_Config_GetPath(self.raw())) }
+    }
+
+    /// Calls `f` with the current path set to `path`, then restores the previous path.
+    pub fn with_path<R>(&self, path: &str, f: impl FnOnce(&Self) -> R) -> R {
+        let previous = self.get_path();
+        self.set_path(path);
+        let result = f(self);
+        // An empty path wouldn't move back to the root when `path` was relative
+        self.set_path(if previous.is_empty() { "/" } else { &previous });
+        result
+    }
+
+    /// Reads an entry, or returns `None` if it is missing or not a valid `T`.
+    pub fn read<T: ConfigValue>(&self, key: &str) -> Option<T> {
+        let c_key = CString::new(key).ok()?;
+        T::read_config(self, &c_key)
+    }
+
+    /// Reads an entry, falling back to `default` if it is missing or invalid.
+    pub fn read_or<T: ConfigValue>(&self, key: &str, default: T) -> T {
+        self.read(key).unwrap_or(default)
+    }
+
+    /// Writes an entry, creating any missing groups. Returns false on failure.
+    pub fn write<T: ConfigValue + ?Sized>(&self, key: &str, value: &T) -> bool {
+        match CString::new(key) {
+            Ok(c_key) => value.write_config(self, &c_key),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns true if the entry exists
+    pub fn has_entry(&self, key: &str) -> bool {
+        let c_key = CString::new(key).unwrap_or_default();
+        unsafe { ffi::wxd_Config_HasEntry(self.raw(), c_key.as_ptr()) }
+    }
+
+    /// Returns true if the group exists
+    pub fn has_group(&self, key: &str) -> bool {
+        let c_key = CString::new(key).unwrap_or_default();
+        unsafe { ffi::wxd_Config_HasGroup(self.raw(), c_key.as_ptr()) }
+    }
+
+    /// Gets the names of the entries in the current group
+    pub fn entries(&self) -> Vec<String> {
+        let names = WxdArrayString::new();
+        unsafe { ffi::wxd_Config_GetEntries(self.raw(), names.as_ptr()) };
+        names.into_vec()
+    }
+
+    /// Gets the names of the subgroups of the current group
+    pub fn groups(&self) -> Vec<String> {
+        let names = WxdArrayString::new();
+        unsafe { ffi::wxd_Config_GetGroups(self.raw(), names.as_ptr()) };
+        names.into_vec()
+    }
+
+    /// Deletes an entry, and its group too if `delete_group_if_empty` is set and
+    /// nothing else is left in it.
+    pub fn delete_entry(&self, key: &str, delete_group_if_empty: bool) -> bool {
+        let c_key = CString::new(key).unwrap_or_default();
+        unsafe { ffi::wxd_Config_DeleteEntry(self.raw(), c_key.as_ptr(), delete_group_if_empty) }
+    }
+
+    /// Deletes a group with all its entries and subgroups
+    pub fn delete_group(&self, key: &str) -> bool {
+        let c_key = CString::new(key).unwrap_or_default();
+        unsafe { ffi::wxd_Config_DeleteGroup(self.raw(), c_key.as_ptr()) }
+    }
+
+    /// Deletes all settings, including the file
+    pub fn delete_all(&self) -> bool {
+        unsafe { ffi::wxd_Config_DeleteAll(self.raw()) }
+    }
+
+    /// Renames an entry in the current group. Fails if `new_name` is taken.
+    pub fn rename_entry(&self, old_name: &str, new_name: &str) -> bool {
+        let c_old = CString::new(old_name).unwrap_or_default();
+        let c_new = CString::new(new_name).unwrap_or_default();
+        unsafe { ffi::wxd_Config_RenameEntry(self.raw(), c_old.as_ptr(), c_new.as_ptr()) }
+    }
+
+    /// Renames a subgroup of the current group. Fails if `new_name` is taken.
+    pub fn rename_group(&self, old_name: &str, new_name: &str) -> bool {
+        let c_old = CString::new(old_name).unwrap_or_default();
+        let c_new = CString::new(new_name).unwrap_or_default();
+        unsafe { ffi::wxd_Config_RenameGroup(self.raw(), c_old.as_ptr(), c_new.as_ptr()) }
+    }
+
+    /// Writes pending changes to the file
+    pub fn flush(&self) -> bool {
+        unsafe { ffi::wxd_Config_Flush(self.raw()) }
+    }
+
+    /// Returns the raw underlying config pointer.
+    ///
+    /// For the global config this is the config that is global right now.
+    pub fn as_ptr(&self) -> *mut ffi::wxd_Config_t {
+        self.raw()
+    }
+}
+
+impl std::fmt::Debug for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Config")
+            .field("path", &self.get_path())
+            .finish()
+    }
+}
+
+impl Drop for Config {
+    fn drop(&mut self) {
+        if !self.global && !self.ptr.is_null() {
+            unsafe { ffi::wxd_Config_Destroy(self.ptr) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+
+    #[test]
+    fn values_round_trip_through_file() {
+        let path = std::env::temp_dir().join(format!("wxdragon-config-{}.ini", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let config = Config::with_file(&path);
+        assert!(config.write("Name", "wxdragon"));
+        assert!(config.write("Window/Width", &800));
+        assert!(config.write("Window/Maximized", &true));
+        assert!(config.write("Scale", &1.5));
+        assert!(config.flush());
+        drop(config);
+        assert!(path.exists());
+
+        let config = Config::with_file(&path);
+        assert_eq!(config.read::<String>("Name").as_deref(), Some("wxdragon"));
+        assert_eq!(config.read::<i32>("Window/Width"), Some(800));
+        assert_eq!(config.read::<bool>("Window/Maximized"), Some(true));
+        assert_eq!(config.read::<f64>("Scale"), Some(1.5));
+        assert_eq!(config.read::<i32>("Missing"), None);
+        assert_eq!(config.groups(), vec!["Window".to_string()]);
+        let mut entries = config.with_path("Window", |config| config.entries());
+        entries.sort();
+        assert_eq!(entries, vec!["Maximized", "Width"]);
+        drop(config);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -22,7 +22,7 @@ use crate::window::WxWidget;
 ///     dc.clear();
 ///     
 ///     // Draw your animation content
-///     dc.set_pen(Colour::rgb(255, 0, 0), 2, PenStyle::Solid);
+///     dc.set_pen(&Pen::new(Colour::rgb(255, 0, 0), 2, PenStyle::Solid));
 ///     dc.draw_circle(100, 100, 50);
 /// });
 /// ```
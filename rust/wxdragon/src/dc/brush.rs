@@ -0,0 +1,94 @@
+use crate::bitmap::Bitmap;
+use crate::color::Colour;
+use crate::dc::BrushStyle;
+use wxdragon_sys as ffi;
+
+/// A brush for filling shapes on a [`DeviceContext`](crate::dc::DeviceContext).
+///
+/// Brushes are cheap to clone and can be kept and reused across paint events.
+pub struct Brush {
+    ptr: *mut ffi::wxd_Brush_t,
+}
+
+impl Brush {
+    /// Creates a brush filling with a colour, or a hatch pattern in that colour
+    pub fn new(colour: Colour, style: BrushStyle) -> Self {
+        let ptr = unsafe { ffi::wxd_Brush_Create(colour.into(), style.bits() as i32) };
+        Self { ptr }
+    }
+
+    /// Creates a brush filling with a single colour
+    pub fn solid(colour: Colour) -> Self {
+        Self::new(colour, BrushStyle::Solid)
+    }
+
+    /// Creates a brush that fills nothing, to draw shapes as outlines only
+    pub fn transparent() -> Self {
+        Self::new(Colour::BLACK, BrushStyle::Transparent)
+    }
+
+    /// Creates a brush filling with `bitmap` repeated.
+    ///
+    /// Returns `None` if the bitmap is invalid.
+    pub fn from_stipple(bitmap: &Bitmap) -> Option<Self> {
+        let ptr = unsafe { ffi::wxd_Brush_CreateFromStipple(bitmap.as_ptr()) };
+        (!ptr.is_null()).then_some(Self { ptr })
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const ffi::wxd_Brush_t {
+        self.ptr
+    }
+
+    /// Sets the colour
+    pub fn set_colour(&mut self, colour: Colour) {
+        unsafe { ffi::wxd_Brush_SetColour(self.ptr, colour.into()) }
+    }
+
+    /// Gets the colour
+    pub fn get_colour(&self) -> Colour {
+        unsafe { ffi::wxd_Brush_GetColour(self.ptr) }.into()
+    }
+
+    /// Sets the style
+    pub fn set_style(&mut self, style: BrushStyle) {
+        unsafe { ffi::wxd_Brush_SetStyle(self.ptr, style.bits() as i32) }
+    }
+
+    /// Gets the style
+    pub fn get_style(&self) -> BrushStyle {
+        BrushStyle::from_bits_retain(unsafe { ffi::wxd_Brush_GetStyle(self.ptr) } as i64)
+    }
+
+    /// Fills with `bitmap` repeated. The style becomes [`BrushStyle::Stipple`],
+    /// or [`BrushStyle::StippleMaskOpaque`] if the bitmap has a mask.
+    pub fn set_stipple(&mut self, bitmap: &Bitmap) {
+        unsafe { ffi::wxd_Brush_SetStipple(self.ptr, bitmap.as_ptr()) }
+    }
+
+    /// Returns true if the style is one of the hatch patterns
+    pub fn is_hatch(&self) -> bool {
+        unsafe { ffi::wxd_Brush_IsHatch(self.ptr) }
+    }
+}
+
+impl Clone for Brush {
+    fn clone(&self) -> Self {
+        let ptr = unsafe { ffi::wxd_Brush_Clone(self.ptr) };
+        Self { ptr }
+    }
+}
+
+impl std::fmt::Debug for Brush {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Brush")
+            .field("colour", &self.get_colour())
+            .field("style", &self.get_style())
+            .finish()
+    }
+}
+
+impl Drop for Brush {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_Brush_Destroy(self.ptr) }
+    }
+}
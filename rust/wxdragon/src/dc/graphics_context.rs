@@ -0,0 +1,628 @@
+//! Anti-aliased drawing with floating point coordinates and transforms.
+//!
+//! A [`GraphicsContext`] draws on a window, memory DC or bitmap through the
+//! platform's vector graphics API (Direct2D or GDI+, Core Graphics, Cairo).
+//! Unlike a [`DeviceContext`], it honours alpha in colours, draws gradients and
+//! supports arbitrary affine transforms.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::f64::consts::PI;
+//! use wxdragon::dc::PolygonFillMode;
+//! use wxdragon::prelude::*;
+//!
+//! # fn paint(panel: &Panel) {
+//! let dc = PaintDC::new(panel);
+//! if let Some(gc) = GraphicsContext::from_dc(&dc) {
+//!     let mut path = gc.create_path();
+//!     path.move_to(10.0, 10.0)
+//!         .line_to(110.0, 10.0)
+//!         .arc(110.0, 60.0, 50.0, -PI / 2.0, PI / 2.0, true)
+//!         .close();
+//!
+//!     let stops = GradientStops::new(Colour::rgb(30, 144, 255), Colour::new(30, 144, 255, 0));
+//!     gc.set_brush(&gc.create_linear_gradient_brush(10.0, 10.0, 160.0, 110.0, &stops));
+//!     gc.set_pen(&gc.create_pen(&GraphicsPenInfo::new(Colour::BLACK).with_width(1.5)));
+//!     gc.draw_path(&path, PolygonFillMode::OddEven);
+//! }
+//! # }
+//! ```
+
+use std::ffi::CString;
+use std::marker::PhantomData;
+use wxdragon_sys as ffi;
+
+use crate::bitmap::Bitmap;
+use crate::color::Colour;
+use crate::dc::{DeviceContext, PenCap, PenJoin, PenStyle, PolygonFillMode};
+use crate::font::Font;
+
+/// A context for anti-aliased drawing on a DC or bitmap
+///
+/// The context borrows what it draws on. Drawing may be buffered until the
+/// context is dropped or [`flush`](Self::flush) is called.
+pub struct GraphicsContext<'a> {
+    ptr: *mut ffi::wxd_GraphicsContext_t,
+    _target: PhantomData<&'a ()>,
+}
+
+impl<'a> GraphicsContext<'a> {
+    /// Creates a context drawing on `dc`, which may be a [`PaintDC`](crate::dc::PaintDC),
+    /// [`ClientDC`](crate::dc::ClientDC), [`WindowDC`](crate::dc::WindowDC),
+    /// [`MemoryDC`](crate::dc::MemoryDC) or [`AutoBufferedPaintDC`](crate::dc::AutoBufferedPaintDC).
+    ///
+    /// Returns `None` for other DCs.
+    pub fn from_dc<D: DeviceContext + ?Sized>(dc: &'a D) -> Option<Self> {
+        let ptr = unsafe { ffi::wxd_GraphicsContext_CreateFromDC(dc.dc_ptr()) };
+        Self::from_ptr(ptr)
+    }
+
+    /// Creates a context drawing on `bitmap`. The drawing is stored in the
+    /// bitmap when the context is dropped.
+    ///
+    /// The bitmap must not be selected into a [`MemoryDC`](crate::dc::MemoryDC).
+    /// Returns `None` if the bitmap is invalid.
+    pub fn from_bitmap(bitmap: &'a mut Bitmap) -> Option<Self> {
+        let ptr = unsafe { ffi::wxd_GraphicsContext_CreateFromBitmap(bitmap.as_ptr()) };
+        Self::from_ptr(ptr)
+    }
+
+    fn from_ptr(ptr: *mut ffi::wxd_GraphicsContext_t) -> Option<Self> {
+        (!ptr.is_null()).then_some(Self {
+            ptr,
+            _target: PhantomData,
+        })
+    }
+
+    /// Creates a pen for stroking lines and paths
+    pub fn create_pen(&self, info: &GraphicsPenInfo) -> GraphicsPen {
+        let ptr = unsafe {
+            ffi::wxd_GraphicsContext_CreatePen(
+                self.ptr,
+                info.colour.into(),
+                info.width,
+                info.style.bits() as i32,
+                info.join.to_raw(),
+                info.cap.to_raw(),
+            )
+        };
+        GraphicsPen { ptr }
+    }
+
+    /// Creates a brush filling with a single colour
+    pub fn create_brush(&self, colour: Colour) -> GraphicsBrush {
+        let ptr = unsafe { ffi::wxd_GraphicsContext_CreateBrush(self.ptr, colour.into()) };
+        GraphicsBrush { ptr }
+    }
+
+    /// Creates a brush with a gradient along the line from (`x1`, `y1`) to (`x2`, `y2`)
+    pub fn create_linear_gradient_brush(
+        &self,
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        stops: &GradientStops,
+    ) -> GraphicsBrush {
+        let (colours, positions) = stops.to_raw();
+        let ptr = unsafe {
+            ffi::wxd_GraphicsContext_CreateLinearGradientBrush(
+                self.ptr,
+                x1,
+                y1,
+                x2,
+                y2,
+                colours.as_ptr(),
+                positions.as_ptr(),
+                colours.len() as i32,
+            )
+        };
+        GraphicsBrush { ptr }
+    }
+
+    /// Creates a brush with a gradient from the point (`start_x`, `start_y`) to
+    /// the circle around (`end_x`, `end_y`) with the given radius
+    pub fn create_radial_gradient_brush(
+        &self,
+        start_x: f64,
+        start_y: f64,
+        end_x: f64,
+        end_y: f64,
+        radius: f64,
+        stops: &GradientStops,
+    ) -> GraphicsBrush {
+        let (colours, positions) = stops.to_raw();
+        let ptr = unsafe {
+            ffi::wxd_GraphicsContext_CreateRadialGradientBrush(
+                self.ptr,
+                start_x,
+                start_y,
+                end_x,
+                end_y,
+                radius,
+                colours.as_ptr(),
+                positions.as_ptr(),
+                colours.len() as i32,
+            )
+        };
+        GraphicsBrush { ptr }
+    }
+
+    /// Creates a font for drawing text in the given colour
+    pub fn create_font(&self, font: &Font, colour: Colour) -> GraphicsFont {
+        let ptr =
+            unsafe { ffi::wxd_GraphicsContext_CreateFont(self.ptr, font.as_ptr(), colour.into()) };
+        GraphicsFont { ptr }
+    }
+
+    /// Creates an empty path
+    pub fn create_path(&self) -> GraphicsPath {
+        let ptr = unsafe { ffi::wxd_GraphicsContext_CreatePath(self.ptr) };
+        GraphicsPath { ptr }
+    }
+
+    /// Sets the pen used for stroking
+    pub fn set_pen(&self, pen: &GraphicsPen) {
+        unsafe { ffi::wxd_GraphicsContext_SetPen(self.ptr, pen.ptr) }
+    }
+
+    /// Stops stroking outlines
+    pub fn clear_pen(&self) {
+        unsafe { ffi::wxd_GraphicsContext_SetPen(self.ptr, std::ptr::null()) }
+    }
+
+    /// Sets the brush used for filling
+    pub fn set_brush(&self, brush: &GraphicsBrush) {
+        unsafe { ffi::wxd_GraphicsContext_SetBrush(self.ptr, brush.ptr) }
+    }
+
+    /// Stops filling shapes
+    pub fn clear_brush(&self) {
+        unsafe { ffi::wxd_GraphicsContext_SetBrush(self.ptr, std::ptr::null()) }
+    }
+
+    /// Sets the font used for text
+    pub fn set_font(&self, font: &GraphicsFont) {
+        unsafe { ffi::wxd_GraphicsContext_SetFont(self.ptr, font.ptr) }
+    }
+
+    /// Strokes a path with the current pen
+    pub fn stroke_path(&self, path: &GraphicsPath) {
+        unsafe { ffi::wxd_GraphicsContext_StrokePath(self.ptr, path.ptr) }
+    }
+
+    /// Fills a path with the current brush
+    pub fn fill_path(&self, path: &GraphicsPath, fill_mode: PolygonFillMode) {
+        unsafe { ffi::wxd_GraphicsContext_FillPath(self.ptr, path.ptr, fill_mode.to_raw()) }
+    }
+
+    /// Fills a path with the current brush, then strokes it with the current pen
+    pub fn draw_path(&self, path: &GraphicsPath, fill_mode: PolygonFillMode) {
+        unsafe { ffi::wxd_GraphicsContext_DrawPath(self.ptr, path.ptr, fill_mode.to_raw()) }
+    }
+
+    /// Strokes a line
+    pub fn stroke_line(&self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        unsafe { ffi::wxd_GraphicsContext_StrokeLine(self.ptr, x1, y1, x2, y2) }
+    }
+
+    /// Strokes lines connecting the points
+    pub fn stroke_lines(&self, points: &[(f64, f64)]) {
+        let points = to_raw_points(points);
+        unsafe {
+            ffi::wxd_GraphicsContext_StrokeLines(self.ptr, points.as_ptr(), points.len() as i32)
+        }
+    }
+
+    /// Fills and strokes the polygon through the points
+    pub fn draw_lines(&self, points: &[(f64, f64)], fill_mode: PolygonFillMode) {
+        let points = to_raw_points(points);
+        unsafe {
+            ffi::wxd_GraphicsContext_DrawLines(
+                self.ptr,
+                points.as_ptr(),
+                points.len() as i32,
+                fill_mode.to_raw(),
+            )
+        }
+    }
+
+    /// Draws a rectangle
+    pub fn draw_rectangle(&self, x: f64, y: f64, width: f64, height: f64) {
+        unsafe { ffi::wxd_GraphicsContext_DrawRectangle(self.ptr, x, y, width, height) }
+    }
+
+    /// Draws a rectangle with rounded corners
+    pub fn draw_rounded_rectangle(&self, x: f64, y: f64, width: f64, height: f64, radius: f64) {
+        unsafe {
+            ffi::wxd_GraphicsContext_DrawRoundedRectangle(self.ptr, x, y, width, height, radius)
+        }
+    }
+
+    /// Draws an ellipse inside the rectangle
+    pub fn draw_ellipse(&self, x: f64, y: f64, width: f64, height: f64) {
+        unsafe { ffi::wxd_GraphicsContext_DrawEllipse(self.ptr, x, y, width, height) }
+    }
+
+    /// Draws a bitmap scaled to the rectangle
+    pub fn draw_bitmap(&self, bitmap: &Bitmap, x: f64, y: f64, width: f64, height: f64) {
+        unsafe {
+            ffi::wxd_GraphicsContext_DrawBitmap(self.ptr, bitmap.as_ptr(), x, y, width, height)
+        }
+    }
+
+    /// Draws text with its top left corner at (`x`, `y`) using the current font
+    pub fn draw_text(&self, text: &str, x: f64, y: f64) {
+        self.draw_rotated_text(text, x, y, 0.0);
+    }
+
+    /// Draws text rotated counter-clockwise by `angle` radians around (`x`, `y`)
+    pub fn draw_rotated_text(&self, text: &str, x: f64, y: f64, angle: f64) {
+        if let Ok(c_text) = CString::new(text) {
+            unsafe { ffi::wxd_GraphicsContext_DrawText(self.ptr, c_text.as_ptr(), x, y, angle) }
+        }
+    }
+
+    /// Gets the width and height of text in the current font
+    pub fn get_text_extent(&self, text: &str) -> (f64, f64) {
+        let (width, height, _, _) = self.get_full_text_extent(text);
+        (width, height)
+    }
+
+    /// Gets the width, height, descent and external leading of text in the current font
+    pub fn get_full_text_extent(&self, text: &str) -> (f64, f64, f64, f64) {
+        let (mut width, mut height, mut descent, mut leading) = (0.0, 0.0, 0.0, 0.0);
+        if let Ok(c_text) = CString::new(text) {
+            unsafe {
+                ffi::wxd_GraphicsContext_GetTextExtent(
+                    self.ptr,
+                    c_text.as_ptr(),
+                    &mut width,
+                    &mut height,
+                    &mut descent,
+                    &mut leading,
+                )
+            }
+        }
+        (width, height, descent, leading)
+    }
+
+    /// Saves the transform, clipping and other state, to be restored by
+    /// [`pop_state`](Self::pop_state)
+    pub fn push_state(&self) {
+        unsafe { ffi::wxd_GraphicsContext_PushState(self.ptr) }
+    }
+
+    /// Restores the state saved by the last [`push_state`](Self::push_state)
+    pub fn pop_state(&self) {
+        unsafe { ffi::wxd_GraphicsContext_PopState(self.ptr) }
+    }
+
+    /// Moves the origin
+    pub fn translate(&self, dx: f64, dy: f64) {
+        unsafe { ffi::wxd_GraphicsContext_Translate(self.ptr, dx, dy) }
+    }
+
+    /// Rotates the coordinate system clockwise by `angle` radians
+    pub fn rotate(&self, angle: f64) {
+        unsafe { ffi::wxd_GraphicsContext_Rotate(self.ptr, angle) }
+    }
+
+    /// Scales the coordinate system
+    pub fn scale(&self, x_scale: f64, y_scale: f64) {
+        unsafe { ffi::wxd_GraphicsContext_Scale(self.ptr, x_scale, y_scale) }
+    }
+
+    /// Applies the affine transform mapping (x, y) to
+    /// (`a`·x + `c`·y + `tx`, `b`·x + `d`·y + `ty`) before the current one
+    pub fn concat_transform(&self, a: f64, b: f64, c: f64, d: f64, tx: f64, ty: f64) {
+        unsafe { ffi::wxd_GraphicsContext_ConcatTransform(self.ptr, a, b, c, d, tx, ty) }
+    }
+
+    /// Resets the transform to the identity
+    pub fn reset_transform(&self) {
+        unsafe { ffi::wxd_GraphicsContext_ResetTransform(self.ptr) }
+    }
+
+    /// Restricts drawing to the intersection of the current clipping region and the rectangle
+    pub fn clip(&self, x: f64, y: f64, width: f64, height: f64) {
+        unsafe { ffi::wxd_GraphicsContext_Clip(self.ptr, x, y, width, height) }
+    }
+
+    /// Removes the clipping region
+    pub fn reset_clip(&self) {
+        unsafe { ffi::wxd_GraphicsContext_ResetClip(self.ptr) }
+    }
+
+    /// Starts a layer whose drawing is composited with `opacity` (0.0 to 1.0)
+    /// when [`end_layer`](Self::end_layer) is called
+    pub fn begin_layer(&self, opacity: f64) {
+        unsafe { ffi::wxd_GraphicsContext_BeginLayer(self.ptr, opacity) }
+    }
+
+    /// Ends the last layer started with [`begin_layer`](Self::begin_layer)
+    pub fn end_layer(&self) {
+        unsafe { ffi::wxd_GraphicsContext_EndLayer(self.ptr) }
+    }
+
+    /// Turns anti-aliasing on (the default) or off. Returns false if not supported.
+    pub fn set_antialias(&self, enabled: bool) -> bool {
+        unsafe { ffi::wxd_GraphicsContext_SetAntialias(self.ptr, enabled) }
+    }
+
+    /// Gets the size of the drawing area
+    pub fn get_size(&self) -> (f64, f64) {
+        let (mut width, mut height) = (0.0, 0.0);
+        unsafe { ffi::wxd_GraphicsContext_GetSize(self.ptr, &mut width, &mut height) };
+        (width, height)
+    }
+
+    /// Makes sure pending drawing is done
+    pub fn flush(&self) {
+        unsafe { ffi::wxd_GraphicsContext_Flush(self.ptr) }
+    }
+}
+
+impl Drop for GraphicsContext<'_> {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_GraphicsContext_Destroy(self.ptr) }
+    }
+}
+
+fn to_raw_points(points: &[(f64, f64)]) -> Vec<ffi::wxd_Point2DDouble> {
+    points
+        .iter()
+        .map(|&(x, y)| ffi::wxd_Point2DDouble { x, y })
+        .collect()
+}
+
+/// Describes a [`GraphicsPen`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraphicsPenInfo {
+    colour: Colour,
+    width: f64,
+    style: PenStyle,
+    join: PenJoin,
+    cap: PenCap,
+}
+
+impl GraphicsPenInfo {
+    /// Creates a solid pen description of width 1 with round joins and caps
+    pub fn new(colour: Colour) -> Self {
+        Self {
+            colour,
+            width: 1.0,
+            style: PenStyle::Solid,
+            join: PenJoin::Round,
+            cap: PenCap::Round,
+        }
+    }
+
+    /// Sets the line width
+    pub fn with_width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the line style
+    pub fn with_style(mut self, style: PenStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets how line segments are joined
+    pub fn with_join(mut self, join: PenJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Sets how line ends are drawn
+    pub fn with_cap(mut self, cap: PenCap) -> Self {
+        self.cap = cap;
+        self
+    }
+}
+
+/// The colours of a gradient brush
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientStops {
+    start: Colour,
+    end: Colour,
+    stops: Vec<(Colour, f32)>,
+}
+
+impl GradientStops {
+    /// Creates a gradient from `start` to `end`
+    pub fn new(start: Colour, end: Colour) -> Self {
+        Self {
+            start,
+            end,
+            stops: Vec::new(),
+        }
+    }
+
+    /// Adds a colour at `position`, between 0.0 (the start) and 1.0 (the end)
+    pub fn with_stop(mut self, colour: Colour, position: f32) -> Self {
+        self.stops.push((colour, position.clamp(0.0, 1.0)));
+        self
+    }
+
+    // Colours and positions with the start first and the end last
+    fn to_raw(&self) -> (Vec<ffi::wxd_Colour_t>, Vec<f32>) {
+        let mut colours = vec![self.start.into()];
+        let mut positions = vec![0.0];
+        for &(colour, position) in &self.stops {
+            colours.push(colour.into());
+            positions.push(position);
+        }
+        colours.push(self.end.into());
+        positions.push(1.0);
+        (colours, positions)
+    }
+}
+
+/// A pen created by a [`GraphicsContext`]
+pub struct GraphicsPen {
+    ptr: *mut ffi::wxd_GraphicsPen_t,
+}
+
+impl Drop for GraphicsPen {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_GraphicsPen_Destroy(self.ptr) }
+    }
+}
+
+/// A brush created by a [`GraphicsContext`]
+pub struct GraphicsBrush {
+    ptr: *mut ffi::wxd_GraphicsBrush_t,
+}
+
+impl Drop for GraphicsBrush {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_GraphicsBrush_Destroy(self.ptr) }
+    }
+}
+
+/// A font and text colour created by a [`GraphicsContext`]
+pub struct GraphicsFont {
+    ptr: *mut ffi::wxd_GraphicsFont_t,
+}
+
+impl Drop for GraphicsFont {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_GraphicsFont_Destroy(self.ptr) }
+    }
+}
+
+/// A path of lines and curves created by a [`GraphicsContext`]
+///
+/// Angles are in radians, measured clockwise from the positive x axis.
+pub struct GraphicsPath {
+    ptr: *mut ffi::wxd_GraphicsPath_t,
+}
+
+impl GraphicsPath {
+    /// Starts a new subpath at (`x`, `y`)
+    pub fn move_to(&mut self, x: f64, y: f64) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_MoveToPoint(self.ptr, x, y) };
+        self
+    }
+
+    /// Adds a line from the current point to (`x`, `y`)
+    pub fn line_to(&mut self, x: f64, y: f64) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_AddLineToPoint(self.ptr, x, y) };
+        self
+    }
+
+    /// Adds a cubic Bézier curve from the current point to (`x`, `y`)
+    pub fn curve_to(
+        &mut self,
+        cx1: f64,
+        cy1: f64,
+        cx2: f64,
+        cy2: f64,
+        x: f64,
+        y: f64,
+    ) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_AddCurveToPoint(self.ptr, cx1, cy1, cx2, cy2, x, y) };
+        self
+    }
+
+    /// Adds a quadratic Bézier curve from the current point to (`x`, `y`)
+    pub fn quad_curve_to(&mut self, cx: f64, cy: f64, x: f64, y: f64) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_AddQuadCurveToPoint(self.ptr, cx, cy, x, y) };
+        self
+    }
+
+    /// Adds an arc of the circle around (`x`, `y`), with a line to its start
+    /// if there is a current point
+    pub fn arc(
+        &mut self,
+        x: f64,
+        y: f64,
+        radius: f64,
+        start_angle: f64,
+        end_angle: f64,
+        clockwise: bool,
+    ) -> &mut Self {
+        unsafe {
+            ffi::wxd_GraphicsPath_AddArc(self.ptr, x, y, radius, start_angle, end_angle, clockwise)
+        };
+        self
+    }
+
+    /// Adds an arc of the given radius tangent to the line from the current
+    /// point to (`x1`, `y1`) and the line from there to (`x2`, `y2`)
+    pub fn arc_to(&mut self, x1: f64, y1: f64, x2: f64, y2: f64, radius: f64) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_AddArcToPoint(self.ptr, x1, y1, x2, y2, radius) };
+        self
+    }
+
+    /// Adds a rectangle as a closed subpath
+    pub fn add_rectangle(&mut self, x: f64, y: f64, width: f64, height: f64) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_AddRectangle(self.ptr, x, y, width, height) };
+        self
+    }
+
+    /// Adds a rectangle with rounded corners as a closed subpath
+    pub fn add_rounded_rectangle(
+        &mut self,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        radius: f64,
+    ) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_AddRoundedRectangle(self.ptr, x, y, width, height, radius) };
+        self
+    }
+
+    /// Adds a circle as a closed subpath
+    pub fn add_circle(&mut self, x: f64, y: f64, radius: f64) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_AddCircle(self.ptr, x, y, radius) };
+        self
+    }
+
+    /// Adds the ellipse inside the rectangle as a closed subpath
+    pub fn add_ellipse(&mut self, x: f64, y: f64, width: f64, height: f64) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_AddEllipse(self.ptr, x, y, width, height) };
+        self
+    }
+
+    /// Adds the subpaths of another path
+    pub fn add_path(&mut self, other: &GraphicsPath) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_AddPath(self.ptr, other.ptr) };
+        self
+    }
+
+    /// Closes the current subpath with a line to its start
+    pub fn close(&mut self) -> &mut Self {
+        unsafe { ffi::wxd_GraphicsPath_CloseSubpath(self.ptr) };
+        self
+    }
+
+    /// Gets the current point
+    pub fn get_current_point(&self) -> (f64, f64) {
+        let point = unsafe { ffi::wxd_GraphicsPath_GetCurrentPoint(self.ptr) };
+        (point.x, point.y)
+    }
+
+    /// Gets the bounding box as (x, y, width, height)
+    pub fn get_box(&self) -> (f64, f64, f64, f64) {
+        let (mut x, mut y, mut width, mut height) = (0.0, 0.0, 0.0, 0.0);
+        unsafe { ffi::wxd_GraphicsPath_GetBox(self.ptr, &mut x, &mut y, &mut width, &mut height) };
+        (x, y, width, height)
+    }
+
+    /// Returns true if (`x`, `y`) lies inside the path
+    pub fn contains(&self, x: f64, y: f64, fill_mode: PolygonFillMode) -> bool {
+        unsafe { ffi::wxd_GraphicsPath_Contains(self.ptr, x, y, fill_mode.to_raw()) }
+    }
+}
+
+impl Drop for GraphicsPath {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_GraphicsPath_Destroy(self.ptr) }
+    }
+}
@@ -77,6 +77,70 @@ impl PolygonFillMode {
     }
 }
 
+/// How the segments of a thick line are joined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PenJoin {
+    /// Bevelled corners
+    Bevel,
+    /// Sharp, mitred corners
+    Miter,
+    /// Rounded corners
+    #[default]
+    Round,
+}
+
+impl PenJoin {
+    /// Convert to the raw FFI value
+    pub fn to_raw(&self) -> wxdragon_sys::wxd_PenJoin {
+        match self {
+            PenJoin::Bevel => wxdragon_sys::wxd_PenJoin_WXD_JOIN_BEVEL,
+            PenJoin::Miter => wxdragon_sys::wxd_PenJoin_WXD_JOIN_MITER,
+            PenJoin::Round => wxdragon_sys::wxd_PenJoin_WXD_JOIN_ROUND,
+        }
+    }
+
+    /// Convert from the raw FFI value
+    pub fn from_raw(value: wxdragon_sys::wxd_PenJoin) -> Self {
+        match value {
+            wxdragon_sys::wxd_PenJoin_WXD_JOIN_BEVEL => PenJoin::Bevel,
+            wxdragon_sys::wxd_PenJoin_WXD_JOIN_MITER => PenJoin::Miter,
+            _ => PenJoin::Round,
+        }
+    }
+}
+
+/// How the ends of a thick line are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PenCap {
+    /// Rounded ends
+    #[default]
+    Round,
+    /// Square ends extending half the line width past the end points
+    Projecting,
+    /// Square ends at the end points
+    Butt,
+}
+
+impl PenCap {
+    /// Convert to the raw FFI value
+    pub fn to_raw(&self) -> wxdragon_sys::wxd_PenCap {
+        match self {
+            PenCap::Round => wxdragon_sys::wxd_PenCap_WXD_CAP_ROUND,
+            PenCap::Projecting => wxdragon_sys::wxd_PenCap_WXD_CAP_PROJECTING,
+            PenCap::Butt => wxdragon_sys::wxd_PenCap_WXD_CAP_BUTT,
+        }
+    }
+
+    /// Convert from the raw FFI value
+    pub fn from_raw(value: wxdragon_sys::wxd_PenCap) -> Self {
+        match value {
+            wxdragon_sys::wxd_PenCap_WXD_CAP_PROJECTING => PenCap::Projecting,
+            wxdragon_sys::wxd_PenCap_WXD_CAP_BUTT => PenCap::Butt,
+            _ => PenCap::Round,
+        }
+    }
+}
+
 /// Flood fill modes
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FloodFillMode {
@@ -347,17 +411,28 @@ impl From<wxdragon_sys::wxd_Rect> for Rect {
 }
 
 pub mod auto_buffered_paint_dc;
+pub mod brush;
 pub mod client_dc;
+pub mod graphics_context;
 pub mod memory_dc;
 pub mod paint_dc;
+pub mod pen;
 pub mod screen_dc;
+pub mod svg_file_dc;
 pub mod window_dc;
 
 pub use auto_buffered_paint_dc::AutoBufferedPaintDC;
+pub use brush::Brush;
 pub use client_dc::ClientDC;
+pub use graphics_context::{
+    GradientStops, GraphicsBrush, GraphicsContext, GraphicsFont, GraphicsPath, GraphicsPen,
+    GraphicsPenInfo,
+};
 pub use memory_dc::MemoryDC;
 pub use paint_dc::PaintDC;
+pub use pen::{Pen, PenBuilder};
 pub use screen_dc::ScreenDC;
+pub use svg_file_dc::{SvgFileDC, SvgFileDCBuilder};
 pub use window_dc::WindowDC;
 
 // Re-export for convenience
@@ -487,17 +562,19 @@ pub trait DeviceContext {
         }
     }
 
-    /// Set the pen for drawing outlines
-    fn set_pen(&self, colour: Colour, width: i32, style: PenStyle) {
+    /// Set the pen for drawing lines and outlines
+    ///
+    /// The DC keeps its own reference to the pen, which can be dropped or reused.
+    fn set_pen(&self, pen: &Pen) {
         unsafe {
-            wxdragon_sys::wxd_DC_SetPen(self.dc_ptr(), colour.into(), width, style.bits() as i32);
+            wxdragon_sys::wxd_DC_SetPen(self.dc_ptr(), pen.as_ptr());
         }
     }
 
     /// Set the brush for filling shapes
-    fn set_brush(&self, colour: Colour, style: BrushStyle) {
+    fn set_brush(&self, brush: &Brush) {
         unsafe {
-            wxdragon_sys::wxd_DC_SetBrush(self.dc_ptr(), colour.into(), style.bits() as i32);
+            wxdragon_sys::wxd_DC_SetBrush(self.dc_ptr(), brush.as_ptr());
         }
     }
 
@@ -0,0 +1,219 @@
+use crate::bitmap::Bitmap;
+use crate::color::Colour;
+use crate::dc::{PenCap, PenJoin, PenStyle};
+use wxdragon_sys as ffi;
+
+/// A pen for drawing lines and outlines on a [`DeviceContext`](crate::dc::DeviceContext).
+///
+/// Pens are cheap to clone and can be kept and reused across paint events.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use wxdragon::dc::{Pen, PenCap, PenJoin};
+/// use wxdragon::prelude::*;
+///
+/// # fn paint(dc: &PaintDC) {
+/// let connector = Pen::builder(Colour::rgb(80, 80, 80))
+///     .with_width(2)
+///     .with_join(PenJoin::Round)
+///     .with_cap(PenCap::Butt)
+///     .with_dashes(&[4, 2])
+///     .build();
+///
+/// dc.set_pen(&connector);
+/// dc.draw_line(10, 10, 200, 10);
+/// # }
+/// ```
+pub struct Pen {
+    ptr: *mut ffi::wxd_Pen_t,
+}
+
+impl Pen {
+    /// Creates a pen with round joins and caps
+    pub fn new(colour: Colour, width: i32, style: PenStyle) -> Self {
+        let ptr = unsafe { ffi::wxd_Pen_Create(colour.into(), width, style.bits() as i32) };
+        Self { ptr }
+    }
+
+    /// Creates a pen that draws nothing, to draw shapes without outlines
+    pub fn transparent() -> Self {
+        Self::new(Colour::BLACK, 1, PenStyle::Transparent)
+    }
+
+    /// Creates a builder for a pen of the given colour
+    pub fn builder(colour: Colour) -> PenBuilder {
+        PenBuilder {
+            colour,
+            width: 1,
+            style: PenStyle::Solid,
+            join: PenJoin::Round,
+            cap: PenCap::Round,
+            dashes: Vec::new(),
+            stipple: None,
+        }
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const ffi::wxd_Pen_t {
+        self.ptr
+    }
+
+    /// Sets the colour
+    pub fn set_colour(&mut self, colour: Colour) {
+        unsafe { ffi::wxd_Pen_SetColour(self.ptr, colour.into()) }
+    }
+
+    /// Gets the colour
+    pub fn get_colour(&self) -> Colour {
+        unsafe { ffi::wxd_Pen_GetColour(self.ptr) }.into()
+    }
+
+    /// Sets the width in pixels. A width of 0 draws the thinnest possible line.
+    pub fn set_width(&mut self, width: i32) {
+        unsafe { ffi::wxd_Pen_SetWidth(self.ptr, width) }
+    }
+
+    /// Gets the width in pixels
+    pub fn get_width(&self) -> i32 {
+        unsafe { ffi::wxd_Pen_GetWidth(self.ptr) }
+    }
+
+    /// Sets the style
+    pub fn set_style(&mut self, style: PenStyle) {
+        unsafe { ffi::wxd_Pen_SetStyle(self.ptr, style.bits() as i32) }
+    }
+
+    /// Gets the style
+    pub fn get_style(&self) -> PenStyle {
+        PenStyle::from_bits_retain(unsafe { ffi::wxd_Pen_GetStyle(self.ptr) } as i64)
+    }
+
+    /// Sets how the segments of lines are joined
+    pub fn set_join(&mut self, join: PenJoin) {
+        unsafe { ffi::wxd_Pen_SetJoin(self.ptr, join.to_raw()) }
+    }
+
+    /// Gets how the segments of lines are joined
+    pub fn get_join(&self) -> PenJoin {
+        PenJoin::from_raw(unsafe { ffi::wxd_Pen_GetJoin(self.ptr) })
+    }
+
+    /// Sets how the ends of lines are drawn
+    pub fn set_cap(&mut self, cap: PenCap) {
+        unsafe { ffi::wxd_Pen_SetCap(self.ptr, cap.to_raw()) }
+    }
+
+    /// Gets how the ends of lines are drawn
+    pub fn get_cap(&self) -> PenCap {
+        PenCap::from_raw(unsafe { ffi::wxd_Pen_GetCap(self.ptr) })
+    }
+
+    /// Sets a dash pattern of alternating dash and gap lengths, in multiples
+    /// of the pen width, and the style to [`PenStyle::UserDash`].
+    ///
+    /// An empty pattern removes the dashes and turns a
+    /// [`PenStyle::UserDash`] pen into a [`PenStyle::Solid`] one.
+    pub fn set_dashes(&mut self, dashes: &[i32]) {
+        unsafe { ffi::wxd_Pen_SetDashes(self.ptr, dashes.as_ptr(), dashes.len() as i32) }
+    }
+
+    /// Gets the dash pattern set with [`set_dashes`](Self::set_dashes)
+    pub fn get_dashes(&self) -> Vec<i32> {
+        let count = unsafe { ffi::wxd_Pen_GetDashes(self.ptr, std::ptr::null_mut(), 0) };
+        let mut dashes = vec![0; count.max(0) as usize];
+        unsafe { ffi::wxd_Pen_GetDashes(self.ptr, dashes.as_mut_ptr(), count) };
+        dashes
+    }
+
+    /// Draws lines with a bitmap pattern and sets the style to
+    /// [`PenStyle::Stipple`]. Not supported on every platform.
+    pub fn set_stipple(&mut self, bitmap: &Bitmap) {
+        unsafe { ffi::wxd_Pen_SetStipple(self.ptr, bitmap.as_ptr()) }
+    }
+}
+
+impl Clone for Pen {
+    fn clone(&self) -> Self {
+        let ptr = unsafe { ffi::wxd_Pen_Clone(self.ptr) };
+        Self { ptr }
+    }
+}
+
+impl std::fmt::Debug for Pen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pen")
+            .field("colour", &self.get_colour())
+            .field("width", &self.get_width())
+            .field("style", &self.get_style())
+            .finish()
+    }
+}
+
+impl Drop for Pen {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_Pen_Destroy(self.ptr) }
+    }
+}
+
+/// Builder for [`Pen`]
+pub struct PenBuilder {
+    colour: Colour,
+    width: i32,
+    style: PenStyle,
+    join: PenJoin,
+    cap: PenCap,
+    dashes: Vec<i32>,
+    stipple: Option<Bitmap>,
+}
+
+impl PenBuilder {
+    /// Sets the width in pixels (1 by default)
+    pub fn with_width(mut self, width: i32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the style ([`PenStyle::Solid`] by default)
+    pub fn with_style(mut self, style: PenStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets how the segments of lines are joined
+    pub fn with_join(mut self, join: PenJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Sets how the ends of lines are drawn
+    pub fn with_cap(mut self, cap: PenCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Sets a dash pattern, see [`Pen::set_dashes`]
+    pub fn with_dashes(mut self, dashes: &[i32]) -> Self {
+        self.dashes = dashes.to_vec();
+        self
+    }
+
+    /// Sets a bitmap pattern, see [`Pen::set_stipple`]
+    pub fn with_stipple(mut self, bitmap: &Bitmap) -> Self {
+        self.stipple = Some(bitmap.clone());
+        self
+    }
+
+    /// Creates the pen
+    pub fn build(self) -> Pen {
+        let mut pen = Pen::new(self.colour, self.width, self.style);
+        pen.set_join(self.join);
+        pen.set_cap(self.cap);
+        if !self.dashes.is_empty() {
+            pen.set_dashes(&self.dashes);
+        }
+        if let Some(bitmap) = &self.stipple {
+            pen.set_stipple(bitmap);
+        }
+        pen
+    }
+}
@@ -0,0 +1,139 @@
+//! Drawing to SVG files.
+//!
+//! [`SvgFileDC`] records everything drawn on it as SVG elements, so the same
+//! [`DeviceContext`] code that paints a window can export a vector image.
+
+use crate::dc::DeviceContext;
+use std::ffi::CString;
+use std::path::{Path, PathBuf};
+
+/// A device context that writes drawing to an SVG file.
+///
+/// The file is complete once the DC is dropped. Drawing code written against
+/// [`DeviceContext`] can target the screen or a file:
+///
+/// ```rust,no_run
+/// use wxdragon::dc::SvgFileDC;
+/// use wxdragon::prelude::*;
+///
+/// fn draw_diagram(dc: &impl DeviceContext) {
+///     dc.set_pen(&Pen::new(Colour::BLACK, 2, PenStyle::Solid));
+///     dc.draw_rectangle(10, 10, 200, 100);
+/// }
+///
+/// if let Some(dc) = SvgFileDC::builder("diagram.svg", 400, 300)
+///     .with_title("Diagram")
+///     .build()
+/// {
+///     draw_diagram(&dc);
+/// }
+/// ```
+pub struct SvgFileDC {
+    dc_ptr: *mut wxdragon_sys::wxd_SVGFileDC_t,
+}
+
+impl SvgFileDC {
+    /// Create an SVG device context of the given size in pixels at 72 DPI.
+    ///
+    /// Returns `None` if the file can't be created.
+    pub fn new<P: AsRef<Path>>(path: P, width: i32, height: i32) -> Option<Self> {
+        Self::builder(path, width, height).build()
+    }
+
+    /// Create a builder for an SVG device context of the given size in pixels
+    pub fn builder<P: AsRef<Path>>(path: P, width: i32, height: i32) -> SvgFileDCBuilder {
+        SvgFileDCBuilder {
+            path: path.as_ref().to_path_buf(),
+            width,
+            height,
+            dpi: 72.0,
+            title: String::new(),
+        }
+    }
+}
+
+impl DeviceContext for SvgFileDC {
+    fn dc_ptr(&self) -> *mut wxdragon_sys::wxd_DC_t {
+        unsafe { wxdragon_sys::wxd_SVGFileDC_AsDC(self.dc_ptr) }
+    }
+}
+
+impl Drop for SvgFileDC {
+    fn drop(&mut self) {
+        unsafe {
+            wxdragon_sys::wxd_SVGFileDC_Destroy(self.dc_ptr);
+        }
+    }
+}
+
+/// Builder for [`SvgFileDC`]
+pub struct SvgFileDCBuilder {
+    path: PathBuf,
+    width: i32,
+    height: i32,
+    dpi: f64,
+    title: String,
+}
+
+impl SvgFileDCBuilder {
+    /// Set the resolution, which determines the physical size of the image (72 by default)
+    pub fn with_dpi(mut self, dpi: f64) -> Self {
+        self.dpi = dpi;
+        self
+    }
+
+    /// Set the title stored in the file
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Create the device context, or `None` if the file can't be created
+    pub fn build(self) -> Option<SvgFileDC> {
+        let path = CString::new(self.path.to_string_lossy().as_bytes()).ok()?;
+        let title = CString::new(self.title).ok()?;
+        let dc_ptr = unsafe {
+            wxdragon_sys::wxd_SVGFileDC_Create(
+                path.as_ptr(),
+                self.width,
+                self.height,
+                self.dpi,
+                title.as_ptr(),
+            )
+        };
+        (!dc_ptr.is_null()).then_some(SvgFileDC { dc_ptr })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SvgFileDC;
+    use crate::color::Colour;
+    use crate::dc::{Brush, DeviceContext, Pen, PenStyle};
+
+    #[test]
+    fn writes_drawn_primitives() {
+        let path = std::env::temp_dir().join(format!("wxdragon-svg-{}.svg", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let dc = SvgFileDC::builder(&path, 200, 100)
+            .with_title("Primitives")
+            .build()
+            .expect("failed to create the SVG file");
+        dc.set_pen(&Pen::new(Colour::BLACK, 2, PenStyle::Solid));
+        dc.set_brush(&Brush::solid(Colour::RED));
+        dc.draw_rectangle(10, 10, 80, 40);
+        dc.draw_circle(150, 50, 20);
+        dc.draw_line(0, 90, 200, 90);
+        drop(dc);
+
+        let svg = std::fs::read_to_string(&path).expect("SVG file wasn't written");
+        let _ = std::fs::remove_file(&path);
+        assert!(svg.contains("<svg"));
+        assert!(svg.contains("<title>Primitives</title>"));
+        assert!(svg.contains("<rect"));
+        assert!(svg.contains("<ellipse"));
+        assert!(svg.contains("<path"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
@@ -107,6 +107,8 @@ impl Dialog {
 // Apply common trait implementations for Dialog
 implement_widget_traits_with_target!(Dialog, window, Window);
 
+impl crate::event::CharHookEvents for Dialog {}
+
 // XRC Support - enables Dialog to be created from XRC-managed pointers
 impl_xrc_support!(Dialog, {
     window,
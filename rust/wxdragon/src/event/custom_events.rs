@@ -0,0 +1,127 @@
+//! User-defined event types carrying a Rust payload.
+//!
+//! Custom event types are registered by name with [`EventType::custom`] and are
+//! bound like any other event. Events can be queued from the GUI thread with
+//! [`queue_event`](crate::event::WxEvtHandler::queue_event), or from any thread
+//! through an [`EventSender`]. Delivery always happens on the GUI thread.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! struct DownloadDone {
+//!     bytes: usize,
+//! }
+//!
+//! let frame = Frame::builder().title("Downloads").build();
+//! let download_done = EventType::custom("DOWNLOAD_DONE");
+//!
+//! frame.on_custom_event(download_done, |event| {
+//!     if let Some(done) = event.get_payload::<DownloadDone>() {
+//!         println!("Downloaded {} bytes", done.bytes);
+//!     }
+//! });
+//!
+//! let sender = frame.event_sender();
+//! std::thread::spawn(move || {
+//!     // ... long running work ...
+//!     sender.queue_event(download_done, DownloadDone { bytes: 1024 });
+//! });
+//! ```
+
+use crate::event::EventType;
+use std::any::Any;
+use std::ffi::{c_void, CString};
+use wxdragon_sys as ffi;
+
+/// The boxed form a payload takes while it is owned by the C++ side.
+pub(crate) type EventPayload = Box<dyn Any + Send>;
+
+impl EventType {
+    /// Registers a user-defined event type, or returns the existing one if `name`
+    /// has already been registered.
+    ///
+    /// The same name always yields the same `EventType` for the lifetime of the
+    /// process, so this can be called wherever the type is needed. It is safe to
+    /// call from any thread.
+    ///
+    /// # Panics
+    /// Panics if `name` contains a NUL byte.
+    pub fn custom(name: &str) -> EventType {
+        let c_name = match CString::new(name) {
+            Ok(c_name) => c_name,
+            Err(_) => panic!("custom event type name {name:?} contains a NUL byte"),
+        };
+        let raw = unsafe { ffi::wxd_Event_RegisterCustomType(c_name.as_ptr()) };
+        EventType::from_bits_retain(raw)
+    }
+
+    /// Returns true if this is a user-defined event type created with [`EventType::custom`].
+    pub fn is_custom(&self) -> bool {
+        self.bits() >= ffi::WXD_EVENT_TYPE_CUSTOM_BASE as ffi::WXDEventTypeCEnum
+    }
+}
+
+/// A handle for queuing custom events to an event handler from any thread.
+///
+/// Obtained from [`event_sender`](crate::event::WxEvtHandler::event_sender). The sender
+/// can be copied and moved to worker threads. Events are delivered on the GUI thread;
+/// if the target has been destroyed by then, the event and its payload are dropped.
+#[derive(Debug, Clone, Copy)]
+pub struct EventSender {
+    handler_ptr: *mut ffi::wxd_EvtHandler_t,
+    generation: u64,
+}
+
+// The pointer is never dereferenced off the GUI thread: the C++ side only compares
+// it and the generation against the live handlers once the event has reached the GUI thread.
+unsafe impl Send for EventSender {}
+unsafe impl Sync for EventSender {}
+
+impl EventSender {
+    /// Must be called on the GUI thread.
+    pub(crate) fn new(handler_ptr: *mut ffi::wxd_EvtHandler_t) -> Self {
+        let generation = if handler_ptr.is_null() {
+            0
+        } else {
+            unsafe { ffi::wxd_EvtHandler_GetGeneration(handler_ptr) }
+        };
+        Self {
+            handler_ptr,
+            generation,
+        }
+    }
+
+    /// Queues a custom event with the given payload for delivery on the GUI thread.
+    ///
+    /// Returns false if the event could not be queued, for example when the
+    /// application is not running or `event_type` is not a custom event type.
+    pub fn queue_event<T: Any + Send>(&self, event_type: EventType, payload: T) -> bool {
+        if self.handler_ptr.is_null() || self.generation == 0 || !event_type.is_custom() {
+            return false;
+        }
+        let boxed: EventPayload = Box::new(payload);
+        let payload_ptr = Box::into_raw(Box::new(boxed)) as *mut c_void;
+        unsafe {
+            ffi::wxd_EvtHandler_QueueCustomEvent(
+                self.handler_ptr,
+                self.generation,
+                event_type.bits(),
+                payload_ptr,
+            )
+        }
+    }
+}
+
+/// Function called by C++ to drop the payload of a custom event.
+///
+/// # Safety
+/// `ptr` must be a pointer obtained from `Box::into_raw(Box<Box<dyn Any + Send>>)`
+/// that has not been dropped yet. It must not be used after this function returns.
+#[no_mangle]
+pub unsafe extern "C" fn drop_rust_event_payload(ptr: *mut c_void) {
+    if !ptr.is_null() {
+        let _ = Box::from_raw(ptr as *mut EventPayload);
+    }
+}
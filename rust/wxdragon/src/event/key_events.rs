@@ -0,0 +1,393 @@
+//! Typed keyboard events and key codes.
+//!
+//! Key down/up events and `wxEVT_CHAR_HOOK` report keys as [`KeyCode`]s.
+//! Char hook events are sent to the top-level window before the focused control
+//! sees the key, which makes them the place for window-wide shortcuts.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! let frame = Frame::builder().with_title("Editor").build();
+//! let dialog = Dialog::builder(&frame, "Find").build();
+//!
+//! let dialog_for_keys = dialog.clone();
+//! dialog.on_char_hook(move |event| {
+//!     if event.key_code() == KeyCode::Escape && event.modifiers().is_empty() {
+//!         dialog_for_keys.end_modal(ID_CANCEL);
+//!     } else {
+//!         event.skip(true);
+//!     }
+//! });
+//! ```
+
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
+use wxdragon_sys as ffi;
+
+// WXK_RAW_CONTROL only differs from WXK_CONTROL on macOS
+#[cfg(target_os = "macos")]
+const WXK_RAW_CONTROL: i32 = 396;
+#[cfg(not(target_os = "macos"))]
+const WXK_RAW_CONTROL: i32 = 308;
+
+macro_rules! key_codes {
+    ($($(#[$meta:meta])* $variant:ident = $value:expr,)*) => {
+        /// Key codes, matching wxWidgets' `wxKeyCode` (`WXK_*`) values
+        ///
+        /// Printable keys are reported as [`KeyCode::Char`]. For key down and up
+        /// events, letters are always reported in upper case.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum KeyCode {
+            $($(#[$meta])* $variant,)*
+            /// A printable Latin-1 character
+            Char(char),
+            /// Any other key code
+            Other(i32),
+        }
+
+        impl KeyCode {
+            /// Converts a raw wxWidgets key code
+            pub fn from_raw(code: i32) -> Self {
+                $(
+                    if code == $value {
+                        return KeyCode::$variant;
+                    }
+                )*
+                match code {
+                    33..=126 | 160..=255 => KeyCode::Char(char::from(code as u8)),
+                    _ => KeyCode::Other(code),
+                }
+            }
+
+            /// Gets the raw wxWidgets key code
+            pub fn to_raw(self) -> i32 {
+                match self {
+                    $(KeyCode::$variant => $value,)*
+                    KeyCode::Char(c) => c as i32,
+                    KeyCode::Other(code) => code,
+                }
+            }
+        }
+    };
+}
+
+key_codes! {
+    None = 0,
+    Back = 8,
+    Tab = 9,
+    Return = 13,
+    Escape = 27,
+    Space = 32,
+    Delete = 127,
+    Start = 300,
+    LButton = 301,
+    RButton = 302,
+    Cancel = 303,
+    MButton = 304,
+    Clear = 305,
+    Shift = 306,
+    Alt = 307,
+    /// Control on Windows and Linux, Cmd on macOS
+    Control = 308,
+    Menu = 309,
+    Pause = 310,
+    Capital = 311,
+    End = 312,
+    Home = 313,
+    Left = 314,
+    Up = 315,
+    Right = 316,
+    Down = 317,
+    Select = 318,
+    Print = 319,
+    Execute = 320,
+    Snapshot = 321,
+    Insert = 322,
+    Help = 323,
+    Numpad0 = 324,
+    Numpad1 = 325,
+    Numpad2 = 326,
+    Numpad3 = 327,
+    Numpad4 = 328,
+    Numpad5 = 329,
+    Numpad6 = 330,
+    Numpad7 = 331,
+    Numpad8 = 332,
+    Numpad9 = 333,
+    Multiply = 334,
+    Add = 335,
+    Separator = 336,
+    Subtract = 337,
+    Decimal = 338,
+    Divide = 339,
+    F1 = 340,
+    F2 = 341,
+    F3 = 342,
+    F4 = 343,
+    F5 = 344,
+    F6 = 345,
+    F7 = 346,
+    F8 = 347,
+    F9 = 348,
+    F10 = 349,
+    F11 = 350,
+    F12 = 351,
+    F13 = 352,
+    F14 = 353,
+    F15 = 354,
+    F16 = 355,
+    F17 = 356,
+    F18 = 357,
+    F19 = 358,
+    F20 = 359,
+    F21 = 360,
+    F22 = 361,
+    F23 = 362,
+    F24 = 363,
+    NumLock = 364,
+    Scroll = 365,
+    PageUp = 366,
+    PageDown = 367,
+    NumpadSpace = 368,
+    NumpadTab = 369,
+    NumpadEnter = 370,
+    NumpadF1 = 371,
+    NumpadF2 = 372,
+    NumpadF3 = 373,
+    NumpadF4 = 374,
+    NumpadHome = 375,
+    NumpadLeft = 376,
+    NumpadUp = 377,
+    NumpadRight = 378,
+    NumpadDown = 379,
+    NumpadPageUp = 380,
+    NumpadPageDown = 381,
+    NumpadEnd = 382,
+    NumpadBegin = 383,
+    NumpadInsert = 384,
+    NumpadDelete = 385,
+    NumpadEqual = 386,
+    NumpadMultiply = 387,
+    NumpadAdd = 388,
+    NumpadSeparator = 389,
+    NumpadSubtract = 390,
+    NumpadDecimal = 391,
+    NumpadDivide = 392,
+    WindowsLeft = 393,
+    WindowsRight = 394,
+    WindowsMenu = 395,
+    /// The physical Control key on macOS; the same as `Control` elsewhere
+    RawControl = WXK_RAW_CONTROL,
+    Special1 = 397,
+    Special2 = 398,
+    Special3 = 399,
+    Special4 = 400,
+    Special5 = 401,
+    Special6 = 402,
+    Special7 = 403,
+    Special8 = 404,
+    Special9 = 405,
+    Special10 = 406,
+    Special11 = 407,
+    Special12 = 408,
+    Special13 = 409,
+    Special14 = 410,
+    Special15 = 411,
+    Special16 = 412,
+    Special17 = 413,
+    Special18 = 414,
+    Special19 = 415,
+    Special20 = 416,
+    BrowserBack = 417,
+    BrowserForward = 418,
+    BrowserRefresh = 419,
+    BrowserStop = 420,
+    BrowserSearch = 421,
+    BrowserFavorites = 422,
+    BrowserHome = 423,
+    VolumeMute = 424,
+    VolumeDown = 425,
+    VolumeUp = 426,
+    MediaNextTrack = 427,
+    MediaPrevTrack = 428,
+    MediaStop = 429,
+    MediaPlayPause = 430,
+    LaunchMail = 431,
+    LaunchApp1 = 432,
+    LaunchApp2 = 433,
+}
+
+impl KeyCode {
+    /// Cmd on macOS and Control elsewhere, for portable shortcuts
+    pub const COMMAND: KeyCode = KeyCode::Control;
+}
+
+bitflags::bitflags! {
+    /// Modifier keys held during a keyboard or mouse event (`wxMOD_*`)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct KeyModifiers: i32 {
+        const ALT = 0x0001;
+        /// Control on Windows and Linux, Cmd on macOS
+        const CONTROL = 0x0002;
+        const SHIFT = 0x0004;
+        /// Meta on Linux, the Windows key on Windows
+        const META = 0x0008;
+        /// The physical Control key on macOS; unused elsewhere
+        const RAW_CONTROL = 0x0010;
+        /// Cmd on macOS and Control elsewhere
+        const CMD = Self::CONTROL.bits();
+    }
+}
+
+bitflags::bitflags! {
+    /// Key categories for [`KeyEvent::is_key_in_category`] (`WXK_CATEGORY_*`)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct KeyCategory: i32 {
+        /// The arrow keys, on the main keyboard and the numpad
+        const ARROW = 1;
+        /// Page up and page down
+        const PAGING = 2;
+        /// Home and end
+        const JUMP = 4;
+        /// Tab
+        const TAB = 8;
+        /// Backspace and delete
+        const CUT = 16;
+        /// Any key that moves the caret
+        const NAVIGATION = Self::ARROW.bits() | Self::PAGING.bits() | Self::JUMP.bits();
+    }
+}
+
+/// Data for keyboard events
+#[derive(Debug)]
+pub struct KeyEvent {
+    pub event: Event,
+}
+
+impl KeyEvent {
+    pub fn new(event: Event) -> Self {
+        KeyEvent { event }
+    }
+
+    /// Gets the key that generated the event
+    pub fn key_code(&self) -> KeyCode {
+        if self.event.is_null() {
+            return KeyCode::None;
+        }
+        KeyCode::from_raw(unsafe { ffi::wxd_KeyEvent_GetKeyCode(self.event._as_ptr()) })
+    }
+
+    /// Gets the character for the key, or `None` for keys that don't produce one
+    /// (function keys, arrows and the like)
+    pub fn unicode_key(&self) -> Option<char> {
+        self.event
+            .get_unicode_key()
+            .and_then(|code| char::from_u32(code as u32))
+    }
+
+    /// Gets the platform-specific key code, such as the virtual key code on Windows
+    pub fn raw_key_code(&self) -> u32 {
+        if self.event.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_KeyEvent_GetRawKeyCode(self.event._as_ptr()) }
+    }
+
+    /// Gets the platform-specific key flags
+    pub fn raw_key_flags(&self) -> u32 {
+        if self.event.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_KeyEvent_GetRawKeyFlags(self.event._as_ptr()) }
+    }
+
+    /// Gets the modifier keys held down during this event
+    pub fn modifiers(&self) -> KeyModifiers {
+        if self.event.is_null() {
+            return KeyModifiers::empty();
+        }
+        KeyModifiers::from_bits_truncate(unsafe {
+            ffi::wxd_KeyEvent_GetModifiers(self.event._as_ptr())
+        })
+    }
+
+    /// Returns true if the key belongs to any of the given categories
+    pub fn is_key_in_category(&self, category: KeyCategory) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_KeyEvent_IsKeyInCategory(self.event._as_ptr(), category.bits()) }
+    }
+
+    /// Gets the raw key code, or `None` if there is none.
+    ///
+    /// Prefer [`key_code`](Self::key_code).
+    pub fn get_key_code(&self) -> Option<i32> {
+        self.event.get_key_code()
+    }
+
+    /// Gets the raw unicode value, or `None` if there is none.
+    ///
+    /// Prefer [`unicode_key`](Self::unicode_key).
+    pub fn get_unicode_key(&self) -> Option<i32> {
+        self.event.get_unicode_key()
+    }
+
+    /// Check if the Control key is pressed during this key event
+    pub fn control_down(&self) -> bool {
+        self.event.control_down()
+    }
+
+    /// Check if the Shift key is pressed during this key event
+    pub fn shift_down(&self) -> bool {
+        self.event.shift_down()
+    }
+
+    /// Check if the Alt key is pressed during this key event
+    pub fn alt_down(&self) -> bool {
+        self.event.alt_down()
+    }
+
+    /// Check if the Meta key is pressed during this key event (Cmd on macOS, Windows key on Windows)
+    pub fn meta_down(&self) -> bool {
+        self.event.meta_down()
+    }
+
+    /// Check if the platform-specific command key is pressed (Cmd on macOS, Ctrl on Windows/Linux)
+    pub fn cmd_down(&self) -> bool {
+        self.event.cmd_down()
+    }
+
+    /// Lets wxWidgets generate the `wxEVT_CHAR` event for a char hook event that
+    /// is not skipped.
+    ///
+    /// Only meaningful in a char hook handler.
+    pub fn allow_next_event(&self) {
+        if self.event.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_KeyEvent_DoAllowNextEvent(self.event._as_ptr()) }
+    }
+
+    pub fn skip(&self, skip: bool) {
+        self.event.skip(skip);
+    }
+}
+
+/// Trait for top-level windows that can intercept keys (frames and dialogs)
+pub trait CharHookEvents: WxEvtHandler {
+    /// Binds a handler that sees every key press before the focused control (wxEVT_CHAR_HOOK)
+    ///
+    /// Call `skip(true)` on keys the handler doesn't consume so that they reach
+    /// the control as usual.
+    fn on_char_hook<F>(&self, mut callback: F) -> EventBinding
+    where
+        F: FnMut(KeyEvent) + 'static,
+    {
+        let wrapper = move |event: Event| {
+            callback(KeyEvent::new(event));
+        };
+        self.bind_internal(EventType::CHAR_HOOK, wrapper)
+    }
+}
@@ -1,6 +1,6 @@
 //! Menu-specific events and event data.
 
-use crate::event::{Event, EventType};
+use crate::event::{Event, EventBinding, EventType};
 use crate::geometry::Point;
 use wxdragon_sys as ffi;
 
@@ -102,48 +102,48 @@ impl MenuEventData {
 /// Trait for widgets that can handle menu events
 pub trait MenuEvents: crate::event::WxEvtHandler {
     /// Binds a handler for menu selection events (wxEVT_MENU)
-    fn on_menu_selected<F>(&self, callback: F)
+    fn on_menu_selected<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(MenuEventData) + 'static,
     {
-        self.bind_menu_event(MenuEvent::Selected, callback);
+        self.bind_menu_event(MenuEvent::Selected, callback)
     }
 
     /// Binds a handler for menu open events (wxEVT_MENU_OPEN)
-    fn on_menu_opened<F>(&self, callback: F)
+    fn on_menu_opened<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(MenuEventData) + 'static,
     {
-        self.bind_menu_event(MenuEvent::Opened, callback);
+        self.bind_menu_event(MenuEvent::Opened, callback)
     }
 
     /// Binds a handler for menu close events (wxEVT_MENU_CLOSE)
-    fn on_menu_closed<F>(&self, callback: F)
+    fn on_menu_closed<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(MenuEventData) + 'static,
     {
-        self.bind_menu_event(MenuEvent::Closed, callback);
+        self.bind_menu_event(MenuEvent::Closed, callback)
     }
 
     /// Binds a handler for menu highlight events (wxEVT_MENU_HIGHLIGHT)
-    fn on_menu_highlighted<F>(&self, callback: F)
+    fn on_menu_highlighted<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(MenuEventData) + 'static,
     {
-        self.bind_menu_event(MenuEvent::Highlighted, callback);
+        self.bind_menu_event(MenuEvent::Highlighted, callback)
     }
 
     /// Binds a handler for context menu events (wxEVT_CONTEXT_MENU)
-    fn on_context_menu<F>(&self, callback: F)
+    fn on_context_menu<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(MenuEventData) + 'static,
     {
-        self.bind_menu_event(MenuEvent::ContextRequested, callback);
+        self.bind_menu_event(MenuEvent::ContextRequested, callback)
     }
 
     /// Internal binding method
     #[doc(hidden)]
-    fn bind_menu_event<F>(&self, event: MenuEvent, mut callback: F)
+    fn bind_menu_event<F>(&self, event: MenuEvent, mut callback: F) -> EventBinding
     where
         F: FnMut(MenuEventData) + 'static,
     {
@@ -160,7 +160,7 @@ pub trait MenuEvents: crate::event::WxEvtHandler {
             callback(menu_event_data);
         };
 
-        crate::event::WxEvtHandler::bind_internal(self, event_type, wrapper);
+        crate::event::WxEvtHandler::bind_internal(self, event_type, wrapper)
     }
 }
 
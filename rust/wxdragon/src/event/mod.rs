@@ -8,24 +8,31 @@ use std::ffi::CStr;
 use std::os::raw::c_char;
 use wxdragon_sys as ffi;
 pub mod button_events;
+pub mod custom_events;
 pub mod event_data;
+pub mod key_events;
 pub mod macros;
 pub mod menu_events;
+pub mod mouse_events;
 pub mod scroll_events;
 pub mod taskbar_events;
 pub mod text_events;
 pub mod tree_events;
+pub mod update_ui_events;
 pub mod window_events;
 
 // Re-export window events for easier access
 pub use window_events::{
-    IdleEventData, KeyboardEvent, MouseButtonEvent, MouseEnterEvent, MouseLeaveEvent,
-    MouseMotionEvent, WindowEvent, WindowEventData, WindowEvents, WindowSizeEvent,
+    DpiChangedEvent, IdleEventData, KeyboardEvent, MouseButtonEvent, MouseEnterEvent,
+    MouseLeaveEvent, MouseMotionEvent, WindowEvent, WindowEventData, WindowEvents, WindowSizeEvent,
 };
 
 // Re-export button events for easier access
 pub use button_events::{ButtonEvent, ButtonEventData, ButtonEvents};
 
+// Re-export custom event support
+pub use custom_events::EventSender;
+
 // Re-export text events for easier access
 pub use text_events::{TextEvent, TextEventData, TextEvents};
 
@@ -38,6 +45,15 @@ pub use scroll_events::{ScrollEvent, ScrollEventData, ScrollEvents};
 // Re-export menu events for easier access
 pub use menu_events::{MenuEvent, MenuEventData, MenuEvents};
 
+// Re-export typed keyboard event data
+pub use key_events::{CharHookEvents, KeyCategory, KeyCode, KeyEvent, KeyModifiers};
+
+// Re-export typed mouse event data
+pub use mouse_events::{MouseButton, MouseEvent, WheelAxis};
+
+// Re-export update UI events for easier access
+pub use update_ui_events::{UpdateUIEvent, UpdateUIEvents, UpdateUIMode};
+
 // Re-export taskbar events for easier access
 #[cfg(any(target_os = "windows", target_os = "linux"))]
 pub use taskbar_events::{TaskBarIconEvent, TaskBarIconEventData};
@@ -71,6 +87,16 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     const RIGHT_UP = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_RIGHT_UP;
     const MIDDLE_DOWN = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MIDDLE_DOWN;
     const MIDDLE_UP = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MIDDLE_UP;
+    const LEFT_DCLICK = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_LEFT_DCLICK;
+    const RIGHT_DCLICK = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_RIGHT_DCLICK;
+    const MIDDLE_DCLICK = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MIDDLE_DCLICK;
+    const AUX1_DOWN = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX1_DOWN;
+    const AUX1_UP = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX1_UP;
+    const AUX1_DCLICK = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX1_DCLICK;
+    const AUX2_DOWN = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX2_DOWN;
+    const AUX2_UP = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX2_UP;
+    const AUX2_DCLICK = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_AUX2_DCLICK;
+    const MOUSE_CAPTURE_LOST = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MOUSE_CAPTURE_LOST;
     const MOTION = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MOTION;
     const MOUSEWHEEL = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_MOUSEWHEEL;
     const ENTER_WINDOW = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_ENTER_WINDOW;
@@ -78,6 +104,8 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     const KEY_DOWN = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_KEY_DOWN;
     const KEY_UP = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_KEY_UP;
     const CHAR = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_CHAR;
+    const CHAR_HOOK = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_CHAR_HOOK;
+    const DPI_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_DPI_CHANGED;
     const COMMAND_RADIOBUTTON_SELECTED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_RADIOBUTTON_SELECTED;
     const COMMAND_RADIOBOX_SELECTED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_RADIOBOX_SELECTED;
     const COMMAND_LISTBOX_SELECTED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_COMMAND_LISTBOX_SELECTED;
@@ -194,6 +222,7 @@ pub struct EventType: ffi::WXDEventTypeCEnum { // Use the generated C enum type
     const EVT_DATE_CHANGED = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_DATE_CHANGED;
 
     const IDLE = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_IDLE;
+    const UPDATE_UI = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_UPDATE_UI;
 
     // Drag and drop events
     const DROP_FILES = ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_DROP_FILES;
@@ -480,11 +509,29 @@ impl Event {
         // If event_type_c is WXD_EVENT_TYPE_NULL or an invalid value, return None
         if event_type_c == ffi::WXDEventTypeCEnum_WXD_EVENT_TYPE_NULL {
             None
+        } else if event_type_c >= ffi::WXD_EVENT_TYPE_CUSTOM_BASE as WXDEventTypeCEnum {
+            // Custom event types are registered at runtime and are not known flags
+            Some(EventType::from_bits_retain(event_type_c))
         } else {
             EventType::from_bits(event_type_c)
         }
     }
 
+    /// Gets the payload of a custom event queued with `queue_event`.
+    ///
+    /// Returns `None` if this is not a custom event or the payload is not of type `T`.
+    pub fn get_payload<T: std::any::Any>(&self) -> Option<&T> {
+        if self.0.is_null() {
+            return None;
+        }
+        let payload_ptr = unsafe { ffi::wxd_CustomEvent_GetPayload(self.0) };
+        if payload_ptr.is_null() {
+            return None;
+        }
+        let payload = unsafe { &*(payload_ptr as *const custom_events::EventPayload) };
+        payload.downcast_ref::<T>()
+    }
+
     /// Controls whether the event is processed further.
     pub fn skip(&self, skip: bool) {
         if self.0.is_null() {
@@ -723,6 +770,41 @@ impl Event {
     }
 }
 
+// --- Event Binding Token ---
+
+/// A token identifying a single handler bound with one of the `on_*` methods.
+///
+/// Pass it to [`WxEvtHandler::unbind`] on the same object to remove the handler.
+/// Dropping the token does *not* unbind the handler; handlers that are never
+/// unbound stay attached until the object is destroyed.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct EventBinding {
+    event_type: EventType,
+    id: i32,
+    closure_ptr: *mut c_void,
+}
+
+impl EventBinding {
+    // Token for a bind call that failed or never reached C++; unbinding it is a no-op
+    fn unbound(event_type: EventType, id: i32) -> Self {
+        EventBinding {
+            event_type,
+            id,
+            closure_ptr: std::ptr::null_mut(),
+        }
+    }
+
+    /// Gets the event type this binding was made for.
+    pub fn event_type(&self) -> EventType {
+        self.event_type
+    }
+
+    /// Gets the ID this binding is restricted to, or `ID_ANY` for bindings without an ID.
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+}
+
 // --- WxEvtHandler Trait (Updated for Simple Event Handling) ---
 
 pub trait WxEvtHandler {
@@ -736,14 +818,14 @@ pub trait WxEvtHandler {
 
     // Internal implementation with crate visibility
     #[doc(hidden)]
-    fn bind_internal<F>(&self, event_type: EventType, callback: F)
+    fn bind_internal<F>(&self, event_type: EventType, callback: F) -> EventBinding
     where
         F: FnMut(Event) + 'static,
     {
         let handler_ptr = unsafe { self.get_event_handler_ptr() };
         if handler_ptr.is_null() {
             /* ... error handling ... */
-            return;
+            return EventBinding::unbound(event_type, ffi::WXD_ID_ANY as i32);
         }
 
         // Double-box the callback to match trampoline expectations
@@ -756,19 +838,29 @@ pub trait WxEvtHandler {
         let trampoline_c_void = trampoline_ptr as *mut c_void;
 
         let et = event_type.bits();
-        unsafe { ffi::wxd_EvtHandler_Bind(handler_ptr, et, trampoline_c_void, user_data) };
+        // On failure C++ has already dropped the closure, so its address must not be kept
+        if !unsafe { ffi::wxd_EvtHandler_Bind(handler_ptr, et, trampoline_c_void, user_data) } {
+            return EventBinding::unbound(event_type, ffi::WXD_ID_ANY as i32);
+        }
+
+        // Plain bindings are keyed by wxID_ANY on the C++ side
+        EventBinding {
+            event_type,
+            id: ffi::WXD_ID_ANY as i32,
+            closure_ptr: user_data,
+        }
     }
 
     // Internal implementation with ID support for tools and menu items
     #[doc(hidden)]
-    fn bind_with_id_internal<F>(&self, event_type: EventType, id: i32, callback: F)
+    fn bind_with_id_internal<F>(&self, event_type: EventType, id: i32, callback: F) -> EventBinding
     where
         F: FnMut(Event) + 'static,
     {
         let handler_ptr = unsafe { self.get_event_handler_ptr() };
         if handler_ptr.is_null() {
             /* ... error handling ... */
-            return;
+            return EventBinding::unbound(event_type, id);
         }
 
         // Double-box the callback to match trampoline expectations
@@ -781,7 +873,65 @@ pub trait WxEvtHandler {
         let trampo_c_void = trampoline_ptr as *mut c_void;
 
         let et = event_type.bits();
-        unsafe { ffi::wxd_EvtHandler_BindWithId(handler_ptr, et, id, trampo_c_void, user_data) };
+        // On failure C++ has already dropped the closure, so its address must not be kept
+        if !unsafe { ffi::wxd_EvtHandler_BindWithId(handler_ptr, et, id, trampo_c_void, user_data) }
+        {
+            return EventBinding::unbound(event_type, id);
+        }
+
+        EventBinding {
+            event_type,
+            id,
+            closure_ptr: user_data,
+        }
+    }
+
+    /// Removes a handler previously bound on this object.
+    ///
+    /// `binding` is the token returned by the `on_*` method that bound the handler.
+    /// The handler's closure is dropped immediately, or once the current event has
+    /// been dispatched if called from within a handler.
+    ///
+    /// Returns false if the handler was not bound on this object (for example because
+    /// it was bound on a different object, or the object has been recreated).
+    fn unbind(&self, binding: EventBinding) -> bool {
+        let handler_ptr = unsafe { self.get_event_handler_ptr() };
+        if handler_ptr.is_null() || binding.closure_ptr.is_null() {
+            return false;
+        }
+        unsafe {
+            ffi::wxd_EvtHandler_Unbind(
+                handler_ptr,
+                binding.event_type.bits(),
+                binding.id,
+                binding.closure_ptr,
+            )
+        }
+    }
+
+    /// Binds a handler for a custom event type created with [`EventType::custom`].
+    ///
+    /// Use [`Event::get_payload`] inside the handler to access the event's payload.
+    fn on_custom_event<F>(&self, event_type: EventType, callback: F) -> EventBinding
+    where
+        F: FnMut(Event) + 'static,
+    {
+        self.bind_internal(event_type, callback)
+    }
+
+    /// Queues a custom event with the given payload to this handler.
+    ///
+    /// The event is delivered asynchronously on the GUI thread, once control returns
+    /// to the event loop. To queue events from another thread, use [`event_sender`](Self::event_sender).
+    /// Returns false if the event could not be queued.
+    fn queue_event<T: std::any::Any + Send>(&self, event_type: EventType, payload: T) -> bool {
+        self.event_sender().queue_event(event_type, payload)
+    }
+
+    /// Returns an [`EventSender`] that can be moved to other threads to queue custom
+    /// events to this handler.
+    fn event_sender(&self) -> EventSender {
+        EventSender::new(unsafe { self.get_event_handler_ptr() })
     }
 }
 
@@ -810,13 +960,21 @@ pub unsafe extern "C" fn rust_event_handler_trampoline(
     let closure_box = &mut *(user_data as *mut Box<dyn FnMut(Event) + 'static>);
     let event_ptr = event_ptr_cvoid as *mut ffi::wxd_Event_t;
 
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+    let result = crate::app::catch_handler_panic(|| {
         // UPDATED: Create simple Event
         let safe_event = Event::from_ptr(event_ptr);
         (*closure_box)(safe_event);
-    }));
+    });
 
-    if result.is_err() { /* ... error handling ... */ }
+    if let Err(payload) = result {
+        let event = Event::from_ptr(event_ptr);
+        let event_type = event.get_event_type();
+        let window = event.get_event_object();
+        // The policy runs user code too; it must not unwind into C++ either
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            crate::app::handle_handler_panic(payload, event_type, window);
+        }));
+    }
 }
 
 /// Function called by C++ to drop the Rust closure Box.
@@ -0,0 +1,254 @@
+//! Typed mouse event data.
+
+use crate::dc::DeviceContext;
+use crate::event::{Event, KeyModifiers};
+use crate::geometry::Point;
+use wxdragon_sys as ffi;
+
+/// Mouse buttons, matching wxWidgets' `wxMouseButton`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MouseButton {
+    /// Any button (only meaningful as an argument to the button queries)
+    Any = -1,
+    /// No button
+    None = 0,
+    Left = 1,
+    Middle = 2,
+    Right = 3,
+    /// First extra button, usually "back"
+    Aux1 = 4,
+    /// Second extra button, usually "forward"
+    Aux2 = 5,
+}
+
+impl MouseButton {
+    fn from_raw(value: i32) -> Self {
+        match value {
+            -1 => MouseButton::Any,
+            1 => MouseButton::Left,
+            2 => MouseButton::Middle,
+            3 => MouseButton::Right,
+            4 => MouseButton::Aux1,
+            5 => MouseButton::Aux2,
+            _ => MouseButton::None,
+        }
+    }
+}
+
+/// The axis a mouse wheel event scrolls along
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WheelAxis {
+    Vertical,
+    /// Horizontal scrolling, from tilt wheels and touchpads
+    Horizontal,
+}
+
+/// Data for mouse events
+///
+/// Button and state queries reflect the state at the time the event was generated.
+#[derive(Debug)]
+pub struct MouseEvent {
+    pub event: Event,
+}
+
+impl MouseEvent {
+    pub fn new(event: Event) -> Self {
+        MouseEvent { event }
+    }
+
+    pub fn get_position(&self) -> Option<Point> {
+        self.event.get_position()
+    }
+
+    /// Gets the position in logical coordinates of `dc`, taking its origin,
+    /// scale and mapping mode into account.
+    ///
+    /// The result can be negative when the origin of `dc` has been moved. Returns
+    /// `None` only if the event carries no mouse data or `dc` is not valid.
+    pub fn get_logical_position(&self, dc: &dyn DeviceContext) -> Option<Point> {
+        if self.event.is_null() {
+            return None;
+        }
+        let mut pos = ffi::wxd_Point { x: 0, y: 0 };
+        let ok = unsafe {
+            ffi::wxd_MouseEvent_GetLogicalPosition(self.event._as_ptr(), dc.dc_ptr(), &mut pos)
+        };
+        ok.then(|| Point::new(pos.x, pos.y))
+    }
+
+    /// Gets the button that changed state, or `MouseButton::None` for events
+    /// that are not button presses, releases or double clicks.
+    pub fn button(&self) -> MouseButton {
+        if self.event.is_null() {
+            return MouseButton::None;
+        }
+        MouseButton::from_raw(unsafe { ffi::wxd_MouseEvent_GetButton(self.event._as_ptr()) })
+    }
+
+    /// Returns true if `button` was pressed in this event (`MouseButton::Any` for any button)
+    pub fn button_down(&self, button: MouseButton) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_MouseEvent_ButtonDown(self.event._as_ptr(), button as i32) }
+    }
+
+    /// Returns true if `button` was released in this event (`MouseButton::Any` for any button)
+    pub fn button_up(&self, button: MouseButton) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_MouseEvent_ButtonUp(self.event._as_ptr(), button as i32) }
+    }
+
+    /// Returns true if `button` was double clicked in this event (`MouseButton::Any` for any button)
+    pub fn button_dclick(&self, button: MouseButton) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_MouseEvent_ButtonDClick(self.event._as_ptr(), button as i32) }
+    }
+
+    /// Returns true if this is a double click of any button
+    pub fn is_dclick(&self) -> bool {
+        self.button_dclick(MouseButton::Any)
+    }
+
+    /// Returns true if `button` is currently held down (`MouseButton::Any` for any button)
+    pub fn button_is_down(&self, button: MouseButton) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_MouseEvent_ButtonIsDown(self.event._as_ptr(), button as i32) }
+    }
+
+    /// Returns true if the left button is held down
+    pub fn left_is_down(&self) -> bool {
+        self.button_is_down(MouseButton::Left)
+    }
+
+    /// Returns true if the middle button is held down
+    pub fn middle_is_down(&self) -> bool {
+        self.button_is_down(MouseButton::Middle)
+    }
+
+    /// Returns true if the right button is held down
+    pub fn right_is_down(&self) -> bool {
+        self.button_is_down(MouseButton::Right)
+    }
+
+    /// Returns true if the first extra button is held down
+    pub fn aux1_is_down(&self) -> bool {
+        self.button_is_down(MouseButton::Aux1)
+    }
+
+    /// Returns true if the second extra button is held down
+    pub fn aux2_is_down(&self) -> bool {
+        self.button_is_down(MouseButton::Aux2)
+    }
+
+    /// Gets the number of clicks: 1 for a single click, 2 for a double click, and so on
+    pub fn get_click_count(&self) -> i32 {
+        if self.event.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_MouseEvent_GetClickCount(self.event._as_ptr()) }
+    }
+
+    /// Returns true if this is a motion event with a button held down
+    pub fn dragging(&self) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_MouseEvent_Dragging(self.event._as_ptr()) }
+    }
+
+    /// Returns true if this is a motion event with no button held down
+    pub fn moving(&self) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_MouseEvent_Moving(self.event._as_ptr()) }
+    }
+
+    /// Gets the wheel rotation value for mouse wheel events.
+    /// Returns the wheel rotation amount in multiples of wheel delta.
+    /// Positive values indicate forward/up scrolling, negative values indicate backward/down scrolling.
+    pub fn get_wheel_rotation(&self) -> i32 {
+        self.event.get_wheel_rotation()
+    }
+
+    /// Gets the wheel delta value for mouse wheel events.
+    /// This is the basic unit of wheel rotation, typically 120 on most systems.
+    /// The actual rotation can be calculated as get_wheel_rotation() / get_wheel_delta().
+    pub fn get_wheel_delta(&self) -> i32 {
+        self.event.get_wheel_delta()
+    }
+
+    /// Gets the axis of a mouse wheel event
+    pub fn get_wheel_axis(&self) -> WheelAxis {
+        if self.event.is_null() {
+            return WheelAxis::Vertical;
+        }
+        match unsafe { ffi::wxd_MouseEvent_GetWheelAxis(self.event._as_ptr()) } {
+            1 => WheelAxis::Horizontal,
+            _ => WheelAxis::Vertical,
+        }
+    }
+
+    /// Returns true if the platform reports "natural" (inverted) scrolling
+    pub fn is_wheel_inverted(&self) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_MouseEvent_IsWheelInverted(self.event._as_ptr()) }
+    }
+
+    /// Gets the number of lines to scroll per wheel delta, as configured by the user
+    pub fn get_lines_per_action(&self) -> i32 {
+        if self.event.is_null() {
+            return 0;
+        }
+        unsafe { ffi::wxd_MouseEvent_GetLinesPerAction(self.event._as_ptr()) }
+    }
+
+    /// Gets the modifier keys held down during this event
+    pub fn modifiers(&self) -> KeyModifiers {
+        if self.event.is_null() {
+            return KeyModifiers::empty();
+        }
+        KeyModifiers::from_bits_truncate(unsafe {
+            ffi::wxd_MouseEvent_GetModifiers(self.event._as_ptr())
+        })
+    }
+
+    /// Check if the Control key was pressed during this mouse event
+    pub fn control_down(&self) -> bool {
+        self.modifiers().contains(KeyModifiers::CONTROL)
+    }
+
+    /// Check if the Shift key was pressed during this mouse event
+    pub fn shift_down(&self) -> bool {
+        self.modifiers().contains(KeyModifiers::SHIFT)
+    }
+
+    /// Check if the Alt key was pressed during this mouse event
+    pub fn alt_down(&self) -> bool {
+        self.modifiers().contains(KeyModifiers::ALT)
+    }
+
+    /// Check if the Meta key was pressed during this mouse event
+    pub fn meta_down(&self) -> bool {
+        self.modifiers().contains(KeyModifiers::META)
+    }
+
+    /// Check if the platform-specific command key was pressed (Cmd on macOS, Ctrl on Windows/Linux)
+    pub fn cmd_down(&self) -> bool {
+        // wxWidgets reports Cmd as Control on macOS
+        self.control_down()
+    }
+
+    pub fn skip(&self, skip: bool) {
+        self.event.skip(skip);
+    }
+}
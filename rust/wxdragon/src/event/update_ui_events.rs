@@ -0,0 +1,169 @@
+//! Update UI events and event data.
+//!
+//! wxWidgets periodically sends `wxEVT_UPDATE_UI` for every menu item, tool and
+//! control, which lets an application compute enabled, checked and label state
+//! in one place instead of pushing it to each widget whenever it changes.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::cell::Cell;
+//! use std::rc::Rc;
+//! use wxdragon::prelude::*;
+//!
+//! const ID_SAVE: i32 = ID_HIGHEST + 1;
+//!
+//! let frame = Frame::builder().with_title("Editor").build();
+//! let modified = Rc::new(Cell::new(false));
+//!
+//! let modified_for_ui = modified.clone();
+//! frame.on_update_ui(ID_SAVE, move |event| {
+//!     event.enable(modified_for_ui.get());
+//! });
+//! ```
+
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
+use std::ffi::CString;
+use wxdragon_sys as ffi;
+
+/// Update UI event processing modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateUIMode {
+    /// Send update UI events to all windows
+    ProcessAll = 0,
+    /// Send update UI events only to windows with `ExtraWindowStyle::ProcessUiUpdates`
+    ProcessSpecified = 1,
+}
+
+/// Event data for update UI events
+///
+/// The setters record the desired state; wxWidgets applies it to the menu item,
+/// tool or control with the event's ID once the handler returns.
+#[derive(Debug)]
+pub struct UpdateUIEvent {
+    event: Event,
+}
+
+impl UpdateUIEvent {
+    /// Creates a new UpdateUIEvent from a raw event
+    pub(crate) fn new(event: Event) -> Self {
+        Self { event }
+    }
+
+    /// Gets the underlying event
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+
+    /// Gets the ID of the item being updated
+    pub fn get_id(&self) -> i32 {
+        self.event.get_id()
+    }
+
+    /// Enables or disables the item
+    pub fn enable(&self, enable: bool) {
+        if self.event.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_UpdateUIEvent_Enable(self.event._as_ptr(), enable) }
+    }
+
+    /// Checks or unchecks the item (for checkable menu items, tools and controls)
+    pub fn check(&self, check: bool) {
+        if self.event.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_UpdateUIEvent_Check(self.event._as_ptr(), check) }
+    }
+
+    /// Sets the item's label
+    pub fn set_text(&self, text: &str) {
+        if self.event.is_null() {
+            return;
+        }
+        let c_text = CString::new(text).unwrap_or_default();
+        unsafe { ffi::wxd_UpdateUIEvent_SetText(self.event._as_ptr(), c_text.as_ptr()) }
+    }
+
+    /// Shows or hides the item (only supported for windows)
+    pub fn show(&self, show: bool) {
+        if self.event.is_null() {
+            return;
+        }
+        unsafe { ffi::wxd_UpdateUIEvent_Show(self.event._as_ptr(), show) }
+    }
+
+    /// Gets the enabled state set by a handler so far
+    pub fn get_enabled(&self) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_UpdateUIEvent_GetEnabled(self.event._as_ptr()) }
+    }
+
+    /// Gets the checked state set by a handler so far
+    pub fn get_checked(&self) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_UpdateUIEvent_GetChecked(self.event._as_ptr()) }
+    }
+
+    /// Gets the shown state set by a handler so far
+    pub fn get_shown(&self) -> bool {
+        if self.event.is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_UpdateUIEvent_GetShown(self.event._as_ptr()) }
+    }
+
+    /// Skips the event to allow further processing
+    pub fn skip(&self, skip: bool) {
+        self.event.skip(skip);
+    }
+
+    /// Sets which windows receive update UI events.
+    ///
+    /// With `UpdateUIMode::ProcessSpecified`, only windows that have
+    /// `ExtraWindowStyle::ProcessUiUpdates` set are updated.
+    pub fn set_mode(mode: UpdateUIMode) {
+        unsafe { ffi::wxd_UpdateUIEvent_SetMode(mode as i32) }
+    }
+
+    /// Gets the current update UI processing mode.
+    pub fn get_mode() -> UpdateUIMode {
+        match unsafe { ffi::wxd_UpdateUIEvent_GetMode() } {
+            1 => UpdateUIMode::ProcessSpecified,
+            _ => UpdateUIMode::ProcessAll,
+        }
+    }
+
+    /// Sets the minimum time between update UI events, in milliseconds.
+    ///
+    /// `0` (the default) sends them on every idle cycle and `-1` turns them off.
+    pub fn set_update_interval(interval_ms: i64) {
+        unsafe { ffi::wxd_UpdateUIEvent_SetUpdateInterval(interval_ms) }
+    }
+
+    /// Gets the minimum time between update UI events, in milliseconds.
+    pub fn get_update_interval() -> i64 {
+        unsafe { ffi::wxd_UpdateUIEvent_GetUpdateInterval() }
+    }
+}
+
+/// Trait for windows that can handle update UI events
+pub trait UpdateUIEvents: WxEvtHandler {
+    /// Binds a handler that computes the state of the item with the given ID (wxEVT_UPDATE_UI)
+    ///
+    /// Bind on the frame to update its menu bar and toolbar items; pass `ID_ANY`
+    /// to receive the events for every item.
+    fn on_update_ui<F>(&self, id: i32, mut callback: F) -> EventBinding
+    where
+        F: FnMut(UpdateUIEvent) + 'static,
+    {
+        let wrapper = move |event: Event| {
+            callback(UpdateUIEvent::new(event));
+        };
+        self.bind_with_id_internal(EventType::UPDATE_UI, id, wrapper)
+    }
+}
@@ -1,7 +1,7 @@
 //! Window-level events that are common to all widgets.
 
 use crate::event::event_data::{KeyEventData, MouseEventData};
-use crate::event::{Event, EventType};
+use crate::event::{Event, EventType, MouseEvent};
 use crate::geometry::Size;
 use std::fmt::Debug;
 
@@ -15,10 +15,20 @@ pub enum WindowEvent {
     RightUp,
     MiddleDown,
     MiddleUp,
+    LeftDClick,
+    RightDClick,
+    MiddleDClick,
+    Aux1Down,
+    Aux1Up,
+    Aux1DClick,
+    Aux2Down,
+    Aux2Up,
+    Aux2DClick,
     Motion,
     MouseWheel,
     EnterWindow,
     LeaveWindow,
+    MouseCaptureLost,
 
     // Keyboard events
     KeyDown,
@@ -32,6 +42,7 @@ pub enum WindowEvent {
     Erase,     // Now implemented in C++ layer
     SetFocus,  // Now implemented in C++ layer
     KillFocus, // Now implemented in C++ layer
+    DpiChanged,
 
     // Misc events
     Idle,
@@ -49,6 +60,7 @@ pub enum WindowEventData {
     Keyboard(KeyboardEvent),
     Size(WindowSizeEvent),
     Idle(IdleEventData),
+    DpiChanged(DpiChangedEvent),
     General(Event),
 }
 
@@ -86,6 +98,8 @@ impl WindowEventData {
                 return WindowEventData::MouseLeave(MouseLeaveEvent::new(event));
             } else if event_type == EventType::IDLE {
                 return WindowEventData::Idle(IdleEventData::new(event));
+            } else if event_type == EventType::DPI_CHANGED {
+                return WindowEventData::DpiChanged(DpiChangedEvent::new(event));
             }
         }
 
@@ -93,6 +107,17 @@ impl WindowEventData {
         WindowEventData::General(event)
     }
 
+    /// Gets the typed mouse data for mouse button, motion, wheel and enter/leave events
+    pub fn mouse(&self) -> Option<&MouseEvent> {
+        match self {
+            WindowEventData::MouseButton(event) => Some(&event.event),
+            WindowEventData::MouseMotion(event) => Some(&event.event),
+            WindowEventData::MouseEnter(event) => Some(&event.event),
+            WindowEventData::MouseLeave(event) => Some(&event.event),
+            _ => None,
+        }
+    }
+
     /// Skip this event, allowing it to propagate to parent handlers
     pub fn skip(&self, skip: bool) {
         match self {
@@ -103,6 +128,7 @@ impl WindowEventData {
             WindowEventData::Keyboard(event) => event.event.skip(skip),
             WindowEventData::Size(event) => event.event.skip(skip),
             WindowEventData::Idle(event) => event.event.skip(skip),
+            WindowEventData::DpiChanged(event) => event.event.skip(skip),
             WindowEventData::General(event) => event.skip(skip),
         }
     }
@@ -193,6 +219,16 @@ impl KeyboardEvent {
         }
     }
 
+    /// Gets the key that generated the event
+    pub fn key_code(&self) -> crate::event::KeyCode {
+        self.event.key_code()
+    }
+
+    /// Gets the modifier keys held down during this event
+    pub fn modifiers(&self) -> crate::event::KeyModifiers {
+        self.event.modifiers()
+    }
+
     pub fn get_key_code(&self) -> Option<i32> {
         self.event.get_key_code()
     }
@@ -245,6 +281,45 @@ impl WindowSizeEvent {
     }
 }
 
+/// DPI changed events, sent when a window moves to a display with a different DPI
+///
+/// wxWidgets rescales the window and its sizers itself; handle this event to
+/// rescale anything sized in pixels by hand, such as bitmaps or fixed column widths.
+#[derive(Debug)]
+pub struct DpiChangedEvent {
+    pub event: Event,
+}
+
+impl DpiChangedEvent {
+    pub fn new(event: Event) -> Self {
+        Self { event }
+    }
+
+    /// Gets the DPI of the display the window was on
+    pub fn get_old_dpi(&self) -> Size {
+        if self.event.is_null() {
+            return Size::new(0, 0);
+        }
+        unsafe { wxdragon_sys::wxd_DPIChangedEvent_GetOldDPI(self.event._as_ptr()) }.into()
+    }
+
+    /// Gets the DPI of the display the window is on now
+    pub fn get_new_dpi(&self) -> Size {
+        if self.event.is_null() {
+            return Size::new(0, 0);
+        }
+        unsafe { wxdragon_sys::wxd_DPIChangedEvent_GetNewDPI(self.event._as_ptr()) }.into()
+    }
+
+    /// Rescales a size in pixels from the old DPI to the new one
+    pub fn scale(&self, size: Size) -> Size {
+        if self.event.is_null() {
+            return size;
+        }
+        unsafe { wxdragon_sys::wxd_DPIChangedEvent_Scale(self.event._as_ptr(), size.into()) }.into()
+    }
+}
+
 /// Idle events
 #[derive(Debug)]
 pub struct IdleEventData {
@@ -278,10 +353,20 @@ crate::implement_category_event_handlers!(
     RightUp => mouse_right_up, EventType::RIGHT_UP,
     MiddleDown => mouse_middle_down, EventType::MIDDLE_DOWN,
     MiddleUp => mouse_middle_up, EventType::MIDDLE_UP,
+    LeftDClick => mouse_left_dclick, EventType::LEFT_DCLICK,
+    RightDClick => mouse_right_dclick, EventType::RIGHT_DCLICK,
+    MiddleDClick => mouse_middle_dclick, EventType::MIDDLE_DCLICK,
+    Aux1Down => mouse_aux1_down, EventType::AUX1_DOWN,
+    Aux1Up => mouse_aux1_up, EventType::AUX1_UP,
+    Aux1DClick => mouse_aux1_dclick, EventType::AUX1_DCLICK,
+    Aux2Down => mouse_aux2_down, EventType::AUX2_DOWN,
+    Aux2Up => mouse_aux2_up, EventType::AUX2_UP,
+    Aux2DClick => mouse_aux2_dclick, EventType::AUX2_DCLICK,
     Motion => mouse_motion, EventType::MOTION,
     MouseWheel => mouse_wheel, EventType::MOUSEWHEEL,
     EnterWindow => mouse_enter, EventType::ENTER_WINDOW,
     LeaveWindow => mouse_leave, EventType::LEAVE_WINDOW,
+    MouseCaptureLost => mouse_capture_lost, EventType::MOUSE_CAPTURE_LOST,
     KeyDown => key_down, EventType::KEY_DOWN,
     KeyUp => key_up, EventType::KEY_UP,
     Char => char, EventType::CHAR,
@@ -291,6 +376,7 @@ crate::implement_category_event_handlers!(
     Erase => erase_background, EventType::ERASE,
     SetFocus => set_focus, EventType::SET_FOCUS,
     KillFocus => kill_focus, EventType::KILL_FOCUS,
+    DpiChanged => dpi_changed, EventType::DPI_CHANGED,
     Idle => idle, EventType::IDLE,
     Close => close, EventType::CLOSE_WINDOW,
     Destroy => destroy, EventType::DESTROY
@@ -0,0 +1,388 @@
+//! A single-threaded executor that runs futures on the GUI thread.
+//!
+//! Futures spawned with [`spawn_local`] are polled from the application's idle
+//! processing, alongside callbacks queued with [`call_after`](crate::app::call_after).
+//! Waking a task wakes the event loop, so nothing is polled while no task is
+//! ready. Because tasks never leave the GUI thread, they may hold widgets and
+//! other `!Send` values across `.await` points.
+//!
+//! Any runtime-agnostic future can be awaited: channels, [`sleep`], or a
+//! [`JoinHandle`] of another task. Tasks still pending when the application
+//! exits are dropped before wxWidgets shuts down.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use wxdragon::prelude::*;
+//!
+//! wxdragon::main(|_| {
+//!     let frame = Frame::builder().with_title("Countdown").build();
+//!     let label = StaticText::builder(&frame).with_label("3").build();
+//!     frame.show(true);
+//!
+//!     wxdragon::spawn_local(async move {
+//!         for n in (0..3).rev() {
+//!             wxdragon::executor::sleep(Duration::from_secs(1)).await;
+//!             label.set_label(&n.to_string());
+//!         }
+//!     });
+//! });
+//! ```
+
+use lazy_static::lazy_static;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::time::{Duration, Instant};
+use wxdragon_sys as ffi;
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+type ReadyQueue = Arc<Mutex<VecDeque<usize>>>;
+
+struct TaskWaker {
+    id: usize,
+    // Set while the task sits in the ready queue, so repeated wakes queue it once
+    scheduled: AtomicBool,
+    ready: ReadyQueue,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if !self.scheduled.swap(true, Ordering::AcqRel) {
+            self.ready.lock().unwrap().push_back(self.id);
+            unsafe { ffi::wxd_WakeUpIdle() };
+        }
+    }
+}
+
+struct Task {
+    // None while the task is being polled
+    future: Option<LocalFuture>,
+    waker: Arc<TaskWaker>,
+    // Woken from a nested event loop while it was being polled
+    rewake: bool,
+}
+
+#[derive(Default)]
+struct Executor {
+    tasks: HashMap<usize, Task>,
+    next_id: usize,
+    ready: ReadyQueue,
+    shut_down: bool,
+}
+
+thread_local! {
+    static EXECUTOR: RefCell<Executor> = RefCell::new(Executor::default());
+}
+
+/// Spawns a future onto the GUI thread.
+///
+/// The future does not need to be `Send`. It is first polled during the next
+/// idle processing, and again each time it is woken.
+///
+/// Must be called on the main thread: a future spawned on any other thread is
+/// never polled. To start work from another thread, use
+/// [`call_after`](crate::app::call_after) to call `spawn_local` on the main
+/// thread. Debug builds panic when called from another thread.
+///
+/// Dropping the returned [`JoinHandle`] detaches the task; it keeps running.
+/// If the future panics, the panic is passed to the application's
+/// [panic policy](crate::app::PanicPolicy) and the handle never completes.
+pub fn spawn_local<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + 'static,
+    F::Output: 'static,
+{
+    debug_assert!(
+        unsafe { ffi::wxd_IsMainThread() },
+        "spawn_local must be called on the main thread"
+    );
+    let state = Rc::new(RefCell::new(JoinState {
+        result: None,
+        waker: None,
+        finished: false,
+    }));
+    let task_state = state.clone();
+    let task = async move {
+        let output = future.await;
+        let mut state = task_state.borrow_mut();
+        state.result = Some(output);
+        state.finished = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    };
+
+    let waker = EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        if executor.shut_down {
+            return None;
+        }
+        let id = executor.next_id;
+        executor.next_id += 1;
+        let waker = Arc::new(TaskWaker {
+            id,
+            scheduled: AtomicBool::new(false),
+            ready: executor.ready.clone(),
+        });
+        executor.tasks.insert(
+            id,
+            Task {
+                future: Some(Box::pin(task)),
+                waker: waker.clone(),
+                rewake: false,
+            },
+        );
+        Some(waker)
+    });
+    if let Some(waker) = waker {
+        waker.wake_by_ref();
+    }
+
+    JoinHandle { state }
+}
+
+/// Polls the tasks that were ready when the call started.
+///
+/// Called from idle processing. Returns true if any task was polled.
+pub(crate) fn run_ready_tasks() -> bool {
+    let ready: Vec<usize> = EXECUTOR.with(|executor| {
+        let queue = executor.borrow().ready.clone();
+        let mut queue = queue.lock().unwrap();
+        queue.drain(..).collect()
+    });
+    if ready.is_empty() {
+        return false;
+    }
+
+    for id in ready {
+        let taken = EXECUTOR.with(|executor| {
+            let mut executor = executor.borrow_mut();
+            let task = executor.tasks.get_mut(&id)?;
+            task.waker.scheduled.store(false, Ordering::Release);
+            match task.future.take() {
+                Some(future) => Some((future, task.waker.clone())),
+                None => {
+                    // Already being polled further up the stack
+                    task.rewake = true;
+                    None
+                }
+            }
+        });
+        let Some((mut future, task_waker)) = taken else {
+            continue;
+        };
+
+        let waker = Waker::from(task_waker.clone());
+        let mut cx = Context::from_waker(&waker);
+        let result = crate::app::catch_handler_panic(|| future.as_mut().poll(&mut cx));
+
+        match result {
+            Ok(Poll::Pending) => {
+                let rewake = EXECUTOR.with(|executor| {
+                    let mut executor = executor.borrow_mut();
+                    match executor.tasks.get_mut(&id) {
+                        Some(task) => {
+                            task.future = Some(future);
+                            std::mem::take(&mut task.rewake)
+                        }
+                        None => false,
+                    }
+                });
+                if rewake {
+                    task_waker.wake_by_ref();
+                }
+            }
+            Ok(Poll::Ready(())) => {
+                EXECUTOR.with(|executor| executor.borrow_mut().tasks.remove(&id));
+            }
+            Err(payload) => {
+                EXECUTOR.with(|executor| executor.borrow_mut().tasks.remove(&id));
+                crate::app::handle_handler_panic(payload, None, None);
+            }
+        }
+    }
+
+    true
+}
+
+/// Function called by C++ when the application exits.
+///
+/// Drops all pending tasks while wxWidgets is still alive. Tasks spawned after
+/// this point are dropped immediately.
+#[no_mangle]
+pub extern "C" fn shutdown_rust_executor() {
+    let tasks = EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        executor.shut_down = true;
+        executor.ready.lock().unwrap().clear();
+        std::mem::take(&mut executor.tasks)
+    });
+    // Dropped outside the borrow: a future's destructor may call back into the executor
+    drop(tasks);
+}
+
+struct JoinState<T> {
+    result: Option<T>,
+    waker: Option<Waker>,
+    finished: bool,
+}
+
+/// A handle to a task spawned with [`spawn_local`].
+///
+/// Awaiting the handle yields the task's output.
+pub struct JoinHandle<T> {
+    state: Rc<RefCell<JoinState<T>>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Returns true if the task has run to completion.
+    pub fn is_finished(&self) -> bool {
+        self.state.borrow().finished
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.borrow_mut();
+        match state.result.take() {
+            Some(output) => Poll::Ready(output),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// --- Timers ---
+
+// Deadlines are tracked by a helper thread, which wakes the owning task (and
+// with it the event loop) once a deadline passes.
+struct TimerQueue {
+    // Entries are identified by the id of the Sleep that registered them
+    entries: Mutex<Vec<(u64, Instant, Waker)>>,
+    changed: Condvar,
+    next_id: AtomicU64,
+}
+
+lazy_static! {
+    static ref TIMERS: Arc<TimerQueue> = {
+        let queue = Arc::new(TimerQueue {
+            entries: Mutex::new(Vec::new()),
+            changed: Condvar::new(),
+            next_id: AtomicU64::new(0),
+        });
+        let thread_queue = queue.clone();
+        std::thread::Builder::new()
+            .name("wxdragon-timer".into())
+            .spawn(move || run_timer_thread(&thread_queue))
+            .expect("Failed to spawn timer thread");
+        queue
+    };
+}
+
+fn run_timer_thread(queue: &TimerQueue) {
+    let mut entries = queue.entries.lock().unwrap();
+    loop {
+        let now = Instant::now();
+        let mut next_deadline = None::<Instant>;
+        entries.retain(|(_, deadline, waker)| {
+            if *deadline <= now {
+                waker.wake_by_ref();
+                false
+            } else {
+                next_deadline = Some(next_deadline.map_or(*deadline, |d| d.min(*deadline)));
+                true
+            }
+        });
+        entries = match next_deadline {
+            Some(deadline) => {
+                queue
+                    .changed
+                    .wait_timeout(entries, deadline - now)
+                    .unwrap()
+                    .0
+            }
+            None => queue.changed.wait(entries).unwrap(),
+        };
+    }
+}
+
+/// Returns a future that completes after `duration` has elapsed.
+pub fn sleep(duration: Duration) -> Sleep {
+    Sleep {
+        deadline: Instant::now() + duration,
+        registered: None,
+    }
+}
+
+/// Future returned by [`sleep`].
+///
+/// Dropping it before it completes cancels its timer.
+pub struct Sleep {
+    deadline: Instant,
+    // Timer id and the waker it wakes
+    registered: Option<(u64, Waker)>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        let already_registered = self
+            .registered
+            .as_ref()
+            .is_some_and(|(_, waker)| waker.will_wake(cx.waker()));
+        if !already_registered {
+            let waker = cx.waker().clone();
+            let mut entries = TIMERS.entries.lock().unwrap();
+            let existing = self
+                .registered
+                .as_ref()
+                .and_then(|(id, _)| entries.iter_mut().find(|(entry_id, ..)| entry_id == id));
+            let id = match existing {
+                // Polled by a different task since; wake that one instead
+                Some((id, _, entry_waker)) => {
+                    *entry_waker = waker.clone();
+                    *id
+                }
+                None => {
+                    let id = TIMERS.next_id.fetch_add(1, Ordering::Relaxed);
+                    entries.push((id, self.deadline, waker.clone()));
+                    TIMERS.changed.notify_one();
+                    id
+                }
+            };
+            self.registered = Some((id, waker));
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let Some((id, _)) = &self.registered {
+            TIMERS
+                .entries
+                .lock()
+                .unwrap()
+                .retain(|(entry_id, ..)| entry_id != id);
+        }
+    }
+}
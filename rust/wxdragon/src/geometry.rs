@@ -143,3 +143,55 @@ impl From<ffi::wxd_Rect> for Rect {
         }
     }
 }
+
+/// Values that can be converted between device-independent pixels (DIPs) and
+/// physical pixels: [`Size`], [`Point`] and `i32`.
+///
+/// Used by [`WxWidget::from_dip`](crate::window::WxWidget::from_dip) and
+/// [`WxWidget::to_dip`](crate::window::WxWidget::to_dip). Components equal to
+/// -1 (as in [`DEFAULT_SIZE`] and [`DEFAULT_POSITION`]) are left unchanged.
+pub trait DipValue: Copy {
+    /// Converts from DIPs to physical pixels for `window` (or the primary display if null).
+    ///
+    /// # Safety
+    /// `window` must be null or point to a live window.
+    #[doc(hidden)]
+    unsafe fn from_dip_for(self, window: *mut ffi::wxd_Window_t) -> Self;
+
+    /// Converts from physical pixels to DIPs for `window` (or the primary display if null).
+    ///
+    /// # Safety
+    /// `window` must be null or point to a live window.
+    #[doc(hidden)]
+    unsafe fn to_dip_for(self, window: *mut ffi::wxd_Window_t) -> Self;
+}
+
+impl DipValue for Size {
+    unsafe fn from_dip_for(self, window: *mut ffi::wxd_Window_t) -> Self {
+        ffi::wxd_Window_FromDIPSize(window, self.into()).into()
+    }
+
+    unsafe fn to_dip_for(self, window: *mut ffi::wxd_Window_t) -> Self {
+        ffi::wxd_Window_ToDIPSize(window, self.into()).into()
+    }
+}
+
+impl DipValue for Point {
+    unsafe fn from_dip_for(self, window: *mut ffi::wxd_Window_t) -> Self {
+        ffi::wxd_Window_FromDIPPoint(window, self.into()).into()
+    }
+
+    unsafe fn to_dip_for(self, window: *mut ffi::wxd_Window_t) -> Self {
+        ffi::wxd_Window_ToDIPPoint(window, self.into()).into()
+    }
+}
+
+impl DipValue for i32 {
+    unsafe fn from_dip_for(self, window: *mut ffi::wxd_Window_t) -> Self {
+        ffi::wxd_Window_FromDIPInt(window, self)
+    }
+
+    unsafe fn to_dip_for(self, window: *mut ffi::wxd_Window_t) -> Self {
+        ffi::wxd_Window_ToDIPInt(window, self)
+    }
+}
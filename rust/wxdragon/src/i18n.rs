@@ -0,0 +1,372 @@
+//! Translations and locale-aware formatting.
+//!
+//! [`LocaleSetup`] selects the user's locale and loads message catalogs
+//! (compiled gettext `.mo` files), either from directories laid out as
+//! `<dir>/<lang>/<domain>.mo` (or `<dir>/<lang>/LC_MESSAGES/<domain>.mo`) or
+//! from bytes embedded in the executable. Pass it to
+//! [`main_with_options`](crate::app::main_with_options) so it is applied
+//! before any window is created.
+//!
+//! Strings are translated with the [`tr!`](crate::tr) and
+//! [`tr_plural!`](crate::tr_plural) macros. To extract them with `xgettext`, use
+//! `--keyword=tr --keyword=tr_plural:1,2`. Labels in XRC resources are
+//! translated as they are loaded, using the domain set with
+//! `XmlResource::set_domain` (`xrc` feature).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::app::AppOptions;
+//! use wxdragon::i18n::LocaleSetup;
+//! use wxdragon::prelude::*;
+//!
+//! # const MYAPP_DE_MO: &[u8] = &[];
+//! // MYAPP_DE_MO could come from include_bytes!("../locale/de/myapp.mo")
+//! let locale = LocaleSetup::new()
+//!     .with_catalog_dir("locale")
+//!     .with_embedded_catalog("myapp", "de", MYAPP_DE_MO)
+//!     .with_catalog("myapp");
+//!
+//! let _ = wxdragon::app::main_with_options(AppOptions::new().with_locale(locale), |_| {
+//!     let frame = Frame::builder().with_title(&wxdragon::tr!("My App")).build();
+//!     let count = 3;
+//!     let label = wxdragon::tr_plural!("{} file selected", "{} files selected", count);
+//!     frame.show(true);
+//! });
+//! ```
+
+use crate::datetime::DateTime;
+use crate::utils::take_string;
+use std::ffi::CString;
+use std::fmt::{Display, Write};
+use std::path::PathBuf;
+use wxdragon_sys as ffi;
+
+/// Locale and catalogs to set up when the application starts
+///
+/// The standard wxWidgets catalog, which translates stock buttons and built-in
+/// dialogs, is loaded unless turned off with [`with_std_catalog`](Self::with_std_catalog).
+#[derive(Debug, Clone)]
+pub struct LocaleSetup {
+    language: Option<String>,
+    catalog_dirs: Vec<PathBuf>,
+    embedded_catalogs: Vec<(String, String, &'static [u8])>,
+    domains: Vec<String>,
+    std_catalog: bool,
+}
+
+impl Default for LocaleSetup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocaleSetup {
+    /// Uses the user's locale and preferred languages
+    pub fn new() -> Self {
+        LocaleSetup {
+            language: None,
+            catalog_dirs: Vec::new(),
+            embedded_catalogs: Vec::new(),
+            domains: Vec::new(),
+            std_catalog: true,
+        }
+    }
+
+    /// Translates to `language` (e.g. `"fr"` or `"pt_BR"`) instead of the user's
+    /// preferred languages. Formatting still follows the user's locale.
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    /// Adds a directory to search for `.mo` files
+    pub fn with_catalog_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.catalog_dirs.push(dir.into());
+        self
+    }
+
+    /// Adds the compiled catalog for `domain` in `language`, e.g. from
+    /// `include_bytes!`. Embedded catalogs take precedence over files.
+    ///
+    /// The domain must also be loaded with [`with_catalog`](Self::with_catalog).
+    pub fn with_embedded_catalog(
+        mut self,
+        domain: &str,
+        language: &str,
+        data: &'static [u8],
+    ) -> Self {
+        self.embedded_catalogs
+            .push((domain.to_string(), language.to_string(), data));
+        self
+    }
+
+    /// Loads the catalog for `domain`. Catalogs added first are searched first.
+    pub fn with_catalog(mut self, domain: &str) -> Self {
+        self.domains.push(domain.to_string());
+        self
+    }
+
+    /// Sets whether the wxWidgets catalog is loaded
+    pub fn with_std_catalog(mut self, load: bool) -> Self {
+        self.std_catalog = load;
+        self
+    }
+
+    /// Applies the setup. Call it on the main thread, after wxWidgets is
+    /// initialized and before creating windows.
+    ///
+    /// Returns the domains for which no catalog was found in the selected language.
+    pub fn apply(&self) -> Vec<String> {
+        unsafe {
+            ffi::wxd_UILocale_UseDefault();
+            ffi::wxd_Translations_Init();
+        }
+        if let Some(language) = &self.language {
+            let c_language = CString::new(language.as_str()).unwrap_or_default();
+            unsafe { ffi::wxd_Translations_SetLanguage(c_language.as_ptr()) };
+        }
+        for dir in &self.catalog_dirs {
+            if let Ok(c_dir) = CString::new(dir.to_string_lossy().as_bytes()) {
+                unsafe { ffi::wxd_Translations_AddCatalogLookupPath(c_dir.as_ptr()) };
+            }
+        }
+        for (domain, language, data) in &self.embedded_catalogs {
+            let (Ok(c_domain), Ok(c_language)) = (
+                CString::new(domain.as_str()),
+                CString::new(language.as_str()),
+            ) else {
+                continue;
+            };
+            unsafe {
+                ffi::wxd_Translations_AddEmbeddedCatalog(
+                    c_domain.as_ptr(),
+                    c_language.as_ptr(),
+                    data.as_ptr(),
+                    data.len(),
+                )
+            };
+        }
+        if self.std_catalog {
+            unsafe { ffi::wxd_Translations_AddStdCatalog() };
+        }
+        self.domains
+            .iter()
+            .filter(|domain| {
+                let c_domain = CString::new(domain.as_str()).unwrap_or_default();
+                !unsafe { ffi::wxd_Translations_AddCatalog(c_domain.as_ptr()) }
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Translates `text` using the loaded catalogs, or returns it unchanged if it
+/// has no translation.
+pub fn translate(text: &str) -> String {
+    get_translation(text, None)
+}
+
+/// Translates `text` using only the catalog for `domain`
+pub fn translate_in(domain: &str, text: &str) -> String {
+    get_translation(text, Some(domain))
+}
+
+/// Translates the form of a message used for `n` items. Without a translation,
+/// `singular` is returned for 1 and `plural` otherwise.
+pub fn translate_plural(singular: &str, plural: &str, n: u32) -> String {
+    let (Ok(c_singular), Ok(c_plural)) = (CString::new(singular), CString::new(plural)) else {
+        return if n == 1 { singular } else { plural }.to_string();
+    };
+    unsafe {
+        take_string(ffi::wxd_GetTranslationPlural(
+            c_singular.as_ptr(),
+            c_plural.as_ptr(),
+            n,
+            std::ptr::null(),
+        ))
+    }
+}
+
+fn get_translation(text: &str, domain: Option<&str>) -> String {
+    let Ok(c_text) = CString::new(text) else {
+        return text.to_string();
+    };
+    let c_domain = domain.map(|domain| CString::new(domain).unwrap_or_default());
+    let domain_ptr = c_domain.as_ref().map_or(std::ptr::null(), |d| d.as_ptr());
+    unsafe { take_string(ffi::wxd_GetTranslation(c_text.as_ptr(), domain_ptr)) }
+}
+
+/// Gets the name of the current locale, such as `"de_DE"`
+pub fn current_locale_name() -> String {
+    unsafe { take_string(ffi::wxd_UILocale_GetName()) }
+}
+
+/// Formats a number with the locale's decimal separator and thousands
+/// separators. With `precision` of `None`, as many decimals as needed are shown.
+pub fn format_number(value: f64, precision: Option<u8>) -> String {
+    let precision = precision.map_or(-1, i32::from);
+    unsafe { take_string(ffi::wxd_Locale_FormatNumber(value, precision, true)) }
+}
+
+/// Formats an integer with the locale's thousands separators
+pub fn format_integer(value: i64) -> String {
+    unsafe { take_string(ffi::wxd_Locale_FormatInteger(value, true)) }
+}
+
+/// Parses a number written the way [`format_number`] writes it
+pub fn parse_number(text: &str) -> Option<f64> {
+    let c_text = CString::new(text).ok()?;
+    let mut value = 0.0;
+    unsafe { ffi::wxd_Locale_ParseNumber(c_text.as_ptr(), &mut value) }.then_some(value)
+}
+
+/// The locale's date and time formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateFormat {
+    /// The date in numbers, e.g. `12/31/2024`
+    ShortDate,
+    /// The date with the month's name, e.g. `Tuesday, December 31, 2024`
+    LongDate,
+    /// The time of day
+    Time,
+    /// Both the date and the time of day
+    DateTime,
+}
+
+impl DateFormat {
+    fn to_raw(self) -> ffi::wxd_DateFormat {
+        match self {
+            DateFormat::ShortDate => ffi::wxd_DateFormat_WXD_DATE_FORMAT_SHORT_DATE,
+            DateFormat::LongDate => ffi::wxd_DateFormat_WXD_DATE_FORMAT_LONG_DATE,
+            DateFormat::Time => ffi::wxd_DateFormat_WXD_DATE_FORMAT_TIME,
+            DateFormat::DateTime => ffi::wxd_DateFormat_WXD_DATE_FORMAT_DATE_TIME,
+        }
+    }
+}
+
+/// Formats a date and/or time in the locale's format. Invalid dates give an empty string.
+pub fn format_date(datetime: &DateTime, format: DateFormat) -> String {
+    unsafe {
+        take_string(ffi::wxd_Locale_FormatDateTime(
+            datetime.as_ptr(),
+            format.to_raw(),
+        ))
+    }
+}
+
+/// Fills the `{}` and `{N}` placeholders of a translated message.
+///
+/// Placeholders are filled at run time, as the translation decides where they
+/// go; `{{` and `}}` stand for literal braces. Placeholders without a matching
+/// argument are kept as they are.
+#[doc(hidden)]
+pub fn format_message(template: &str, args: &[&dyn Display]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut next_index = 0;
+    let mut rest = template;
+
+    while let Some(pos) = rest.find(['{', '}']) {
+        result.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            result.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        let placeholder = tail
+            .starts_with('{')
+            .then(|| tail.find('}'))
+            .flatten()
+            .map(|end| (&tail[1..end], end));
+        match placeholder {
+            Some((spec, end)) if spec.is_empty() || spec.parse::<usize>().is_ok() => {
+                let index = if spec.is_empty() {
+                    next_index += 1;
+                    next_index - 1
+                } else {
+                    spec.parse().unwrap_or(usize::MAX)
+                };
+                match args.get(index) {
+                    Some(arg) => {
+                        let _ = write!(result, "{arg}");
+                    }
+                    None => result.push_str(&tail[..=end]),
+                }
+                rest = &tail[end + 1..];
+            }
+            _ => {
+                result.push_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Translates a message, filling its `{}` / `{N}` placeholders with the given arguments.
+///
+/// ```rust,no_run
+/// let name = "Ada";
+/// let greeting = wxdragon::tr!("Hello, {}!", name);
+/// let title = wxdragon::tr!("Settings");
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($msgid:literal $(,)?) => {
+        $crate::i18n::translate($msgid)
+    };
+    ($msgid:literal, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::format_message(
+            &$crate::i18n::translate($msgid),
+            &[$(&$arg as &dyn ::std::fmt::Display),+],
+        )
+    };
+}
+
+/// Translates the singular or plural form of a message for `n` items, a `u32`.
+///
+/// Without further arguments, `n` fills the placeholders; otherwise the
+/// arguments do, as with [`tr!`].
+///
+/// ```rust,no_run
+/// let count = 4;
+/// let status = wxdragon::tr_plural!("{} file", "{} files", count);
+/// let folder = "Music";
+/// let detail = wxdragon::tr_plural!("{0} file in {1}", "{0} files in {1}", count, count, folder);
+/// ```
+#[macro_export]
+macro_rules! tr_plural {
+    ($singular:literal, $plural:literal, $n:expr $(,)?) => {{
+        let n: u32 = $n;
+        $crate::i18n::format_message(
+            &$crate::i18n::translate_plural($singular, $plural, n),
+            &[&n as &dyn ::std::fmt::Display],
+        )
+    }};
+    ($singular:literal, $plural:literal, $n:expr, $($arg:expr),+ $(,)?) => {{
+        let n: u32 = $n;
+        $crate::i18n::format_message(
+            &$crate::i18n::translate_plural($singular, $plural, n),
+            &[$(&$arg as &dyn ::std::fmt::Display),+],
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_message;
+
+    #[test]
+    fn fills_placeholders() {
+        assert_eq!(format_message("{} of {}", &[&1, &"two"]), "1 of two");
+        assert_eq!(
+            format_message("{1} before {0}", &[&"a", &"b"]),
+            "b before a"
+        );
+        assert_eq!(format_message("{{{}}}", &[&5]), "{5}");
+        assert_eq!(format_message("{} and {}", &[&1]), "1 and {}");
+        assert_eq!(format_message("{name}", &[&1]), "{name}");
+    }
+}
@@ -0,0 +1,450 @@
+//! Platform-independent images.
+//!
+//! An [`Image`] holds RGB pixel data with optional alpha or mask, and can be
+//! loaded, saved and transformed. Convert it to a [`Bitmap`] to draw or show it.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//!
+//! # fn load_thumbnail(path: &str) -> Option<Bitmap> {
+//! let image = Image::from_file(path)?;
+//! let thumbnail = image.scale(64, 64, ImageResizeQuality::High)?;
+//! thumbnail.to_bitmap()
+//! # }
+//! ```
+
+use crate::bitmap::Bitmap;
+use crate::color::Colour;
+use crate::geometry::{Rect, Size};
+use std::ffi::CString;
+use std::path::Path;
+use wxdragon_sys as ffi;
+
+/// Image file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageType {
+    /// Detect the format when loading; use the file extension when saving
+    #[default]
+    Any,
+    Png,
+    Jpeg,
+    Bmp,
+    Gif,
+    Ico,
+    Tiff,
+}
+
+impl ImageType {
+    fn to_raw(self) -> ffi::wxd_ImageType {
+        match self {
+            ImageType::Any => ffi::wxd_ImageType_WXD_IMAGE_TYPE_ANY,
+            ImageType::Png => ffi::wxd_ImageType_WXD_IMAGE_TYPE_PNG,
+            ImageType::Jpeg => ffi::wxd_ImageType_WXD_IMAGE_TYPE_JPEG,
+            ImageType::Bmp => ffi::wxd_ImageType_WXD_IMAGE_TYPE_BMP,
+            ImageType::Gif => ffi::wxd_ImageType_WXD_IMAGE_TYPE_GIF,
+            ImageType::Ico => ffi::wxd_ImageType_WXD_IMAGE_TYPE_ICO,
+            ImageType::Tiff => ffi::wxd_ImageType_WXD_IMAGE_TYPE_TIFF,
+        }
+    }
+
+    fn from_raw(value: ffi::wxd_ImageType) -> Self {
+        match value {
+            ffi::wxd_ImageType_WXD_IMAGE_TYPE_PNG => ImageType::Png,
+            ffi::wxd_ImageType_WXD_IMAGE_TYPE_JPEG => ImageType::Jpeg,
+            ffi::wxd_ImageType_WXD_IMAGE_TYPE_BMP => ImageType::Bmp,
+            ffi::wxd_ImageType_WXD_IMAGE_TYPE_GIF => ImageType::Gif,
+            ffi::wxd_ImageType_WXD_IMAGE_TYPE_ICO => ImageType::Ico,
+            ffi::wxd_ImageType_WXD_IMAGE_TYPE_TIFF => ImageType::Tiff,
+            _ => ImageType::Any,
+        }
+    }
+}
+
+/// The algorithm used to resize images
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageResizeQuality {
+    /// A fast algorithm, currently [`Nearest`](Self::Nearest)
+    #[default]
+    Normal,
+    /// The best algorithm for the direction of resizing: box averaging when
+    /// shrinking, bicubic when enlarging
+    High,
+    Nearest,
+    Bilinear,
+    Bicubic,
+    BoxAverage,
+}
+
+impl ImageResizeQuality {
+    fn to_raw(self) -> ffi::wxd_ImageResizeQuality {
+        match self {
+            ImageResizeQuality::Normal => ffi::wxd_ImageResizeQuality_WXD_IMAGE_QUALITY_NORMAL,
+            ImageResizeQuality::High => ffi::wxd_ImageResizeQuality_WXD_IMAGE_QUALITY_HIGH,
+            ImageResizeQuality::Nearest => ffi::wxd_ImageResizeQuality_WXD_IMAGE_QUALITY_NEAREST,
+            ImageResizeQuality::Bilinear => ffi::wxd_ImageResizeQuality_WXD_IMAGE_QUALITY_BILINEAR,
+            ImageResizeQuality::Bicubic => ffi::wxd_ImageResizeQuality_WXD_IMAGE_QUALITY_BICUBIC,
+            ImageResizeQuality::BoxAverage => {
+                ffi::wxd_ImageResizeQuality_WXD_IMAGE_QUALITY_BOX_AVERAGE
+            }
+        }
+    }
+}
+
+/// A platform-independent image that can be loaded, saved and transformed.
+///
+/// Methods returning an [`Image`], such as [`scale`](Self::scale) and
+/// [`rotate90`](Self::rotate90), leave the original unchanged. Those taking
+/// `&mut self`, such as [`rescale`](Self::rescale), [`paste`](Self::paste) and
+/// [`set_alpha`](Self::set_alpha), change the image in place.
+pub struct Image {
+    ptr: *mut ffi::wxd_Image_t,
+}
+
+impl Image {
+    /// Creates a black image without alpha
+    pub fn new(width: i32, height: i32) -> Option<Self> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Create(width, height) })
+    }
+
+    /// Creates an image from RGBA pixel data, 4 bytes per pixel row by row
+    pub fn from_rgba(data: &[u8], width: u32, height: u32) -> Option<Self> {
+        let expected_len = (width as usize) * (height as usize) * 4;
+        if data.len() != expected_len || width == 0 || height == 0 {
+            return None;
+        }
+        Self::from_ptr(unsafe {
+            ffi::wxd_Image_CreateFromRGBA(data.as_ptr(), width as i32, height as i32)
+        })
+    }
+
+    /// Loads an image file, detecting its format
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Option<Self> {
+        Self::from_file_with_type(path, ImageType::Any)
+    }
+
+    /// Loads an image file of the given format
+    pub fn from_file_with_type<P: AsRef<Path>>(path: P, image_type: ImageType) -> Option<Self> {
+        let path = CString::new(path.as_ref().to_string_lossy().as_bytes()).ok()?;
+        Self::from_ptr(unsafe { ffi::wxd_Image_CreateFromFile(path.as_ptr(), image_type.to_raw()) })
+    }
+
+    /// Decodes an image from the contents of a file, detecting its format.
+    ///
+    /// This works well with `include_bytes!`:
+    ///
+    /// ```rust,no_run
+    /// # use wxdragon::prelude::*;
+    /// # const LOGO_PNG: &[u8] = &[];
+    /// // LOGO_PNG could come from include_bytes!("../assets/logo.png")
+    /// let logo = Image::from_bytes(LOGO_PNG);
+    /// ```
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        Self::from_bytes_with_type(data, ImageType::Any)
+    }
+
+    /// Decodes an image of the given format from the contents of a file
+    pub fn from_bytes_with_type(data: &[u8], image_type: ImageType) -> Option<Self> {
+        Self::from_ptr(unsafe {
+            ffi::wxd_Image_CreateFromData(data.as_ptr(), data.len(), image_type.to_raw())
+        })
+    }
+
+    /// Creates an image from the pixels of a bitmap
+    pub fn from_bitmap(bitmap: &Bitmap) -> Option<Self> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_CreateFromBitmap(bitmap.as_ptr()) })
+    }
+
+    fn from_ptr(ptr: *mut ffi::wxd_Image_t) -> Option<Self> {
+        (!ptr.is_null()).then_some(Self { ptr })
+    }
+
+    /// Creates a bitmap that can be drawn or shown in a widget
+    pub fn to_bitmap(&self) -> Option<Bitmap> {
+        Bitmap::from_image(self)
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const ffi::wxd_Image_t {
+        self.ptr
+    }
+
+    /// Saves the image, in the format given by the file extension
+    pub fn save_file<P: AsRef<Path>>(&self, path: P) -> bool {
+        self.save_file_with_type(path, ImageType::Any)
+    }
+
+    /// Saves the image in the given format
+    pub fn save_file_with_type<P: AsRef<Path>>(&self, path: P, image_type: ImageType) -> bool {
+        let Ok(path) = CString::new(path.as_ref().to_string_lossy().as_bytes()) else {
+            return false;
+        };
+        unsafe { ffi::wxd_Image_SaveFile(self.ptr, path.as_ptr(), image_type.to_raw()) }
+    }
+
+    /// Encodes the image in the given format, which can't be [`ImageType::Any`]
+    pub fn to_bytes(&self, image_type: ImageType) -> Option<Vec<u8>> {
+        let mut len = 0;
+        unsafe {
+            let data = ffi::wxd_Image_SaveToData(self.ptr, image_type.to_raw(), &mut len);
+            if data.is_null() {
+                return None;
+            }
+            let bytes = std::slice::from_raw_parts(data, len).to_vec();
+            ffi::wxd_Image_FreeData(data);
+            Some(bytes)
+        }
+    }
+
+    /// Gets the width in pixels
+    pub fn get_width(&self) -> i32 {
+        unsafe { ffi::wxd_Image_GetWidth(self.ptr) }
+    }
+
+    /// Gets the height in pixels
+    pub fn get_height(&self) -> i32 {
+        unsafe { ffi::wxd_Image_GetHeight(self.ptr) }
+    }
+
+    /// Gets the size in pixels
+    pub fn get_size(&self) -> Size {
+        Size::new(self.get_width(), self.get_height())
+    }
+
+    /// Gets the format the image was loaded from, or [`ImageType::Any`]
+    pub fn get_type(&self) -> ImageType {
+        ImageType::from_raw(unsafe { ffi::wxd_Image_GetType(self.ptr) })
+    }
+
+    /// Gets the pixels as RGBA data, 4 bytes per pixel row by row. Masked
+    /// pixels are transparent.
+    pub fn get_rgba_data(&self) -> Vec<u8> {
+        let len = (self.get_width() as usize) * (self.get_height() as usize) * 4;
+        unsafe {
+            let data = ffi::wxd_Image_GetRGBAData(self.ptr);
+            if data.is_null() {
+                return Vec::new();
+            }
+            let rgba_data = std::slice::from_raw_parts(data, len).to_vec();
+            ffi::wxd_Image_FreeData(data);
+            rgba_data
+        }
+    }
+
+    /// Gets the colour of a pixel, opaque unless the image has alpha
+    pub fn get_pixel(&self, x: i32, y: i32) -> Colour {
+        unsafe { ffi::wxd_Image_GetPixel(self.ptr, x, y) }.into()
+    }
+
+    /// Sets the colour of a pixel. The alpha is ignored unless the image has alpha.
+    pub fn set_pixel(&mut self, x: i32, y: i32, colour: Colour) {
+        unsafe { ffi::wxd_Image_SetPixel(self.ptr, x, y, colour.into()) }
+    }
+
+    /// Returns a copy resized to the given size
+    pub fn scale(&self, width: i32, height: i32, quality: ImageResizeQuality) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Scale(self.ptr, width, height, quality.to_raw()) })
+    }
+
+    /// Resizes the image in place
+    pub fn rescale(&mut self, width: i32, height: i32, quality: ImageResizeQuality) {
+        unsafe { ffi::wxd_Image_Rescale(self.ptr, width, height, quality.to_raw()) }
+    }
+
+    /// Returns a copy rotated by 90 degrees
+    pub fn rotate90(&self, clockwise: bool) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Rotate90(self.ptr, clockwise) })
+    }
+
+    /// Returns a copy rotated by 180 degrees
+    pub fn rotate180(&self) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Rotate180(self.ptr) })
+    }
+
+    /// Returns a copy rotated counterclockwise by `angle` radians around its
+    /// centre, enlarged to hold the whole rotated image. Uncovered areas are
+    /// transparent if the image has alpha.
+    pub fn rotate(&self, angle: f64, interpolating: bool) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Rotate(self.ptr, angle, interpolating) })
+    }
+
+    /// Returns a mirrored copy, flipped left to right if `horizontally` is
+    /// true and top to bottom otherwise
+    pub fn mirror(&self, horizontally: bool) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_Mirror(self.ptr, horizontally) })
+    }
+
+    /// Returns a copy of part of the image, or `None` if the rectangle doesn't
+    /// lie inside the image
+    pub fn get_sub_image(&self, rect: Rect) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_GetSubImage(self.ptr, rect.into()) })
+    }
+
+    /// Returns a greyscale copy
+    pub fn convert_to_greyscale(&self) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_ConvertToGreyscale(self.ptr) })
+    }
+
+    /// Returns a copy looking like a disabled icon, greyed out and blended
+    /// towards `brightness` (255 for a light background)
+    pub fn convert_to_disabled(&self, brightness: u8) -> Option<Image> {
+        Self::from_ptr(unsafe { ffi::wxd_Image_ConvertToDisabled(self.ptr, brightness) })
+    }
+
+    /// Copies `other` onto this image with its top left corner at (x, y)
+    pub fn paste(&mut self, other: &Image, x: i32, y: i32) {
+        unsafe { ffi::wxd_Image_Paste(self.ptr, other.ptr, x, y) }
+    }
+
+    /// Returns true if the image has an alpha channel
+    pub fn has_alpha(&self) -> bool {
+        unsafe { ffi::wxd_Image_HasAlpha(self.ptr) }
+    }
+
+    /// Adds an alpha channel, converting the mask to it if there is one.
+    /// Does nothing if the image already has alpha.
+    pub fn init_alpha(&mut self) {
+        unsafe { ffi::wxd_Image_InitAlpha(self.ptr) }
+    }
+
+    /// Removes the alpha channel
+    pub fn clear_alpha(&mut self) {
+        unsafe { ffi::wxd_Image_ClearAlpha(self.ptr) }
+    }
+
+    /// Gets the alpha of a pixel, 255 if the image has no alpha
+    pub fn get_alpha(&self, x: i32, y: i32) -> u8 {
+        unsafe { ffi::wxd_Image_GetAlpha(self.ptr, x, y) }
+    }
+
+    /// Sets the alpha of a pixel, adding an alpha channel if needed
+    pub fn set_alpha(&mut self, x: i32, y: i32, alpha: u8) {
+        unsafe { ffi::wxd_Image_SetAlpha(self.ptr, x, y, alpha) }
+    }
+
+    /// Returns true if pixels of the mask colour are transparent
+    pub fn has_mask(&self) -> bool {
+        unsafe { ffi::wxd_Image_HasMask(self.ptr) }
+    }
+
+    /// Enables or disables the mask
+    pub fn set_mask(&mut self, has_mask: bool) {
+        unsafe { ffi::wxd_Image_SetMask(self.ptr, has_mask) }
+    }
+
+    /// Makes pixels of the given colour transparent
+    pub fn set_mask_colour(&mut self, colour: Colour) {
+        unsafe { ffi::wxd_Image_SetMaskColour(self.ptr, colour.into()) }
+    }
+
+    /// Gets the colour of transparent pixels
+    pub fn get_mask_colour(&self) -> Colour {
+        unsafe { ffi::wxd_Image_GetMaskColour(self.ptr) }.into()
+    }
+
+    /// Replaces the alpha channel with a mask of the pixels whose alpha is
+    /// below `threshold`. Returns false if the image has no alpha.
+    pub fn convert_alpha_to_mask(&mut self, threshold: u8) -> bool {
+        unsafe { ffi::wxd_Image_ConvertAlphaToMask(self.ptr, threshold) }
+    }
+}
+
+impl Clone for Image {
+    fn clone(&self) -> Self {
+        let ptr = unsafe { ffi::wxd_Image_Clone(self.ptr) };
+        if ptr.is_null() {
+            panic!("Failed to clone wxImage: wxd_Image_Clone returned null");
+        }
+        Self { ptr }
+    }
+}
+
+impl std::fmt::Debug for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Image")
+            .field("width", &self.get_width())
+            .field("height", &self.get_height())
+            .field("has_alpha", &self.has_alpha())
+            .finish()
+    }
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_Image_Destroy(self.ptr) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Image, ImageType};
+    use crate::color::Colour;
+    use crate::geometry::{Rect, Size};
+
+    /// A single row of the given colours
+    fn row(colours: &[Colour]) -> Image {
+        let mut image = Image::new(colours.len() as i32, 1).unwrap();
+        for (x, colour) in colours.iter().enumerate() {
+            image.set_pixel(x as i32, 0, *colour);
+        }
+        image
+    }
+
+    #[test]
+    fn png_bytes_round_trip() {
+        // Partial alpha keeps the PNG loader from turning alpha into a mask
+        let rgba = [
+            255, 0, 0, 255, 0, 255, 0, 128, //
+            0, 0, 255, 64, 10, 20, 30, 200,
+        ];
+        let image = Image::from_rgba(&rgba, 2, 2).unwrap();
+        let png = image.to_bytes(ImageType::Png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+
+        let decoded = Image::from_bytes(&png).unwrap();
+        assert_eq!(decoded.get_size(), Size::new(2, 2));
+        assert_eq!(decoded.get_type(), ImageType::Png);
+        assert!(decoded.has_alpha());
+        assert_eq!(decoded.get_rgba_data(), rgba);
+    }
+
+    #[test]
+    fn sub_image_must_lie_inside() {
+        let image = row(&[Colour::RED, Colour::GREEN, Colour::BLUE]);
+
+        let sub = image.get_sub_image(Rect::new(1, 0, 2, 1)).unwrap();
+        assert_eq!(sub.get_size(), Size::new(2, 1));
+        assert_eq!(sub.get_pixel(0, 0), Colour::GREEN);
+        assert_eq!(sub.get_pixel(1, 0), Colour::BLUE);
+        assert!(image.get_sub_image(Rect::new(0, 0, 3, 1)).is_some());
+
+        assert!(image.get_sub_image(Rect::new(2, 0, 2, 1)).is_none());
+        assert!(image.get_sub_image(Rect::new(-1, 0, 2, 1)).is_none());
+        assert!(image.get_sub_image(Rect::new(0, 0, 1, 2)).is_none());
+        assert!(image.get_sub_image(Rect::new(0, 0, 0, 1)).is_none());
+    }
+
+    #[test]
+    fn mirror_and_rotate90() {
+        let image = row(&[Colour::RED, Colour::BLUE]);
+
+        let mirrored = image.mirror(true).unwrap();
+        assert_eq!(mirrored.get_pixel(0, 0), Colour::BLUE);
+        assert_eq!(mirrored.get_pixel(1, 0), Colour::RED);
+        let flipped = image.mirror(false).unwrap();
+        assert_eq!(flipped.get_pixel(0, 0), Colour::RED);
+        assert_eq!(flipped.get_pixel(1, 0), Colour::BLUE);
+
+        let clockwise = image.rotate90(true).unwrap();
+        assert_eq!(clockwise.get_size(), Size::new(1, 2));
+        assert_eq!(clockwise.get_pixel(0, 0), Colour::RED);
+        assert_eq!(clockwise.get_pixel(0, 1), Colour::BLUE);
+        let counterclockwise = image.rotate90(false).unwrap();
+        assert_eq!(counterclockwise.get_size(), Size::new(1, 2));
+        assert_eq!(counterclockwise.get_pixel(0, 0), Colour::BLUE);
+        assert_eq!(counterclockwise.get_pixel(0, 1), Colour::RED);
+
+        // The original is unchanged
+        assert_eq!(image.get_pixel(0, 0), Colour::RED);
+    }
+}
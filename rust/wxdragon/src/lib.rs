@@ -3,14 +3,17 @@ pub use wxdragon_sys as ffi;
 
 #[macro_use]
 pub mod macros;
+pub mod accelerator;
 pub mod app;
 pub mod appearance;
 pub mod appprogress;
 pub mod art_provider;
+pub mod binding;
 pub mod bitmap;
 pub mod bitmap_bundle;
 pub mod clipboard;
 pub mod color;
+pub mod config;
 pub mod cursor;
 pub mod data_object;
 pub mod datetime;
@@ -18,18 +21,29 @@ pub mod dc;
 pub mod dialogs;
 pub mod dnd;
 pub mod event;
+pub mod executor;
 pub mod font;
 pub mod font_data;
 pub mod geometry;
+pub mod i18n;
 pub mod id;
+pub mod image;
+#[cfg(feature = "log")]
+pub mod logging;
 pub mod menus;
+pub mod persistence;
 pub mod prelude;
+pub mod printing;
+pub mod process;
 pub mod scrollable;
+pub mod single_instance;
 pub mod sizers;
+pub mod standard_paths;
 pub mod sysopt;
 pub mod timer;
 pub mod types;
 pub mod utils;
+pub mod validator;
 pub mod widgets;
 pub mod window;
 #[cfg(feature = "xrc")]
@@ -38,6 +52,8 @@ pub mod xrc;
 // Re-export the prelude for convenience - users should use this
 pub use prelude::*;
 
+pub use executor::spawn_local;
+
 // Re-export procedural macros from wxdragon-macros
 #[cfg(feature = "xrc")]
 pub use wxdragon_macros::include_xrc;
@@ -60,4 +76,6 @@ pub use widgets::dataview::{
 pub use dc::{BrushStyle, DeviceContext, PenStyle};
 
 // Re-export window functionality including downcasting
-pub use window::{BackgroundStyle, ExtraWindowStyle, Window, WxWidget, WxWidgetDowncast};
+pub use window::{
+    BackgroundStyle, Descendants, ExtraWindowStyle, Window, WxWidget, WxWidgetDowncast,
+};
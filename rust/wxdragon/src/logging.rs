@@ -0,0 +1,288 @@
+//! Bridges between wxWidgets logging and the [`log`] crate (requires the `log` feature).
+//!
+//! wxWidgets reports problems through `wxLog`, which by default shows errors and
+//! warnings in message boxes. [`forward_wx_log`] sends those messages to the
+//! `log` crate instead, under the target `"wx"` (or the wxWidgets component,
+//! such as `"wx/net"`), so they end up wherever the application's logger writes.
+//!
+//! In the other direction, [`WxLogger`] is a `log` implementation that shows
+//! records in the wxWidgets log (for example a [`LogWindow`]) and/or a frame's
+//! status bar. Records that came from wxWidgets are not sent back to it.
+//! [`set_wx_log_level`] limits what wxWidgets logs in the first place.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::logging::{LogWindow, WxLogger};
+//! use wxdragon::prelude::*;
+//!
+//! let _ = wxdragon::main(|_| {
+//!     let frame = Frame::builder().with_title("App").build();
+//!     frame.create_status_bar(1, 0, -1, "statusBar");
+//!
+//!     LogWindow::new(Some(&frame), "Log", false, false);
+//!     WxLogger::new()
+//!         .with_level(log::LevelFilter::Debug)
+//!         .with_status_bar(&frame)
+//!         .init()
+//!         .unwrap();
+//!
+//!     log::info!("Ready");
+//!     frame.show(true);
+//! });
+//! ```
+
+use crate::widgets::Frame;
+use crate::window::{FromWindowWithClassName, WxWidget};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+use std::sync::Mutex;
+use wxdragon_sys as ffi;
+
+// wxLogLevel values
+const WX_LOG_ERROR: c_int = 1;
+const WX_LOG_WARNING: c_int = 2;
+const WX_LOG_MESSAGE: c_int = 3;
+const WX_LOG_STATUS: c_int = 4;
+const WX_LOG_INFO: c_int = 5;
+const WX_LOG_DEBUG: c_int = 6;
+const WX_LOG_TRACE: c_int = 7;
+const WX_LOG_MAX: c_int = 10000;
+
+// The wxLogVerbose setting before forward_wx_log turned it on
+static SAVED_VERBOSE: Mutex<Option<bool>> = Mutex::new(None);
+
+fn level_from_wx(level: c_int) -> Level {
+    match level {
+        ..=WX_LOG_ERROR => Level::Error,
+        WX_LOG_WARNING => Level::Warn,
+        WX_LOG_MESSAGE | WX_LOG_STATUS | WX_LOG_INFO => Level::Info,
+        WX_LOG_DEBUG => Level::Debug,
+        _ => Level::Trace,
+    }
+}
+
+fn level_to_wx(level: Level) -> c_int {
+    match level {
+        Level::Error => WX_LOG_ERROR,
+        Level::Warn => WX_LOG_WARNING,
+        Level::Info => WX_LOG_INFO,
+        Level::Debug => WX_LOG_DEBUG,
+        Level::Trace => WX_LOG_TRACE,
+    }
+}
+
+// Targets used for records forwarded from wxWidgets
+fn is_wx_target(target: &str) -> bool {
+    target == "wx" || target.starts_with("wx/")
+}
+
+/// Sends wxWidgets log messages to the `log` crate instead of the default
+/// target, so wxWidgets no longer shows message boxes for them.
+///
+/// If a [`LogWindow`] is the active target, messages keep being shown in it
+/// as well.
+pub fn forward_wx_log() {
+    let mut saved = SAVED_VERBOSE.lock().unwrap_or_else(|e| e.into_inner());
+    unsafe {
+        ffi::wxd_Log_SetCallbackTarget(Some(forward_wx_record));
+        if saved.is_none() {
+            *saved = Some(ffi::wxd_Log_GetVerbose());
+        }
+        // Filtering is left to the `log` crate
+        ffi::wxd_Log_SetVerbose(true);
+    }
+}
+
+/// Undoes [`forward_wx_log`], restoring the default wxWidgets log target, or
+/// the [`LogWindow`] that was active when it was called, and the previous
+/// verbosity.
+pub fn restore_wx_log() {
+    let saved = SAVED_VERBOSE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+    unsafe {
+        ffi::wxd_Log_SetCallbackTarget(None);
+        if let Some(verbose) = saved {
+            ffi::wxd_Log_SetVerbose(verbose);
+        }
+    }
+}
+
+/// Sets the most verbose level wxWidgets logs, whether to the `log` crate
+/// after [`forward_wx_log`] or to its own targets. Messages above it are
+/// dropped before reaching any target; [`LevelFilter::Off`] still lets fatal
+/// errors through.
+pub fn set_wx_log_level(level: LevelFilter) {
+    let wx_level = match level.to_level() {
+        None => 0,
+        Some(Level::Trace) => WX_LOG_MAX,
+        Some(level) => level_to_wx(level),
+    };
+    unsafe { ffi::wxd_Log_SetLogLevel(wx_level) }
+}
+
+unsafe extern "C" fn forward_wx_record(
+    level: c_int,
+    message: *const c_char,
+    component: *const c_char,
+    file: *const c_char,
+    line: c_int,
+) {
+    if message.is_null() {
+        return;
+    }
+    let result = crate::app::catch_handler_panic(|| {
+        let message = CStr::from_ptr(message).to_string_lossy();
+        let component = if component.is_null() {
+            Default::default()
+        } else {
+            CStr::from_ptr(component).to_string_lossy()
+        };
+        let file = (!file.is_null()).then(|| CStr::from_ptr(file).to_string_lossy());
+        let target: &str = if component.is_empty() {
+            "wx"
+        } else {
+            &component
+        };
+
+        log::logger().log(
+            &Record::builder()
+                .level(level_from_wx(level))
+                .target(target)
+                .file(file.as_deref())
+                .line(u32::try_from(line).ok())
+                .args(format_args!("{message}"))
+                .build(),
+        );
+    });
+    if let Err(payload) = result {
+        crate::app::handle_handler_panic(payload, None, None);
+    }
+}
+
+/// A `log` implementation that shows records in wxWidgets
+///
+/// By default records go to the wxWidgets log, i.e. to a [`LogWindow`] if one
+/// was created, or else to the default target (which shows message boxes for
+/// errors and warnings). Records can be logged from any thread.
+#[derive(Debug, Clone)]
+pub struct WxLogger {
+    level: LevelFilter,
+    to_wx_log: bool,
+    // Frame pointer; only dereferenced on the main thread, after checking the frame still exists
+    status_frame: Option<usize>,
+}
+
+impl Default for WxLogger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WxLogger {
+    /// Creates a logger for records at `Info` level and above
+    pub fn new() -> Self {
+        WxLogger {
+            level: LevelFilter::Info,
+            to_wx_log: true,
+            status_frame: None,
+        }
+    }
+
+    /// Sets the most verbose level that is shown
+    pub fn with_level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Also shows each record in the first field of the frame's status bar.
+    pub fn with_status_bar(mut self, frame: &Frame) -> Self {
+        self.status_frame = Some(frame.handle_ptr() as usize);
+        self
+    }
+
+    /// Sets whether records are passed to the wxWidgets log. Turn it off to
+    /// only use the status bar.
+    pub fn with_wx_log(mut self, enabled: bool) -> Self {
+        self.to_wx_log = enabled;
+        self
+    }
+
+    /// Installs the logger as the `log` crate's global logger and sets its max level.
+    pub fn init(self) -> Result<(), log::SetLoggerError> {
+        let level = self.level;
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(level);
+        Ok(())
+    }
+}
+
+impl Log for WxLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level && !is_wx_target(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        if self.to_wx_log {
+            let text = format!("{}: {}", record.target(), record.args());
+            let c_text = CString::new(text.replace('\0', "")).unwrap_or_default();
+            unsafe { ffi::wxd_Log_LogText(level_to_wx(record.level()), c_text.as_ptr()) };
+        }
+        if let Some(frame) = self.status_frame {
+            let text = record.args().to_string();
+            crate::app::call_after(Box::new(move || {
+                let c_text = CString::new(text.replace('\0', "")).unwrap_or_default();
+                unsafe {
+                    ffi::wxd_Log_SetFrameStatus(frame as *mut ffi::wxd_Frame_t, c_text.as_ptr())
+                };
+            }));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// A frame listing log messages, like `wxLogWindow`
+///
+/// Creating it makes it the active wxWidgets log target. It belongs to the log
+/// system, which destroys it only when the application exits, so the handle
+/// stays valid while the application runs; closing the frame only hides it.
+#[derive(Debug, Clone, Copy)]
+pub struct LogWindow {
+    ptr: *mut ffi::wxd_LogWindow_t,
+}
+
+impl LogWindow {
+    /// Creates the log window.
+    ///
+    /// With `pass_to_old`, messages are also passed to the previous target,
+    /// such as the one installed by [`forward_wx_log`].
+    pub fn new(parent: Option<&dyn WxWidget>, title: &str, show: bool, pass_to_old: bool) -> Self {
+        let parent_ptr = parent.map_or(std::ptr::null_mut(), |p| p.handle_ptr());
+        let c_title = CString::new(title).unwrap_or_default();
+        let ptr =
+            unsafe { ffi::wxd_LogWindow_Create(parent_ptr, c_title.as_ptr(), show, pass_to_old) };
+        LogWindow { ptr }
+    }
+
+    /// Shows or hides the window
+    pub fn show(&self, show: bool) {
+        unsafe { ffi::wxd_LogWindow_Show(self.ptr, show) }
+    }
+
+    /// Gets the frame the messages are shown in
+    pub fn frame(&self) -> Option<Frame> {
+        let frame_ptr = unsafe { ffi::wxd_LogWindow_GetFrame(self.ptr) };
+        if frame_ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Frame::from_ptr(frame_ptr as *mut ffi::wxd_Window_t) })
+        }
+    }
+}
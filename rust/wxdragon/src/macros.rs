@@ -96,6 +96,18 @@ macro_rules! widget_builder {
                     self
                 }
 
+                /// Sets the position in device-independent pixels, converted at the parent's DPI.
+                pub fn with_dip_pos(mut self, pos: Point) -> Self {
+                    self.pos = unsafe { $crate::geometry::DipValue::from_dip_for(pos, self.parent.handle_ptr()) };
+                    self
+                }
+
+                /// Sets the size in device-independent pixels, converted at the parent's DPI.
+                pub fn with_dip_size(mut self, size: Size) -> Self {
+                    self.size = unsafe { $crate::geometry::DipValue::from_dip_for(size, self.parent.handle_ptr()) };
+                    self
+                }
+
                 /// Sets the window style flags.
                 pub fn with_style(mut self, style: $style_type) -> Self {
                     self.style = style;
@@ -184,6 +196,7 @@ macro_rules! implement_widget_traits {
 /// - Clone trait (clones the target field; other fields must be Copy or have manual Clone)
 /// - MenuEvents trait (for context menus and menu handling)
 /// - WindowEvents trait (for standard window events)
+/// - UpdateUIEvents trait (for update UI handlers)
 ///
 /// # Parameters
 ///
@@ -248,6 +261,7 @@ macro_rules! implement_widget_traits_with_target {
         // Auto-implement common event traits that all Window-based widgets support
         impl $crate::event::MenuEvents for $widget_name {}
         impl $crate::event::WindowEvents for $widget_name {}
+        impl $crate::event::UpdateUIEvents for $widget_name {}
     };
 }
 
@@ -502,6 +516,12 @@ macro_rules! custom_widget {
                     self
                 }
 
+                /// Sets the widget size in device-independent pixels
+                pub fn with_dip_size(mut self, size: $crate::geometry::Size) -> Self {
+                    self.size = unsafe { $crate::geometry::DipValue::from_dip_for(size, self.parent.handle_ptr()) };
+                    self
+                }
+
                 $(
                     paste::paste! {
                         /// Sets the field value
@@ -1,7 +1,8 @@
 // wxdragon/src/menus/menuitem.rs
 //! wxMenuItem wrapper and related types
 
-use crate::event::{Event, EventType, WxEvtHandler};
+use crate::accelerator::AcceleratorEntry;
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
 use crate::window::{Window, WxWidget};
 use std::ffi::{CStr, CString};
 use wxdragon_sys as ffi;
@@ -86,14 +87,15 @@ impl MenuItem {
     }
 
     /// Binds a click event handler to this menu item.
-    /// This binds a menu event on the parent window for this item's ID.
-    pub fn on_click<F>(&self, handler: F)
+    /// This binds a menu event on the parent window for this item's ID,
+    /// so the returned binding must be passed to `unbind` on the parent window.
+    pub fn on_click<F>(&self, handler: F) -> EventBinding
     where
         F: FnMut(Event) + 'static,
     {
         // Use ID-specific binding for MENU events
         self.parent_window
-            .bind_with_id_internal(EventType::MENU, self.item_id, handler);
+            .bind_with_id_internal(EventType::MENU, self.item_id, handler)
     }
 
     /// Special XRC loading method for menu items.
@@ -149,6 +151,25 @@ impl MenuItem {
         }
     }
 
+    /// Gets the accelerator given after a tab in the item's label (`"&Save\tCtrl+S"`).
+    ///
+    /// Returns `None` if the label has no accelerator. The entry's ID is the item's ID.
+    pub fn get_accel(&self) -> Option<AcceleratorEntry> {
+        if self.ptr.is_null() {
+            return None;
+        }
+        let mut raw = ffi::wxd_AcceleratorEntry {
+            modifiers: 0,
+            key_code: 0,
+            id: 0,
+        };
+        if unsafe { ffi::wxd_MenuItem_GetAccel(self.ptr, &mut raw) } {
+            Some(AcceleratorEntry::from_raw(&raw))
+        } else {
+            None
+        }
+    }
+
     /// Enables or disables the menu item.
     pub fn enable(&self, enable: bool) {
         if self.ptr.is_null() {
@@ -212,3 +233,31 @@ impl WxEvtHandler for MenuItem {
 }
 
 // Note: No Drop impl here, as wxMenu takes ownership.
+
+#[cfg(test)]
+mod tests {
+    use super::ItemKind;
+    use crate::menus::Menu;
+    use crate::widgets::frame::Frame;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    #[ignore = "needs a display; run with --ignored --test-threads=1"]
+    fn accel_id_is_item_id() {
+        let accel_id = Rc::new(Cell::new(None));
+        let result = accel_id.clone();
+        crate::main(move |_| {
+            // The frame only keeps the main loop running until it's closed
+            let frame = Frame::builder().build();
+            let menu = Menu::builder().build();
+            let item = menu
+                .append(5001, "&Save\tCtrl+S", "", ItemKind::Normal)
+                .unwrap();
+            result.set(item.get_accel().map(|accel| accel.id));
+            frame.close(true);
+        })
+        .unwrap();
+        assert_eq!(accel_id.get(), Some(5001));
+    }
+}
@@ -0,0 +1,189 @@
+//! Saving and restoring UI state between runs.
+//!
+//! Registering a widget under a name restores the state saved for that name
+//! and saves the state again when the widget is destroyed. The following
+//! widgets are supported:
+//!
+//! * frames and dialogs: position, size and maximized/iconized state
+//! * notebooks, treebooks and other book controls: the selected page (and the
+//!   expanded nodes of a treebook)
+//! * [`SplitterWindow`](crate::widgets::SplitterWindow): the sash position.
+//!   Register it after splitting it, as the position is only applied to a split window.
+//! * [`ListCtrl`](crate::widgets::ListCtrl) in report mode: column widths, and
+//!   the column order on Windows
+//! * [`DataViewCtrl`](crate::widgets::dataview::DataViewCtrl): column widths,
+//!   visibility and sorting, and the column order on Windows
+//!
+//! Names must be unique among widgets of the same kind.
+//!
+//! State is kept in the global [`Config`](crate::config::Config) (by default the
+//! registry on Windows and a file in the home directory elsewhere) unless
+//! another store is chosen with [`use_config_file`] or [`set_store`] before
+//! registering widgets.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//! use wxdragon::persistence;
+//!
+//! let frame = Frame::builder()
+//!     .with_title("Viewer")
+//!     .with_persistent_name("main")
+//!     .build();
+//! let notebook = Notebook::builder(&frame).build();
+//! // ... add pages ...
+//! persistence::register(&notebook, "main_tabs");
+//! ```
+
+use crate::window::WxWidget;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_void};
+use std::path::Path;
+use wxdragon_sys as ffi;
+
+/// A place to keep saved UI state, for use with [`set_store`]
+///
+/// Keys look like `"/Persistent_Options/Window/main/x"`. Every value is passed
+/// as a string; numbers are in decimal and booleans are `"0"` or `"1"`.
+pub trait PersistenceStore {
+    /// Stores `value` under `key`. Returns false if it could not be stored.
+    fn save(&mut self, key: &str, value: &str) -> bool;
+
+    /// Gets the value stored under `key`
+    fn restore(&mut self, key: &str) -> Option<String>;
+}
+
+/// Registers a widget under `name` and restores its saved state.
+///
+/// The name also becomes the widget's window name. Registering an already
+/// registered widget replaces its previous registration.
+///
+/// Returns true if saved state was found and restored. Widgets of kinds that
+/// aren't supported are not registered; see [`is_registered`].
+pub fn register(widget: &dyn WxWidget, name: &str) -> bool {
+    let Ok(c_name) = CString::new(name) else {
+        return false;
+    };
+    let result =
+        unsafe { ffi::wxd_Persistence_RegisterAndRestore(widget.handle_ptr(), c_name.as_ptr()) };
+    result == 1
+}
+
+/// Saves the state of a registered widget now rather than when it is destroyed
+pub fn save(widget: &dyn WxWidget) {
+    unsafe { ffi::wxd_Persistence_Save(widget.handle_ptr()) }
+}
+
+/// Restores the saved state of a registered widget. Returns false if none was saved.
+pub fn restore(widget: &dyn WxWidget) -> bool {
+    unsafe { ffi::wxd_Persistence_Restore(widget.handle_ptr()) }
+}
+
+/// Stops managing a widget. Its current state is not saved.
+pub fn unregister(widget: &dyn WxWidget) {
+    unsafe { ffi::wxd_Persistence_Unregister(widget.handle_ptr()) }
+}
+
+/// Returns true if the widget is registered
+pub fn is_registered(widget: &dyn WxWidget) -> bool {
+    unsafe { ffi::wxd_Persistence_IsRegistered(widget.handle_ptr()) }
+}
+
+/// Keeps state in the global [`Config`](crate::config::Config). This is the default.
+pub fn use_default_config() {
+    unsafe { ffi::wxd_Persistence_UseDefaultConfig() }
+}
+
+/// Keeps state in an INI-style file at `path`, which is created when needed.
+pub fn use_config_file<P: AsRef<Path>>(path: P) {
+    let Ok(c_path) = CString::new(path.as_ref().to_string_lossy().as_bytes()) else {
+        return;
+    };
+    unsafe { ffi::wxd_Persistence_UseConfigFile(c_path.as_ptr()) }
+}
+
+/// Keeps state in a store of your own
+pub fn set_store<S: PersistenceStore + 'static>(store: S) {
+    let boxed: Box<Box<dyn PersistenceStore>> = Box::new(Box::new(store));
+    unsafe { ffi::wxd_Persistence_UseRustStore(Box::into_raw(boxed) as *mut c_void) }
+}
+
+unsafe fn store_from<'a>(store: *mut c_void) -> Option<&'a mut Box<dyn PersistenceStore>> {
+    (store as *mut Box<dyn PersistenceStore>).as_mut()
+}
+
+/// Function called by C++ to save a value to a Rust store.
+///
+/// # Safety
+/// `store` must come from [`set_store`] and `key` and `value` must be valid C strings.
+#[no_mangle]
+pub unsafe extern "C" fn rust_persistence_store_save(
+    store: *mut c_void,
+    key: *const c_char,
+    value: *const c_char,
+) -> bool {
+    let Some(store) = store_from(store) else {
+        return false;
+    };
+    if key.is_null() || value.is_null() {
+        return false;
+    }
+    let key = CStr::from_ptr(key).to_string_lossy();
+    let value = CStr::from_ptr(value).to_string_lossy();
+    let result = crate::app::catch_handler_panic(|| store.save(&key, &value));
+    result.unwrap_or_else(|payload| {
+        crate::app::handle_handler_panic(payload, None, None);
+        false
+    })
+}
+
+/// Function called by C++ to restore a value from a Rust store.
+///
+/// # Safety
+/// `store` must come from [`set_store`] and `key` must be a valid C string. The
+/// result must be freed with `rust_persistence_store_free_string`.
+#[no_mangle]
+pub unsafe extern "C" fn rust_persistence_store_restore(
+    store: *mut c_void,
+    key: *const c_char,
+) -> *mut c_char {
+    let Some(store) = store_from(store) else {
+        return std::ptr::null_mut();
+    };
+    if key.is_null() {
+        return std::ptr::null_mut();
+    }
+    let key = CStr::from_ptr(key).to_string_lossy();
+    let result = crate::app::catch_handler_panic(|| store.restore(&key));
+    let value = result.unwrap_or_else(|payload| {
+        crate::app::handle_handler_panic(payload, None, None);
+        None
+    });
+    match value {
+        Some(value) => CString::new(value).unwrap_or_default().into_raw(),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Function called by C++ to free a string returned by `rust_persistence_store_restore`.
+///
+/// # Safety
+/// `text` must have been returned by `rust_persistence_store_restore` and not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn rust_persistence_store_free_string(text: *mut c_char) {
+    if !text.is_null() {
+        drop(CString::from_raw(text));
+    }
+}
+
+/// Function called by C++ to drop a Rust store that is no longer used.
+///
+/// # Safety
+/// `store` must come from [`set_store`] and not have been dropped yet.
+#[no_mangle]
+pub unsafe extern "C" fn rust_persistence_store_drop(store: *mut c_void) {
+    if !store.is_null() {
+        drop(Box::from_raw(store as *mut Box<dyn PersistenceStore>));
+    }
+}
@@ -1,30 +1,46 @@
 // --- Core Types & Traits ---
-pub use crate::app::{call_after, get_app, main, set_appearance, set_top_window};
+pub use crate::accelerator::{AcceleratorEntry, AcceleratorTable};
+pub use crate::app::{
+    call_after, call_after_with_result, get_app, main, set_appearance, set_top_window,
+};
 pub use crate::appearance::{
     get_app as get_app_for_appearance, get_system_appearance, is_system_dark_mode, AppAppearance,
     Appearance, AppearanceResult, SystemAppearance,
 };
+pub use crate::binding::{
+    BindableControl, Binding, Converter, EnumConverter, FnConverter, NumberConverter, Property,
+};
 pub use crate::clipboard::{Clipboard, ClipboardLocker};
 pub use crate::color::{colours, Colour};
+pub use crate::config::Config;
 pub use crate::cursor::{
     begin_busy_cursor, end_busy_cursor, is_busy, set_cursor, BitmapType, BusyCursor, Cursor,
     StockCursor,
 };
 pub use crate::datetime::DateTime;
-pub use crate::event::{Event, EventType, IdleEvent, IdleMode, WindowEventData, WxEvtHandler};
+pub use crate::event::{
+    Event, EventBinding, EventSender, EventType, IdleEvent, IdleMode, UpdateUIEvent, UpdateUIMode,
+    WindowEventData, WxEvtHandler,
+};
+pub use crate::i18n::LocaleSetup;
+pub use crate::standard_paths::StandardPaths;
 // ADDED: Event category traits
 pub use crate::event::{
-    ButtonEvents, MenuEvents, ScrollEvents, TextEvents, TreeEvents, WindowEvents,
+    ButtonEvents, CharHookEvents, MenuEvents, ScrollEvents, TextEvents, TreeEvents, UpdateUIEvents,
+    WindowEvents,
 };
 // ADDED: Event Data Structs
 pub use crate::event::event_data::{CommandEventData, KeyEventData, MouseEventData};
 pub use crate::event::{IdleEventData, MenuEventData};
+pub use crate::event::{KeyCategory, KeyCode, KeyEvent, KeyModifiers};
+pub use crate::event::{MouseButton, MouseEvent, WheelAxis};
 pub use crate::geometry::{Point, Rect, Size, DEFAULT_POSITION, DEFAULT_SIZE};
 pub use crate::id::{Id, ID_ANY, ID_CANCEL, ID_HIGHEST, ID_NO, ID_OK, ID_YES};
 pub use crate::sizers::WxSizer;
 pub use crate::sysopt::SystemOptions;
 pub use crate::types::Style;
 pub use crate::utils::WxdArrayString;
+pub use crate::validator::{NumValidatorStyle, TextValidatorStyle, Validator};
 pub use crate::window::{
     BackgroundStyle, ExtraWindowStyle, Window, WindowStyle, WxWidget, WxWidgetDowncast,
 };
@@ -215,6 +231,7 @@ pub use crate::widgets::ItemKind as WidgetItemKind;
 pub use crate::art_provider::{ArtClient, ArtId, ArtProvider};
 pub use crate::bitmap::Bitmap;
 pub use crate::bitmap_bundle::BitmapBundle; // Added BitmapBundle
+pub use crate::image::{Image, ImageResizeQuality, ImageType};
 
 // --- Dialogs ---
 pub use crate::dialogs::colour_dialog::{ColourDialog, ColourDialogBuilder}; // Added Builder
@@ -245,8 +262,8 @@ pub use crate::dnd::{
 
 // --- Painting & DeviceContexts ---
 pub use crate::dc::{
-    AutoBufferedPaintDC, BackgroundMode, BrushStyle, ClientDC, DeviceContext, MemoryDC, PaintDC,
-    PenStyle, ScreenDC, WindowDC,
+    AutoBufferedPaintDC, BackgroundMode, Brush, BrushStyle, ClientDC, DeviceContext, GradientStops,
+    GraphicsContext, GraphicsPenInfo, MemoryDC, PaintDC, Pen, PenStyle, ScreenDC, WindowDC,
 };
 
 // --- Application & Misc ---
@@ -0,0 +1,942 @@
+//! Printing and print preview.
+//!
+//! Pages are drawn by implementing [`Printout`], whose
+//! [`on_print_page`](Printout::on_print_page) receives a [`PrintoutDC`] to draw
+//! on with the usual [`DeviceContext`] methods. A [`Printer`] sends the pages to
+//! a printer, and a [`PrintPreview`] shows them in a frame.
+//!
+//! Printer settings are kept in [`PrintData`], which [`PrintDialogData`] and
+//! [`PageSetupDialogData`] extend with a page range and margins. The data is
+//! shown to the user with [`PrintDialog`] and [`PageSetupDialog`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//! use wxdragon::printing::{PageInfo, Printer, PrintDialogData, Printout, PrintoutDC};
+//!
+//! struct Report {
+//!     lines: Vec<String>,
+//! }
+//!
+//! const LINES_PER_PAGE: usize = 50;
+//!
+//! impl Report {
+//!     fn page_count(&self) -> i32 {
+//!         self.lines.len().div_ceil(LINES_PER_PAGE).max(1) as i32
+//!     }
+//! }
+//!
+//! impl Printout for Report {
+//!     fn get_page_info(&mut self) -> PageInfo {
+//!         PageInfo::new(1, self.page_count())
+//!     }
+//!
+//!     fn has_page(&mut self, page: i32) -> bool {
+//!         (1..=self.page_count()).contains(&page)
+//!     }
+//!
+//!     fn on_print_page(&mut self, dc: &PrintoutDC, page: i32) -> bool {
+//!         // Draw in screen pixels, at the same size as on screen
+//!         dc.map_screen_size_to_page();
+//!         let start = (page as usize - 1) * LINES_PER_PAGE;
+//!         for (i, line) in self.lines.iter().skip(start).take(LINES_PER_PAGE).enumerate() {
+//!             dc.draw_text(line, 20, 20 + i as i32 * 16);
+//!         }
+//!         true
+//!     }
+//! }
+//!
+//! # fn print(frame: &Frame, lines: Vec<String>) {
+//! let printer = Printer::new(Some(&PrintDialogData::new()));
+//! let mut report = Report { lines };
+//! if let Err(err) = printer.print(Some(frame), &mut report, true) {
+//!     eprintln!("Printing failed: {err}");
+//! }
+//! # }
+//! ```
+
+use crate::dc::DeviceContext;
+use crate::geometry::{Point, Rect, Size};
+use crate::utils::take_string;
+use crate::window::WxWidget;
+use std::ffi::CString;
+use std::os::raw::{c_int, c_void};
+use std::path::Path;
+use wxdragon_sys as ffi;
+
+/// Page orientation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrintOrientation {
+    #[default]
+    Portrait,
+    Landscape,
+}
+
+/// Where the output of printing goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrintMode {
+    #[default]
+    None,
+    Preview,
+    /// To the file set with [`PrintData::set_filename`]
+    File,
+    Printer,
+}
+
+/// Why printing didn't complete
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintError {
+    /// The user cancelled printing
+    Cancelled,
+    /// Printing failed, for example because the printer couldn't be opened
+    Failed,
+}
+
+impl std::fmt::Display for PrintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PrintError::Cancelled => write!(f, "printing was cancelled"),
+            PrintError::Failed => write!(f, "printing failed"),
+        }
+    }
+}
+
+impl std::error::Error for PrintError {}
+
+/// The pages of a printout, returned by [`Printout::get_page_info`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageInfo {
+    /// The first page of the document
+    pub min_page: i32,
+    /// The last page of the document
+    pub max_page: i32,
+    /// The first page selected for printing
+    pub from_page: i32,
+    /// The last page selected for printing
+    pub to_page: i32,
+}
+
+impl PageInfo {
+    /// Pages `first` to `last`, all selected for printing
+    pub fn new(first: i32, last: i32) -> Self {
+        Self {
+            min_page: first,
+            max_page: last,
+            from_page: first,
+            to_page: last,
+        }
+    }
+}
+
+impl Default for PageInfo {
+    /// The wxWidgets default: a single page selected out of up to 32000
+    fn default() -> Self {
+        Self {
+            min_page: 1,
+            max_page: 32000,
+            from_page: 1,
+            to_page: 1,
+        }
+    }
+}
+
+/// A document to print or preview, drawn one page at a time.
+///
+/// Page numbers start at 1.
+pub trait Printout {
+    /// Draws a page. Returning false cancels printing.
+    fn on_print_page(&mut self, dc: &PrintoutDC, page: i32) -> bool;
+
+    /// Returns true if the document has the given page. Only page 1 by default.
+    fn has_page(&mut self, page: i32) -> bool {
+        page == 1
+    }
+
+    /// Returns the range of pages in the document and the range to print
+    fn get_page_info(&mut self) -> PageInfo {
+        PageInfo::default()
+    }
+
+    /// Called before printing starts, once the DC is available, for example to
+    /// paginate the document according to the page size
+    fn on_prepare_printing(&mut self, _dc: &PrintoutDC) {}
+
+    /// The title shown in the print queue and the preview
+    fn title(&self) -> String {
+        "Printout".to_string()
+    }
+}
+
+impl<P: Printout + ?Sized> Printout for &mut P {
+    fn on_print_page(&mut self, dc: &PrintoutDC, page: i32) -> bool {
+        (**self).on_print_page(dc, page)
+    }
+
+    fn has_page(&mut self, page: i32) -> bool {
+        (**self).has_page(page)
+    }
+
+    fn get_page_info(&mut self) -> PageInfo {
+        (**self).get_page_info()
+    }
+
+    fn on_prepare_printing(&mut self, dc: &PrintoutDC) {
+        (**self).on_prepare_printing(dc)
+    }
+
+    fn title(&self) -> String {
+        (**self).title()
+    }
+}
+
+impl<P: Printout + ?Sized> Printout for Box<P> {
+    fn on_print_page(&mut self, dc: &PrintoutDC, page: i32) -> bool {
+        (**self).on_print_page(dc, page)
+    }
+
+    fn has_page(&mut self, page: i32) -> bool {
+        (**self).has_page(page)
+    }
+
+    fn get_page_info(&mut self) -> PageInfo {
+        (**self).get_page_info()
+    }
+
+    fn on_prepare_printing(&mut self, dc: &PrintoutDC) {
+        (**self).on_prepare_printing(dc)
+    }
+
+    fn title(&self) -> String {
+        (**self).title()
+    }
+}
+
+// The handler behind a wxd_Printout_t
+type PrintoutHandler = Box<dyn Printout>;
+
+// The printout may borrow data; the caller must destroy it before that data goes away
+fn create_printout<'a>(printout: Box<dyn Printout + 'a>) -> *mut ffi::wxd_Printout_t {
+    let title = CString::new(printout.title()).unwrap_or_default();
+    let handler = Box::into_raw(Box::new(printout)) as *mut c_void;
+    unsafe { ffi::wxd_Printout_Create(handler, title.as_ptr()) }
+}
+
+/// The device context of a page being printed or previewed.
+///
+/// Besides drawing, it scales the page so that the drawing fits the paper or
+/// keeps its on-screen size. The scaling functions set the DC's user scale and
+/// logical origin, so call one of them before drawing.
+pub struct PrintoutDC {
+    printout: *mut ffi::wxd_Printout_t,
+}
+
+impl PrintoutDC {
+    /// Returns true when drawing for a preview rather than a printer
+    pub fn is_preview(&self) -> bool {
+        unsafe { ffi::wxd_Printout_IsPreview(self.printout) }
+    }
+
+    /// Scales a drawing of the given size to fit the whole paper, centred
+    pub fn fit_this_size_to_paper(&self, size: Size) {
+        unsafe { ffi::wxd_Printout_FitThisSizeToPaper(self.printout, size.into()) }
+    }
+
+    /// Scales a drawing of the given size to fit the printable area of the page, centred
+    pub fn fit_this_size_to_page(&self, size: Size) {
+        unsafe { ffi::wxd_Printout_FitThisSizeToPage(self.printout, size.into()) }
+    }
+
+    /// Scales a drawing of the given size to fit inside the page margins, centred
+    pub fn fit_this_size_to_page_margins(&self, size: Size, page_setup: &PageSetupDialogData) {
+        unsafe {
+            ffi::wxd_Printout_FitThisSizeToPageMargins(self.printout, size.into(), page_setup.ptr)
+        }
+    }
+
+    /// Scales the page so that screen pixels have their on-screen size,
+    /// with the origin at the top left corner of the paper
+    pub fn map_screen_size_to_paper(&self) {
+        unsafe { ffi::wxd_Printout_MapScreenSizeToPaper(self.printout) }
+    }
+
+    /// Scales the page so that screen pixels have their on-screen size,
+    /// with the origin at the top left corner of the printable area
+    pub fn map_screen_size_to_page(&self) {
+        unsafe { ffi::wxd_Printout_MapScreenSizeToPage(self.printout) }
+    }
+
+    /// Scales the page so that screen pixels have their on-screen size,
+    /// with the origin at the top left margin
+    pub fn map_screen_size_to_page_margins(&self, page_setup: &PageSetupDialogData) {
+        unsafe { ffi::wxd_Printout_MapScreenSizeToPageMargins(self.printout, page_setup.ptr) }
+    }
+
+    /// Scales the page so that one unit is one printer pixel
+    pub fn map_screen_size_to_device(&self) {
+        unsafe { ffi::wxd_Printout_MapScreenSizeToDevice(self.printout) }
+    }
+
+    /// Gets the whole paper in the current logical coordinates
+    pub fn get_logical_paper_rect(&self) -> Rect {
+        unsafe { ffi::wxd_Printout_GetLogicalPaperRect(self.printout) }.into()
+    }
+
+    /// Gets the printable area of the page in the current logical coordinates
+    pub fn get_logical_page_rect(&self) -> Rect {
+        unsafe { ffi::wxd_Printout_GetLogicalPageRect(self.printout) }.into()
+    }
+
+    /// Gets the area inside the page margins in the current logical coordinates
+    pub fn get_logical_page_margins_rect(&self, page_setup: &PageSetupDialogData) -> Rect {
+        unsafe { ffi::wxd_Printout_GetLogicalPageMarginsRect(self.printout, page_setup.ptr) }.into()
+    }
+
+    /// Moves the logical origin by the given amount in logical units
+    pub fn offset_logical_origin(&self, dx: i32, dy: i32) {
+        unsafe { ffi::wxd_Printout_OffsetLogicalOrigin(self.printout, dx, dy) }
+    }
+
+    /// Gets the size of the page in printer pixels
+    pub fn get_page_size_pixels(&self) -> Size {
+        unsafe { ffi::wxd_Printout_GetPageSizePixels(self.printout) }.into()
+    }
+
+    /// Gets the size of the page in millimetres
+    pub fn get_page_size_mm(&self) -> Size {
+        unsafe { ffi::wxd_Printout_GetPageSizeMM(self.printout) }.into()
+    }
+
+    /// Gets the horizontal and vertical resolution of the printer in pixels per inch
+    pub fn get_ppi_printer(&self) -> Size {
+        unsafe { ffi::wxd_Printout_GetPPIPrinter(self.printout) }.into()
+    }
+
+    /// Gets the horizontal and vertical resolution of the screen in pixels per inch
+    pub fn get_ppi_screen(&self) -> Size {
+        unsafe { ffi::wxd_Printout_GetPPIScreen(self.printout) }.into()
+    }
+}
+
+impl DeviceContext for PrintoutDC {
+    fn dc_ptr(&self) -> *mut wxdragon_sys::wxd_DC_t {
+        unsafe { ffi::wxd_Printout_GetDC(self.printout) }
+    }
+}
+
+/// Printer settings such as orientation, copies and the output file
+pub struct PrintData {
+    ptr: *mut ffi::wxd_PrintData_t,
+}
+
+impl PrintData {
+    /// Creates settings for the default printer
+    pub fn new() -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_PrintData_Create() },
+        }
+    }
+
+    /// Returns true if the settings refer to a usable printer
+    pub fn is_ok(&self) -> bool {
+        unsafe { ffi::wxd_PrintData_IsOk(self.ptr) }
+    }
+
+    /// Sets the page orientation
+    pub fn set_orientation(&mut self, orientation: PrintOrientation) {
+        let raw = match orientation {
+            PrintOrientation::Portrait => ffi::wxd_PrintOrientation_WXD_PRINT_PORTRAIT,
+            PrintOrientation::Landscape => ffi::wxd_PrintOrientation_WXD_PRINT_LANDSCAPE,
+        };
+        unsafe { ffi::wxd_PrintData_SetOrientation(self.ptr, raw) }
+    }
+
+    /// Gets the page orientation
+    pub fn get_orientation(&self) -> PrintOrientation {
+        match unsafe { ffi::wxd_PrintData_GetOrientation(self.ptr) } {
+            ffi::wxd_PrintOrientation_WXD_PRINT_LANDSCAPE => PrintOrientation::Landscape,
+            _ => PrintOrientation::Portrait,
+        }
+    }
+
+    /// Sets where the output goes
+    pub fn set_print_mode(&mut self, mode: PrintMode) {
+        let raw = match mode {
+            PrintMode::None => ffi::wxd_PrintMode_WXD_PRINT_MODE_NONE,
+            PrintMode::Preview => ffi::wxd_PrintMode_WXD_PRINT_MODE_PREVIEW,
+            PrintMode::File => ffi::wxd_PrintMode_WXD_PRINT_MODE_FILE,
+            PrintMode::Printer => ffi::wxd_PrintMode_WXD_PRINT_MODE_PRINTER,
+        };
+        unsafe { ffi::wxd_PrintData_SetPrintMode(self.ptr, raw) }
+    }
+
+    /// Gets where the output goes
+    pub fn get_print_mode(&self) -> PrintMode {
+        match unsafe { ffi::wxd_PrintData_GetPrintMode(self.ptr) } {
+            ffi::wxd_PrintMode_WXD_PRINT_MODE_PREVIEW => PrintMode::Preview,
+            ffi::wxd_PrintMode_WXD_PRINT_MODE_FILE => PrintMode::File,
+            ffi::wxd_PrintMode_WXD_PRINT_MODE_PRINTER => PrintMode::Printer,
+            _ => PrintMode::None,
+        }
+    }
+
+    /// Sets the file written in [`PrintMode::File`]
+    pub fn set_filename<P: AsRef<Path>>(&mut self, path: P) {
+        let Ok(filename) = CString::new(path.as_ref().to_string_lossy().as_bytes()) else {
+            return;
+        };
+        unsafe { ffi::wxd_PrintData_SetFilename(self.ptr, filename.as_ptr()) }
+    }
+
+    /// Gets the file written in [`PrintMode::File`]
+    pub fn get_filename(&self) -> String {
+        unsafe { take_string(ffi::wxd_PrintData_GetFilename(self.ptr)) }
+    }
+
+    /// Sets the number of copies
+    pub fn set_no_copies(&mut self, copies: i32) {
+        unsafe { ffi::wxd_PrintData_SetNoCopies(self.ptr, copies) }
+    }
+
+    /// Gets the number of copies
+    pub fn get_no_copies(&self) -> i32 {
+        unsafe { ffi::wxd_PrintData_GetNoCopies(self.ptr) }
+    }
+
+    /// Sets whether to print in colour rather than greyscale
+    pub fn set_colour(&mut self, colour: bool) {
+        unsafe { ffi::wxd_PrintData_SetColour(self.ptr, colour) }
+    }
+
+    /// Gets whether to print in colour rather than greyscale
+    pub fn get_colour(&self) -> bool {
+        unsafe { ffi::wxd_PrintData_GetColour(self.ptr) }
+    }
+}
+
+impl Default for PrintData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for PrintData {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_PrintData_Clone(self.ptr) },
+        }
+    }
+}
+
+impl Drop for PrintData {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_PrintData_Destroy(self.ptr) }
+    }
+}
+
+/// The settings of the print dialog: printer settings and the pages to print
+pub struct PrintDialogData {
+    ptr: *mut ffi::wxd_PrintDialogData_t,
+}
+
+impl PrintDialogData {
+    /// Creates settings for the default printer
+    pub fn new() -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_PrintDialogData_Create(std::ptr::null()) },
+        }
+    }
+
+    /// Creates settings for the given printer settings
+    pub fn from_print_data(data: &PrintData) -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_PrintDialogData_Create(data.ptr) },
+        }
+    }
+
+    /// Sets the first page to print
+    pub fn set_from_page(&mut self, page: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetFromPage(self.ptr, page) }
+    }
+
+    /// Gets the first page to print
+    pub fn get_from_page(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetFromPage(self.ptr) }
+    }
+
+    /// Sets the last page to print
+    pub fn set_to_page(&mut self, page: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetToPage(self.ptr, page) }
+    }
+
+    /// Gets the last page to print
+    pub fn get_to_page(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetToPage(self.ptr) }
+    }
+
+    /// Sets the lowest page number that can be chosen in the dialog
+    pub fn set_min_page(&mut self, page: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetMinPage(self.ptr, page) }
+    }
+
+    /// Gets the lowest page number that can be chosen in the dialog
+    pub fn get_min_page(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetMinPage(self.ptr) }
+    }
+
+    /// Sets the highest page number that can be chosen in the dialog
+    pub fn set_max_page(&mut self, page: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetMaxPage(self.ptr, page) }
+    }
+
+    /// Gets the highest page number that can be chosen in the dialog
+    pub fn get_max_page(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetMaxPage(self.ptr) }
+    }
+
+    /// Sets the number of copies
+    pub fn set_no_copies(&mut self, copies: i32) {
+        unsafe { ffi::wxd_PrintDialogData_SetNoCopies(self.ptr, copies) }
+    }
+
+    /// Gets the number of copies
+    pub fn get_no_copies(&self) -> i32 {
+        unsafe { ffi::wxd_PrintDialogData_GetNoCopies(self.ptr) }
+    }
+
+    /// Sets whether all pages are printed rather than a range
+    pub fn set_all_pages(&mut self, all_pages: bool) {
+        unsafe { ffi::wxd_PrintDialogData_SetAllPages(self.ptr, all_pages) }
+    }
+
+    /// Gets whether all pages are printed rather than a range
+    pub fn get_all_pages(&self) -> bool {
+        unsafe { ffi::wxd_PrintDialogData_GetAllPages(self.ptr) }
+    }
+
+    /// Sets whether only the selection is printed
+    pub fn set_selection(&mut self, selection: bool) {
+        unsafe { ffi::wxd_PrintDialogData_SetSelection(self.ptr, selection) }
+    }
+
+    /// Gets whether only the selection is printed
+    pub fn get_selection(&self) -> bool {
+        unsafe { ffi::wxd_PrintDialogData_GetSelection(self.ptr) }
+    }
+
+    /// Enables the choice to print only the selection in the dialog
+    pub fn enable_selection(&mut self, enable: bool) {
+        unsafe { ffi::wxd_PrintDialogData_EnableSelection(self.ptr, enable) }
+    }
+
+    /// Enables choosing a page range in the dialog
+    pub fn enable_page_numbers(&mut self, enable: bool) {
+        unsafe { ffi::wxd_PrintDialogData_EnablePageNumbers(self.ptr, enable) }
+    }
+
+    /// Enables the "print to file" choice in the dialog
+    pub fn enable_print_to_file(&mut self, enable: bool) {
+        unsafe { ffi::wxd_PrintDialogData_EnablePrintToFile(self.ptr, enable) }
+    }
+
+    /// Gets a copy of the printer settings
+    pub fn get_print_data(&self) -> PrintData {
+        PrintData {
+            ptr: unsafe { ffi::wxd_PrintDialogData_GetPrintData(self.ptr) },
+        }
+    }
+
+    /// Sets the printer settings
+    pub fn set_print_data(&mut self, data: &PrintData) {
+        unsafe { ffi::wxd_PrintDialogData_SetPrintData(self.ptr, data.ptr) }
+    }
+}
+
+impl Default for PrintDialogData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for PrintDialogData {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_PrintDialogData_Clone(self.ptr) },
+        }
+    }
+}
+
+impl Drop for PrintDialogData {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_PrintDialogData_Destroy(self.ptr) }
+    }
+}
+
+/// The settings of the page setup dialog: printer settings and page margins.
+///
+/// Margins and paper sizes are in millimetres.
+pub struct PageSetupDialogData {
+    ptr: *mut ffi::wxd_PageSetupDialogData_t,
+}
+
+impl PageSetupDialogData {
+    /// Creates settings for the default printer
+    pub fn new() -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_PageSetupDialogData_Create(std::ptr::null()) },
+        }
+    }
+
+    /// Creates settings for the given printer settings
+    pub fn from_print_data(data: &PrintData) -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_PageSetupDialogData_Create(data.ptr) },
+        }
+    }
+
+    /// Sets the left and top margins
+    pub fn set_margin_top_left(&mut self, margin: Point) {
+        unsafe { ffi::wxd_PageSetupDialogData_SetMarginTopLeft(self.ptr, margin.into()) }
+    }
+
+    /// Gets the left and top margins
+    pub fn get_margin_top_left(&self) -> Point {
+        unsafe { ffi::wxd_PageSetupDialogData_GetMarginTopLeft(self.ptr) }.into()
+    }
+
+    /// Sets the right and bottom margins
+    pub fn set_margin_bottom_right(&mut self, margin: Point) {
+        unsafe { ffi::wxd_PageSetupDialogData_SetMarginBottomRight(self.ptr, margin.into()) }
+    }
+
+    /// Gets the right and bottom margins
+    pub fn get_margin_bottom_right(&self) -> Point {
+        unsafe { ffi::wxd_PageSetupDialogData_GetMarginBottomRight(self.ptr) }.into()
+    }
+
+    /// Gets the paper size
+    pub fn get_paper_size(&self) -> Size {
+        unsafe { ffi::wxd_PageSetupDialogData_GetPaperSize(self.ptr) }.into()
+    }
+
+    /// Gets a copy of the printer settings
+    pub fn get_print_data(&self) -> PrintData {
+        PrintData {
+            ptr: unsafe { ffi::wxd_PageSetupDialogData_GetPrintData(self.ptr) },
+        }
+    }
+
+    /// Sets the printer settings
+    pub fn set_print_data(&mut self, data: &PrintData) {
+        unsafe { ffi::wxd_PageSetupDialogData_SetPrintData(self.ptr, data.ptr) }
+    }
+}
+
+impl Default for PageSetupDialogData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for PageSetupDialogData {
+    fn clone(&self) -> Self {
+        Self {
+            ptr: unsafe { ffi::wxd_PageSetupDialogData_Clone(self.ptr) },
+        }
+    }
+}
+
+impl Drop for PageSetupDialogData {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_PageSetupDialogData_Destroy(self.ptr) }
+    }
+}
+
+/// The standard print dialog
+pub struct PrintDialog;
+
+impl PrintDialog {
+    /// Shows the dialog with the given settings.
+    ///
+    /// Returns true and updates `data` if the user confirmed.
+    pub fn show_modal(parent: Option<&dyn WxWidget>, data: &mut PrintDialogData) -> bool {
+        let parent_ptr = parent.map_or(std::ptr::null_mut(), |p| p.handle_ptr());
+        unsafe { ffi::wxd_PrintDialog_ShowModal(parent_ptr, data.ptr) }
+    }
+}
+
+/// The standard page setup dialog
+pub struct PageSetupDialog;
+
+impl PageSetupDialog {
+    /// Shows the dialog with the given settings.
+    ///
+    /// Returns true and updates `data` if the user confirmed.
+    pub fn show_modal(parent: Option<&dyn WxWidget>, data: &mut PageSetupDialogData) -> bool {
+        let parent_ptr = parent.map_or(std::ptr::null_mut(), |p| p.handle_ptr());
+        unsafe { ffi::wxd_PageSetupDialog_ShowModal(parent_ptr, data.ptr) }
+    }
+}
+
+/// Prints a [`Printout`]
+pub struct Printer {
+    ptr: *mut ffi::wxd_Printer_t,
+}
+
+impl Printer {
+    /// Creates a printer with the given settings, or the default ones
+    pub fn new(data: Option<&PrintDialogData>) -> Self {
+        let data_ptr = data.map_or(std::ptr::null(), |d| d.ptr as *const _);
+        Self {
+            ptr: unsafe { ffi::wxd_Printer_Create(data_ptr) },
+        }
+    }
+
+    /// Prints the printout, first showing the print dialog if `prompt` is true.
+    ///
+    /// Blocks until all pages have been printed.
+    pub fn print<P: Printout + ?Sized>(
+        &self,
+        parent: Option<&dyn WxWidget>,
+        printout: &mut P,
+        prompt: bool,
+    ) -> Result<(), PrintError> {
+        let parent_ptr = parent.map_or(std::ptr::null_mut(), |p| p.handle_ptr());
+        // Printing is synchronous, so the borrowed printout outlives the wxPrintout
+        let printout_ptr = create_printout(Box::new(printout));
+        let printed = unsafe { ffi::wxd_Printer_Print(self.ptr, parent_ptr, printout_ptr, prompt) };
+        unsafe { ffi::wxd_Printout_Destroy(printout_ptr) };
+
+        if printed {
+            return Ok(());
+        }
+        match unsafe { ffi::wxd_Printer_GetLastError() } {
+            ffi::wxd_PrinterError_WXD_PRINTER_CANCELLED => Err(PrintError::Cancelled),
+            _ => Err(PrintError::Failed),
+        }
+    }
+
+    /// Gets the settings, including those chosen in the print dialog
+    pub fn get_print_dialog_data(&self) -> PrintDialogData {
+        PrintDialogData {
+            ptr: unsafe { ffi::wxd_Printer_GetPrintDialogData(self.ptr) },
+        }
+    }
+}
+
+impl Drop for Printer {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_Printer_Destroy(self.ptr) }
+    }
+}
+
+/// A frame showing the pages of a [`Printout`].
+///
+/// ```rust,no_run
+/// # use wxdragon::prelude::*;
+/// # use wxdragon::printing::{PrintPreview, Printout, PrintoutDC};
+/// # #[derive(Clone)]
+/// # struct Report;
+/// # impl Printout for Report {
+/// #     fn on_print_page(&mut self, _dc: &PrintoutDC, _page: i32) -> bool { true }
+/// # }
+/// # fn preview(frame: &Frame, report: Report) {
+/// // The preview needs a second printout to print from its toolbar
+/// let shown = PrintPreview::builder(report.clone())
+///     .with_printout_for_printing(report)
+///     .with_title("Report Preview")
+///     .show(Some(frame));
+/// # }
+/// ```
+pub struct PrintPreview;
+
+impl PrintPreview {
+    /// Creates a builder for a preview of the printout
+    pub fn builder<P: Printout + 'static>(printout: P) -> PrintPreviewBuilder {
+        PrintPreviewBuilder {
+            printout: Box::new(printout),
+            printout_for_printing: None,
+            data: None,
+            title: "Print Preview".to_string(),
+            size: Size::new(800, 600),
+        }
+    }
+}
+
+/// Builder for [`PrintPreview`]
+pub struct PrintPreviewBuilder {
+    printout: Box<dyn Printout>,
+    printout_for_printing: Option<Box<dyn Printout>>,
+    data: Option<PrintDialogData>,
+    title: String,
+    size: Size,
+}
+
+impl PrintPreviewBuilder {
+    /// Sets the printout used to print from the preview. Without one, the
+    /// preview can't print.
+    pub fn with_printout_for_printing<P: Printout + 'static>(mut self, printout: P) -> Self {
+        self.printout_for_printing = Some(Box::new(printout));
+        self
+    }
+
+    /// Sets the printer settings the pages are laid out for
+    pub fn with_print_data(mut self, data: &PrintDialogData) -> Self {
+        self.data = Some(data.clone());
+        self
+    }
+
+    /// Sets the title of the frame
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Sets the size of the frame (800x600 by default)
+    pub fn with_size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Shows the preview frame.
+    ///
+    /// Returns false if the preview couldn't be created, for example because
+    /// no printer is available.
+    pub fn show(self, parent: Option<&dyn WxWidget>) -> bool {
+        let parent_ptr = parent.map_or(std::ptr::null_mut(), |p| p.handle_ptr());
+        let title = CString::new(self.title).unwrap_or_default();
+        let data_ptr = self
+            .data
+            .as_ref()
+            .map_or(std::ptr::null(), |d| d.ptr as *const _);
+        let printout = create_printout(self.printout);
+        let printout_for_printing = self
+            .printout_for_printing
+            .map_or(std::ptr::null_mut(), create_printout);
+        // The preview frame takes ownership of both printouts
+        unsafe {
+            ffi::wxd_PrintPreview_Show(
+                printout,
+                printout_for_printing,
+                data_ptr,
+                parent_ptr,
+                title.as_ptr(),
+                self.size.into(),
+            )
+        }
+    }
+}
+
+/// Prints through wxWidgets' PostScript backend instead of GTK's.
+///
+/// With [`PrintMode::File`] and [`PrintData::set_filename`], this writes
+/// PostScript to a file without a print dialog or a physical printer, for
+/// example to test printing code, as `examples/rust/printing_test` does. Call it
+/// before creating a [`Printer`]. Does nothing on platforms other than Linux.
+pub fn use_postscript_backend() {
+    unsafe { ffi::wxd_Printing_UsePostScript() }
+}
+
+/// Function called by C++ before printing starts.
+///
+/// # Safety
+/// `handler` must come from a printout created by this module and `printout`
+/// must be the printout it belongs to.
+#[no_mangle]
+pub unsafe extern "C" fn rust_printout_on_prepare_printing(
+    handler: *mut c_void,
+    printout: *mut ffi::wxd_Printout_t,
+) {
+    let Some(handler) = (handler as *mut PrintoutHandler).as_mut() else {
+        return;
+    };
+    let dc = PrintoutDC { printout };
+    let result = crate::app::catch_handler_panic(|| handler.on_prepare_printing(&dc));
+    if let Err(payload) = result {
+        crate::app::handle_handler_panic(payload, None, None);
+    }
+}
+
+/// Function called by C++ to check whether a page exists.
+///
+/// # Safety
+/// `handler` must come from a printout created by this module.
+#[no_mangle]
+pub unsafe extern "C" fn rust_printout_has_page(handler: *mut c_void, page: c_int) -> bool {
+    let Some(handler) = (handler as *mut PrintoutHandler).as_mut() else {
+        return false;
+    };
+    let result = crate::app::catch_handler_panic(|| handler.has_page(page));
+    result.unwrap_or_else(|payload| {
+        crate::app::handle_handler_panic(payload, None, None);
+        false
+    })
+}
+
+/// Function called by C++ to get the pages of a printout.
+///
+/// # Safety
+/// `handler` must come from a printout created by this module and the page
+/// pointers must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn rust_printout_get_page_info(
+    handler: *mut c_void,
+    min_page: *mut c_int,
+    max_page: *mut c_int,
+    from_page: *mut c_int,
+    to_page: *mut c_int,
+) {
+    let Some(handler) = (handler as *mut PrintoutHandler).as_mut() else {
+        return;
+    };
+    let result = crate::app::catch_handler_panic(|| handler.get_page_info());
+    let info = result.unwrap_or_else(|payload| {
+        crate::app::handle_handler_panic(payload, None, None);
+        PageInfo::default()
+    });
+    for (ptr, value) in [
+        (min_page, info.min_page),
+        (max_page, info.max_page),
+        (from_page, info.from_page),
+        (to_page, info.to_page),
+    ] {
+        if !ptr.is_null() {
+            *ptr = value;
+        }
+    }
+}
+
+/// Function called by C++ to draw a page.
+///
+/// # Safety
+/// `handler` must come from a printout created by this module and `printout`
+/// must be the printout it belongs to.
+#[no_mangle]
+pub unsafe extern "C" fn rust_printout_on_print_page(
+    handler: *mut c_void,
+    printout: *mut ffi::wxd_Printout_t,
+    page: c_int,
+) -> bool {
+    let Some(handler) = (handler as *mut PrintoutHandler).as_mut() else {
+        return false;
+    };
+    let dc = PrintoutDC { printout };
+    let result = crate::app::catch_handler_panic(|| handler.on_print_page(&dc, page));
+    // A panic cancels printing
+    result.unwrap_or_else(|payload| {
+        crate::app::handle_handler_panic(payload, None, None);
+        false
+    })
+}
+
+/// Function called by C++ when a printout is destroyed.
+///
+/// # Safety
+/// `handler` must come from a printout created by this module and not have
+/// been dropped yet.
+#[no_mangle]
+pub unsafe extern "C" fn rust_printout_drop(handler: *mut c_void) {
+    if !handler.is_null() {
+        drop(Box::from_raw(handler as *mut PrintoutHandler));
+    }
+}
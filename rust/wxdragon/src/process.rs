@@ -0,0 +1,323 @@
+//! Running child processes without blocking the event loop.
+//!
+//! A [`Process`] is started with [`Process::builder`]. Its standard output and
+//! error are read as they arrive and passed line by line to callbacks on the
+//! main thread, followed by the exit code once it ends.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::prelude::*;
+//! use wxdragon::process::Process;
+//!
+//! # fn run(log: TextCtrl, status: StaticText) {
+//! let log_out = log.clone();
+//! let log_err = log.clone();
+//! let build = Process::builder("cargo")
+//!     .with_args(["build", "--release"])
+//!     .with_working_dir("/path/to/project")
+//!     .on_stdout(move |line| log_out.append_text(&format!("{line}\n")))
+//!     .on_stderr(move |line| log_err.append_text(&format!("{line}\n")))
+//!     .on_exit(move |code| status.set_label(&format!("Finished with exit code {code}")))
+//!     .spawn();
+//!
+//! if build.is_none() {
+//!     log.append_text("Could not start cargo\n");
+//! }
+//! # }
+//! ```
+
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::PathBuf;
+use wxdragon_sys as ffi;
+
+// wxSignal values
+const WX_SIGKILL: c_int = 9;
+const WX_SIGTERM: c_int = 15;
+
+/// The output streams of a process
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Default)]
+struct ProcessHandlers {
+    on_output: Option<Box<dyn FnMut(OutputStream, &str)>>,
+    on_stdout: Option<Box<dyn FnMut(&str)>>,
+    on_stderr: Option<Box<dyn FnMut(&str)>>,
+    on_exit: Option<Box<dyn FnOnce(i32)>>,
+}
+
+/// A running child process
+///
+/// Dropping the handle does not stop the process; its callbacks keep being
+/// called until it ends. Use [`kill`](Self::kill) to stop it.
+pub struct Process {
+    ptr: *mut ffi::wxd_Process_t,
+    pid: i64,
+}
+
+impl Process {
+    /// Creates a builder for running `program`, which is looked up in the `PATH`
+    /// if it isn't a path.
+    pub fn builder(program: &str) -> ProcessBuilder {
+        ProcessBuilder {
+            program: program.to_string(),
+            args: Vec::new(),
+            working_dir: None,
+            env: Vec::new(),
+            handlers: ProcessHandlers::default(),
+        }
+    }
+
+    /// Gets the process ID
+    pub fn pid(&self) -> i64 {
+        self.pid
+    }
+
+    /// Returns true until the process has ended and its exit callback was called
+    pub fn is_running(&self) -> bool {
+        unsafe { ffi::wxd_Process_IsRunning(self.ptr) }
+    }
+
+    /// Queues data for the process's standard input.
+    ///
+    /// The data is written without blocking the event loop, as fast as the
+    /// process reads it. Returns false if the process has ended or standard
+    /// input was closed.
+    pub fn write_stdin(&self, data: impl AsRef<[u8]>) -> bool {
+        let data = data.as_ref();
+        unsafe { ffi::wxd_Process_WriteStdin(self.ptr, data.as_ptr(), data.len()) }
+    }
+
+    /// Closes the process's standard input, which signals end of input to it,
+    /// once all data queued with [`write_stdin`](Self::write_stdin) was written
+    pub fn close_stdin(&self) {
+        unsafe { ffi::wxd_Process_CloseStdin(self.ptr) }
+    }
+
+    /// Asks the process and its children to stop (`SIGTERM`, or closing its
+    /// windows on Windows). Returns false if the request could not be sent.
+    pub fn terminate(&self) -> bool {
+        unsafe { ffi::wxd_Process_Kill(self.ptr, WX_SIGTERM) }
+    }
+
+    /// Stops the process and its children immediately. Returns false on failure.
+    pub fn kill(&self) -> bool {
+        unsafe { ffi::wxd_Process_Kill(self.ptr, WX_SIGKILL) }
+    }
+}
+
+impl std::fmt::Debug for Process {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Process")
+            .field("pid", &self.pid)
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+impl Drop for Process {
+    fn drop(&mut self) {
+        unsafe { ffi::wxd_Process_Release(self.ptr) }
+    }
+}
+
+/// Builder for [`Process`]
+pub struct ProcessBuilder {
+    program: String,
+    args: Vec<String>,
+    working_dir: Option<PathBuf>,
+    env: Vec<(String, String)>,
+    handlers: ProcessHandlers,
+}
+
+impl ProcessBuilder {
+    /// Adds an argument
+    pub fn with_arg(mut self, arg: &str) -> Self {
+        self.args.push(arg.to_string());
+        self
+    }
+
+    /// Adds arguments
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.as_ref().to_string()));
+        self
+    }
+
+    /// Runs the process in `dir` instead of the current directory
+    pub fn with_working_dir<P: Into<PathBuf>>(mut self, dir: P) -> Self {
+        self.working_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets an environment variable, in addition to the inherited ones
+    pub fn with_env(mut self, name: &str, value: &str) -> Self {
+        self.env.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Called with each line of output from either stream, without the line break
+    pub fn on_output<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(OutputStream, &str) + 'static,
+    {
+        self.handlers.on_output = Some(Box::new(f));
+        self
+    }
+
+    /// Called with each line of standard output, without the line break
+    pub fn on_stdout<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&str) + 'static,
+    {
+        self.handlers.on_stdout = Some(Box::new(f));
+        self
+    }
+
+    /// Called with each line of standard error, without the line break
+    pub fn on_stderr<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&str) + 'static,
+    {
+        self.handlers.on_stderr = Some(Box::new(f));
+        self
+    }
+
+    /// Called with the exit code once the process has ended and all its output
+    /// was delivered.
+    ///
+    /// Not called if the process could not be started, in which case
+    /// [`spawn`](Self::spawn) returns `None`.
+    pub fn on_exit<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(i32) + 'static,
+    {
+        self.handlers.on_exit = Some(Box::new(f));
+        self
+    }
+
+    /// Starts the process. Returns `None` if it could not be started.
+    ///
+    /// Must be called on the main thread.
+    pub fn spawn(self) -> Option<Process> {
+        let to_c = |text: &str| CString::new(text.replace('\0', "")).unwrap_or_default();
+        let argv: Vec<CString> = std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(|arg| to_c(arg))
+            .collect();
+        let argv_ptrs: Vec<*const c_char> = argv.iter().map(|arg| arg.as_ptr()).collect();
+        let cwd = self
+            .working_dir
+            .as_ref()
+            .map(|dir| to_c(&dir.to_string_lossy()));
+        let env_names: Vec<CString> = self.env.iter().map(|(name, _)| to_c(name)).collect();
+        let env_values: Vec<CString> = self.env.iter().map(|(_, value)| to_c(value)).collect();
+        let env_name_ptrs: Vec<*const c_char> = env_names.iter().map(|n| n.as_ptr()).collect();
+        let env_value_ptrs: Vec<*const c_char> = env_values.iter().map(|v| v.as_ptr()).collect();
+
+        let handlers = Box::into_raw(Box::new(self.handlers)) as *mut c_void;
+        let ptr = unsafe { ffi::wxd_Process_Create(handlers) };
+        if ptr.is_null() {
+            drop(unsafe { Box::from_raw(handlers as *mut ProcessHandlers) });
+            return None;
+        }
+        let pid = unsafe {
+            ffi::wxd_Process_Execute(
+                ptr,
+                argv_ptrs.as_ptr(),
+                argv_ptrs.len() as c_int,
+                cwd.as_ref().map_or(std::ptr::null(), |cwd| cwd.as_ptr()),
+                env_name_ptrs.as_ptr(),
+                env_value_ptrs.as_ptr(),
+                env_name_ptrs.len() as c_int,
+            )
+        };
+        // The C++ object and the handlers are freed when the handle is dropped
+        let process = Process {
+            ptr,
+            pid: i64::from(pid),
+        };
+        (pid != 0).then_some(process)
+    }
+}
+
+/// Function called by C++ with a line of output from a process.
+///
+/// # Safety
+/// `handler` must come from [`ProcessBuilder::spawn`] and `data` must point to
+/// `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rust_process_on_output(
+    handler: *mut c_void,
+    stream: c_int,
+    data: *const c_char,
+    len: usize,
+) {
+    let Some(handlers) = (handler as *mut ProcessHandlers).as_mut() else {
+        return;
+    };
+    let bytes = if data.is_null() {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(data as *const u8, len)
+    };
+    let line = String::from_utf8_lossy(bytes);
+    let stream = if stream == ffi::WXD_PROCESS_STDERR as c_int {
+        OutputStream::Stderr
+    } else {
+        OutputStream::Stdout
+    };
+
+    let result = crate::app::catch_handler_panic(|| {
+        if let Some(on_output) = handlers.on_output.as_mut() {
+            on_output(stream, &line);
+        }
+        let on_stream = match stream {
+            OutputStream::Stdout => handlers.on_stdout.as_mut(),
+            OutputStream::Stderr => handlers.on_stderr.as_mut(),
+        };
+        if let Some(on_stream) = on_stream {
+            on_stream(&line);
+        }
+    });
+    if let Err(payload) = result {
+        crate::app::handle_handler_panic(payload, None, None);
+    }
+}
+
+/// Function called by C++ when a process has ended.
+///
+/// # Safety
+/// `handler` must come from [`ProcessBuilder::spawn`].
+#[no_mangle]
+pub unsafe extern "C" fn rust_process_on_exit(handler: *mut c_void, status: c_int) {
+    let Some(handlers) = (handler as *mut ProcessHandlers).as_mut() else {
+        return;
+    };
+    if let Some(on_exit) = handlers.on_exit.take() {
+        let result = crate::app::catch_handler_panic(|| on_exit(status));
+        if let Err(payload) = result {
+            crate::app::handle_handler_panic(payload, None, None);
+        }
+    }
+}
+
+/// Function called by C++ to drop the handlers of a destroyed process.
+///
+/// # Safety
+/// `handler` must come from [`ProcessBuilder::spawn`] and not have been dropped yet.
+#[no_mangle]
+pub unsafe extern "C" fn rust_process_drop(handler: *mut c_void) {
+    if !handler.is_null() {
+        drop(Box::from_raw(handler as *mut ProcessHandlers));
+    }
+}
@@ -0,0 +1,188 @@
+//! Running a single instance of the application per user.
+//!
+//! When the application is started a second time, the new process sends its
+//! command line arguments (such as files to open) to the running instance and
+//! exits. The running instance receives them on the main thread.
+//!
+//! [`AppOptions::with_single_instance`](crate::app::AppOptions::with_single_instance)
+//! does all of this before `on_init` is called. The functions below can be used
+//! instead for more control. Messages travel over DDE on Windows and a Unix
+//! domain socket in the temporary directory elsewhere.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use wxdragon::app::AppOptions;
+//! use wxdragon::prelude::*;
+//!
+//! let main_frame: Rc<RefCell<Option<Frame>>> = Rc::new(RefCell::new(None));
+//! let frame_slot = main_frame.clone();
+//!
+//! let options = AppOptions::new().with_single_instance("com.example.viewer", move |files| {
+//!     if let Some(frame) = frame_slot.borrow().as_ref() {
+//!         frame.raise();
+//!         // ... open `files` ...
+//!     }
+//! });
+//! let _ = wxdragon::app::main_with_options(options, move |_| {
+//!     let frame = Frame::builder().with_title("Viewer").build();
+//!     frame.show(true);
+//!     *main_frame.borrow_mut() = Some(frame);
+//! });
+//! ```
+
+use std::ffi::CString;
+use std::os::raw::{c_char, c_void};
+use std::time::Duration;
+use wxdragon_sys as ffi;
+
+// The other instance may still be starting up and not listening yet
+const FORWARD_ATTEMPTS: u32 = 10;
+const FORWARD_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+type ArgsHandler = Box<dyn FnMut(Vec<String>)>;
+
+/// Returns true if another process of the current user has claimed `name`.
+///
+/// Otherwise this process claims it until the application exits. `name`
+/// should be unique to the application, e.g. `"com.example.viewer"`.
+pub fn is_another_running(name: &str) -> bool {
+    let Ok(c_name) = CString::new(name) else {
+        return false;
+    };
+    unsafe { ffi::wxd_SingleInstance_IsAnotherRunning(c_name.as_ptr()) }
+}
+
+/// Sends `args` to the instance listening under `name`, retrying for a short
+/// while if it isn't listening yet. Returns false if it could not be reached.
+///
+/// Arguments can't contain NUL characters; any that do are sent without them.
+pub fn forward_args<I, S>(name: &str, args: I) -> bool
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let Ok(c_name) = CString::new(name) else {
+        return false;
+    };
+    let data = encode_args(args);
+    for attempt in 0..FORWARD_ATTEMPTS {
+        if attempt > 0 {
+            std::thread::sleep(FORWARD_RETRY_DELAY);
+        }
+        let sent = unsafe {
+            ffi::wxd_SingleInstance_Forward(
+                c_name.as_ptr(),
+                data.as_ptr() as *const c_char,
+                data.len(),
+            )
+        };
+        if sent {
+            return true;
+        }
+    }
+    false
+}
+
+/// Calls `on_args` on the main thread with the arguments each later instance
+/// forwards. Replaces any previous listener.
+///
+/// Returns false if listening failed, for example because another process
+/// already listens under `name`.
+pub fn listen<F>(name: &str, on_args: F) -> bool
+where
+    F: FnMut(Vec<String>) + 'static,
+{
+    let handler: Box<ArgsHandler> = Box::new(Box::new(on_args));
+    let handler_ptr = Box::into_raw(handler) as *mut c_void;
+    match CString::new(name) {
+        Ok(c_name) => unsafe { ffi::wxd_SingleInstance_Listen(c_name.as_ptr(), handler_ptr) },
+        Err(_) => {
+            drop(unsafe { Box::from_raw(handler_ptr as *mut ArgsHandler) });
+            false
+        }
+    }
+}
+
+// Messages hold the number of arguments, then the arguments, each NUL-terminated
+fn encode_args<I, S>(args: I) -> Vec<u8>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let args: Vec<String> = args
+        .into_iter()
+        .map(|arg| arg.as_ref().replace('\0', ""))
+        .collect();
+    let mut data = format!("{}\0", args.len()).into_bytes();
+    for arg in args {
+        data.extend_from_slice(arg.as_bytes());
+        data.push(0);
+    }
+    data
+}
+
+fn decode_args(data: &[u8]) -> Option<Vec<String>> {
+    let mut fields = data.split(|&byte| byte == 0);
+    let count: usize = std::str::from_utf8(fields.next()?).ok()?.parse().ok()?;
+    let args: Vec<String> = fields
+        .take(count)
+        .map(|field| String::from_utf8_lossy(field).into_owned())
+        .collect();
+    (args.len() == count).then_some(args)
+}
+
+/// Function called by C++ when another instance forwards its arguments.
+///
+/// # Safety
+/// `handler` must come from [`listen`] and `data` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn rust_single_instance_on_args(
+    handler: *mut c_void,
+    data: *const c_char,
+    len: usize,
+) {
+    let Some(handler) = (handler as *mut ArgsHandler).as_mut() else {
+        return;
+    };
+    if data.is_null() {
+        return;
+    }
+    let bytes = std::slice::from_raw_parts(data as *const u8, len);
+    let Some(args) = decode_args(bytes) else {
+        return;
+    };
+    let result = crate::app::catch_handler_panic(|| handler(args));
+    if let Err(payload) = result {
+        crate::app::handle_handler_panic(payload, None, None);
+    }
+}
+
+/// Function called by C++ to drop the handler of a listener that is destroyed.
+///
+/// # Safety
+/// `handler` must come from [`listen`] and not have been dropped yet.
+#[no_mangle]
+pub unsafe extern "C" fn rust_single_instance_drop(handler: *mut c_void) {
+    if !handler.is_null() {
+        drop(Box::from_raw(handler as *mut ArgsHandler));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_args, encode_args};
+
+    #[test]
+    fn args_round_trip() {
+        let args = ["a.txt", "", "dir/b c.txt"];
+        assert_eq!(decode_args(&encode_args(args)).unwrap(), args);
+        assert_eq!(
+            decode_args(&encode_args(Vec::<String>::new())).unwrap(),
+            Vec::<String>::new()
+        );
+        assert!(decode_args(b"3\0a\0").is_none());
+    }
+}
@@ -0,0 +1,119 @@
+//! Standard locations for application files.
+//!
+//! The per-user directories include the application name set with
+//! [`set_app_name`](crate::app::set_app_name) (and on Windows the vendor name),
+//! so they agree with the file used by [`Config`](crate::config::Config) and
+//! [`persistence`](crate::persistence). The application must be running:
+//! before [`main`](crate::main) calls its closure, every path is empty.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use wxdragon::app::AppOptions;
+//! use wxdragon::standard_paths::StandardPaths;
+//!
+//! let options = AppOptions::new().with_app_name("notes");
+//! let _ = wxdragon::app::main_with_options(options, |_| {
+//!     let cache = StandardPaths::user_cache_dir().join("notes");
+//!     std::fs::create_dir_all(&cache).ok();
+//!     let log_file = StandardPaths::user_local_data_dir().join("notes.log");
+//! });
+//! ```
+
+use crate::utils::take_string;
+use std::path::PathBuf;
+use wxdragon_sys as ffi;
+
+/// How files are laid out in the home directory on Unix systems other than macOS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileLayout {
+    /// Dot files and directories directly in the home directory, e.g. `~/.myapp`.
+    /// This is the default.
+    Classic,
+    /// The XDG base directory layout, e.g. `~/.config` and `~/.local/share/myapp`
+    Xdg,
+}
+
+/// Access to the standard directories of the platform
+pub struct StandardPaths;
+
+impl StandardPaths {
+    /// Gets the directory for per-user configuration files, e.g. `~/.config`
+    /// with the XDG layout or the roaming `AppData` directory on Windows.
+    ///
+    /// This directory is shared by applications; see [`config_file`](Self::config_file).
+    pub fn user_config_dir() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetUserConfigDir())) }
+    }
+
+    /// Gets the application's directory for per-user data, e.g. `~/.myapp` or
+    /// `~/.local/share/myapp`, or `AppData\Roaming\Vendor\myapp` on Windows.
+    pub fn user_data_dir() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetUserDataDir())) }
+    }
+
+    /// Gets the application's directory for per-user data that stays on this
+    /// machine, e.g. `AppData\Local\Vendor\myapp` on Windows. Elsewhere it is
+    /// the same as [`user_data_dir`](Self::user_data_dir).
+    pub fn user_local_data_dir() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetUserLocalDataDir())) }
+    }
+
+    /// Gets the per-user cache directory, e.g. `~/.cache` or `~/Library/Caches`.
+    ///
+    /// The directory is shared by applications, so use a subdirectory.
+    pub fn user_cache_dir() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetUserCacheDir())) }
+    }
+
+    /// Gets the user's documents directory
+    pub fn documents_dir() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetDocumentsDir())) }
+    }
+
+    /// Gets the directory for temporary files
+    pub fn temp_dir() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetTempDir())) }
+    }
+
+    /// Gets the full path of the running executable
+    pub fn executable_path() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetExecutablePath())) }
+    }
+
+    /// Gets the directory holding the application's resources, e.g.
+    /// `Contents/Resources` in a macOS bundle or the executable's directory on Windows.
+    pub fn resources_dir() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetResourcesDir())) }
+    }
+
+    /// Gets the directory holding the application's installed data files,
+    /// e.g. `/usr/share/myapp` (under the installation prefix) on Unix.
+    pub fn data_dir() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetDataDir())) }
+    }
+
+    /// Gets the path of the settings file used by [`Config::new`](crate::config::Config::new)
+    /// when given empty names
+    pub fn config_file() -> PathBuf {
+        unsafe { PathBuf::from(take_string(ffi::wxd_StandardPaths_GetConfigFilePath())) }
+    }
+
+    /// Sets the file layout. Changing it also moves where [`Config`](crate::config::Config)
+    /// files are looked for, so set it at startup.
+    pub fn set_file_layout(layout: FileLayout) {
+        let raw = match layout {
+            FileLayout::Classic => ffi::wxd_FileLayout_WXD_FILE_LAYOUT_CLASSIC,
+            FileLayout::Xdg => ffi::wxd_FileLayout_WXD_FILE_LAYOUT_XDG,
+        };
+        unsafe { ffi::wxd_StandardPaths_SetFileLayout(raw) }
+    }
+
+    /// Gets the file layout
+    pub fn file_layout() -> FileLayout {
+        match unsafe { ffi::wxd_StandardPaths_GetFileLayout() } {
+            ffi::wxd_FileLayout_WXD_FILE_LAYOUT_XDG => FileLayout::Xdg,
+            _ => FileLayout::Classic,
+        }
+    }
+}
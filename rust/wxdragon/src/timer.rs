@@ -3,7 +3,7 @@
 //! This module provides a safe wrapper around wxWidgets' wxTimer class.
 //! Timers are used to generate events at regular intervals.
 
-use crate::event::{Event, EventType, WxEvtHandler};
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
 use std::marker::PhantomData;
 use wxdragon_sys as ffi;
 
@@ -62,18 +62,18 @@ impl<T: WxEvtHandler> Timer<T> {
     /// Bind an event handler for timer events.
     ///
     /// This method registers the callback to be called when the timer fires.
-    pub fn on_tick<F>(&self, callback: F)
+    /// The handler is bound on the timer's owner, so the returned binding must be
+    /// passed to `unbind` on the owner.
+    pub fn on_tick<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(Event) + 'static,
     {
-        // We need to make sure the owner exists when using its pointer
-        if !self.owner_ptr.is_null() {
-            // Create a WxEvtHandler wrapper from the bare pointer
-            let handler = TimerOwnerWrapper(self.owner_ptr);
+        // Create a WxEvtHandler wrapper from the bare pointer.
+        // bind_internal returns an inert binding if the owner pointer is null.
+        let handler = TimerOwnerWrapper(self.owner_ptr);
 
-            // Use bind_internal from the WxEvtHandler trait via the wrapper
-            handler.bind_internal(EventType::TIMER, callback);
-        }
+        // Use bind_internal from the WxEvtHandler trait via the wrapper
+        handler.bind_internal(EventType::TIMER, callback)
     }
 
     /// Start the timer.
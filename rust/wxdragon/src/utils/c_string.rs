@@ -0,0 +1,18 @@
+use std::ffi::{c_char, CStr};
+use wxdragon_sys as ffi;
+
+/// Takes ownership of a string allocated by the C API and frees it.
+///
+/// A null pointer gives an empty string.
+///
+/// # Safety
+/// `c_str` must be null or a NUL-terminated string returned by the C API that
+/// is to be freed with `wxd_free_string`. It must not be used afterwards.
+pub(crate) unsafe fn take_string(c_str: *mut c_char) -> String {
+    if c_str.is_null() {
+        return String::new();
+    }
+    let text = CStr::from_ptr(c_str).to_string_lossy().into_owned();
+    ffi::wxd_free_string(c_str);
+    text
+}
@@ -1,3 +1,5 @@
 mod array_string;
+mod c_string;
 
 pub use array_string::WxdArrayString;
+pub(crate) use c_string::take_string;
@@ -0,0 +1,486 @@
+//! Input validators.
+//!
+//! A [`Validator`] attached to a control with
+//! [`WxWidget::set_validator`](crate::window::WxWidget::set_validator) filters
+//! keystrokes, checks the control's value and moves it to and from a shared
+//! Rust value:
+//!
+//! - [`transfer_data_to_window`](crate::window::WxWidget::transfer_data_to_window)
+//!   copies the bound values into the controls,
+//! - [`validate`](crate::window::WxWidget::validate) checks every control,
+//!   telling the user about the first invalid one,
+//! - [`transfer_data_from_window`](crate::window::WxWidget::transfer_data_from_window)
+//!   copies the controls' values back.
+//!
+//! Dialogs do all three by themselves: values are transferred when the dialog
+//! is shown, and the `ID_OK` button only closes it once every field is valid.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use wxdragon::prelude::*;
+//!
+//! let frame = Frame::builder().with_title("App").build();
+//! let dialog = Dialog::builder(&frame, "Settings").build();
+//!
+//! let port = Rc::new(RefCell::new(8080_i64));
+//! let port_ctrl = TextCtrl::builder(&dialog).build();
+//! port_ctrl.set_validator(&Validator::integer(1, 65535).with_value(port.clone()));
+//!
+//! let user = Rc::new(RefCell::new(String::new()));
+//! let user_ctrl = TextCtrl::builder(&dialog).build();
+//! user_ctrl.set_validator(
+//!     &Validator::text(TextValidatorStyle::EMPTY)
+//!         .with_check(|text| {
+//!             if text.contains(' ') {
+//!                 Err("The user name may not contain spaces".to_string())
+//!             } else {
+//!                 Ok(())
+//!             }
+//!         })
+//!         .with_value(user.clone()),
+//! );
+//!
+//! if dialog.show_modal() == ID_OK {
+//!     println!("{}@localhost:{}", user.borrow(), port.borrow());
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::ffi::{c_void, CStr, CString};
+use std::os::raw::c_char;
+use std::rc::Rc;
+use wxdragon_sys as ffi;
+
+bitflags::bitflags! {
+    /// Filters for [`Validator::text`] (`wxFILTER_*`)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct TextValidatorStyle: i64 {
+        /// No filtering
+        const NONE = 0x0;
+        /// Reject empty values
+        const EMPTY = 0x1;
+        /// Only ASCII characters
+        const ASCII = 0x2;
+        /// Only letters
+        const ALPHA = 0x4;
+        /// Only letters and digits
+        const ALPHANUMERIC = 0x8;
+        /// Only digits
+        const DIGITS = 0x10;
+        /// Only digits, signs, decimal points and exponents
+        const NUMERIC = 0x20;
+        /// Only the characters given to [`Validator::with_char_includes`]
+        const INCLUDE_CHAR_LIST = 0x80;
+        /// No characters given to [`Validator::with_char_excludes`]
+        const EXCLUDE_CHAR_LIST = 0x200;
+        /// Only hexadecimal digits
+        const XDIGITS = 0x400;
+        /// Also allow spaces
+        const SPACE = 0x800;
+    }
+}
+
+bitflags::bitflags! {
+    /// Options for [`Validator::integer`] and [`Validator::float`] (`wxNUM_VAL_*`)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct NumValidatorStyle: i32 {
+        const DEFAULT = 0x0;
+        /// Group digits with the locale's thousands separator
+        const THOUSANDS_SEPARATOR = 0x1;
+        /// Show zero as an empty field, and accept an empty field as zero
+        const ZERO_AS_BLANK = 0x2;
+        /// Don't show trailing zeroes after the decimal point
+        const NO_TRAILING_ZEROES = 0x4;
+    }
+}
+
+type CheckFn = Box<dyn Fn(&str) -> Result<(), String>>;
+
+enum BoundValue {
+    None,
+    Text(Rc<RefCell<String>>),
+    Integer(Rc<RefCell<i64>>),
+    Float(Rc<RefCell<f64>>),
+}
+
+struct ValidatorData {
+    check: Option<CheckFn>,
+    value: BoundValue,
+}
+
+// Shared by the validator and every copy wxWidgets makes of it
+type SharedData = Rc<RefCell<ValidatorData>>;
+
+/// Types that can be bound to a validator with [`Validator::with_value`]:
+/// `String`, `i64` and `f64`.
+pub trait ValidatorValue: Sized {
+    #[doc(hidden)]
+    fn bind(cell: Rc<RefCell<Self>>) -> BoundValueHandle;
+}
+
+/// Opaque handle produced by [`ValidatorValue::bind`].
+#[doc(hidden)]
+pub struct BoundValueHandle(BoundValue);
+
+impl ValidatorValue for String {
+    fn bind(cell: Rc<RefCell<Self>>) -> BoundValueHandle {
+        BoundValueHandle(BoundValue::Text(cell))
+    }
+}
+
+impl ValidatorValue for i64 {
+    fn bind(cell: Rc<RefCell<Self>>) -> BoundValueHandle {
+        BoundValueHandle(BoundValue::Integer(cell))
+    }
+}
+
+impl ValidatorValue for f64 {
+    fn bind(cell: Rc<RefCell<Self>>) -> BoundValueHandle {
+        BoundValueHandle(BoundValue::Float(cell))
+    }
+}
+
+/// A validator that checks a control's value and transfers it to and from a
+/// bound Rust value.
+///
+/// Validators from [`text`](Self::text), [`integer`](Self::integer) and
+/// [`float`](Self::float) only work on text entries such as `TextCtrl` and
+/// `ComboBox`. Those from [`from_fn`](Self::from_fn) and [`regex`](Self::regex)
+/// also work on spin controls and choices, which they check by their value or
+/// selected string.
+///
+/// The window stores its own copy when the validator is attached, so one
+/// `Validator` can be attached to several controls and dropped afterwards.
+/// Copies share the check and the bound value.
+pub struct Validator {
+    ptr: *mut ffi::wxd_Validator_t,
+    data: SharedData,
+}
+
+impl Validator {
+    fn create(create: impl FnOnce(*mut c_void) -> *mut ffi::wxd_Validator_t) -> Self {
+        let data: SharedData = Rc::new(RefCell::new(ValidatorData {
+            check: None,
+            value: BoundValue::None,
+        }));
+        // The C++ validator owns one reference, released via rust_validator_drop
+        let raw = Box::into_raw(Box::new(data.clone())) as *mut c_void;
+        let ptr = create(raw);
+        if ptr.is_null() {
+            unsafe { rust_validator_drop(raw) };
+        }
+        Validator { ptr, data }
+    }
+
+    /// Creates a validator that filters characters as given by `style`, for
+    /// text entries only.
+    pub fn text(style: TextValidatorStyle) -> Self {
+        Self::create(|data| unsafe { ffi::wxd_Validator_CreateText(style.bits(), data) })
+    }
+
+    /// Creates a validator that only accepts integers between `min` and `max`,
+    /// for text entries only.
+    pub fn integer(min: i64, max: i64) -> Self {
+        Self::create(|data| unsafe {
+            ffi::wxd_Validator_CreateInteger(NumValidatorStyle::DEFAULT.bits(), min, max, data)
+        })
+    }
+
+    /// Creates a validator that only accepts numbers between `min` and `max`,
+    /// with at most `precision` digits after the decimal point, for text
+    /// entries only.
+    pub fn float(precision: i32, min: f64, max: f64) -> Self {
+        Self::create(|data| unsafe {
+            ffi::wxd_Validator_CreateFloat(
+                NumValidatorStyle::DEFAULT.bits(),
+                precision,
+                min,
+                max,
+                data,
+            )
+        })
+    }
+
+    /// Creates a validator that only runs `check` on the control's text.
+    ///
+    /// `check` returns the message shown to the user when the text is invalid.
+    pub fn from_fn<F>(check: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        Self::create(|data| unsafe { ffi::wxd_Validator_CreateCustom(data) }).with_check(check)
+    }
+
+    /// Creates a validator that accepts text matching the regular expression
+    /// `pattern` as a whole, showing `message` otherwise.
+    ///
+    /// The pattern uses wxWidgets' advanced regular expression syntax. An
+    /// invalid pattern rejects every value.
+    pub fn regex(pattern: &str, message: &str) -> Self {
+        let pattern = CString::new(pattern).unwrap_or_default();
+        let message = message.to_string();
+        Self::from_fn(move |text| {
+            let c_text = CString::new(text).unwrap_or_default();
+            match unsafe { ffi::wxd_Validator_RegexMatches(pattern.as_ptr(), c_text.as_ptr()) } {
+                1 => Ok(()),
+                _ => Err(message.clone()),
+            }
+        })
+    }
+
+    /// Adds a check on the control's text, run after the validator's own checks.
+    ///
+    /// `check` returns the message shown to the user when the text is invalid.
+    pub fn with_check<F>(self, check: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        self.data.borrow_mut().check = Some(Box::new(check));
+        self
+    }
+
+    /// Binds the control's value to `value`.
+    ///
+    /// The control is filled from `value` by `transfer_data_to_window` and
+    /// `value` is updated by `transfer_data_from_window`. Numeric validators
+    /// take an `i64` (integer) or `f64` (float) cell, all others a `String` cell.
+    pub fn with_value<T: ValidatorValue>(self, value: Rc<RefCell<T>>) -> Self {
+        self.data.borrow_mut().value = T::bind(value).0;
+        self
+    }
+
+    /// Sets the characters accepted with `TextValidatorStyle::INCLUDE_CHAR_LIST`.
+    pub fn with_char_includes(self, chars: &str) -> Self {
+        let c_chars = CString::new(chars).unwrap_or_default();
+        unsafe { ffi::wxd_TextValidator_SetCharIncludes(self.ptr, c_chars.as_ptr()) };
+        self
+    }
+
+    /// Sets the characters rejected with `TextValidatorStyle::EXCLUDE_CHAR_LIST`.
+    pub fn with_char_excludes(self, chars: &str) -> Self {
+        let c_chars = CString::new(chars).unwrap_or_default();
+        unsafe { ffi::wxd_TextValidator_SetCharExcludes(self.ptr, c_chars.as_ptr()) };
+        self
+    }
+
+    /// Sets the formatting options of an integer or float validator.
+    pub fn with_num_style(self, style: NumValidatorStyle) -> Self {
+        unsafe { ffi::wxd_NumValidator_SetStyle(self.ptr, style.bits()) };
+        self
+    }
+
+    /// Returns the raw underlying validator pointer.
+    pub fn as_ptr(&self) -> *mut ffi::wxd_Validator_t {
+        self.ptr
+    }
+}
+
+impl Drop for Validator {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ffi::wxd_Validator_Destroy(self.ptr) };
+        }
+    }
+}
+
+// --- Callbacks from C++ ---
+
+unsafe fn shared_data<'a>(data: *mut c_void) -> Option<&'a SharedData> {
+    (data as *const SharedData).as_ref()
+}
+
+/// Function called by C++ when a validator is copied; returns a new reference to its data.
+///
+/// # Safety
+/// `data` must be null or a pointer passed to a `wxd_Validator_Create*` function
+/// (or returned from this function) that has not been dropped yet.
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_clone(data: *mut c_void) -> *mut c_void {
+    match shared_data(data) {
+        Some(shared) => Box::into_raw(Box::new(shared.clone())) as *mut c_void,
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Function called by C++ to release a validator's reference to its data.
+///
+/// # Safety
+/// `data` must be null or a live data pointer as for [`rust_validator_clone`].
+/// It must not be used after this function returns.
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_drop(data: *mut c_void) {
+    if !data.is_null() {
+        drop(Box::from_raw(data as *mut SharedData));
+    }
+}
+
+/// Function called by C++ to run the check closure on a control's text.
+///
+/// # Safety
+/// `data` must be null or a live data pointer, `text` null or a valid C string,
+/// and `out_message` null or valid for writes. A message written to
+/// `out_message` must be freed with [`rust_validator_free_string`].
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_check(
+    data: *mut c_void,
+    text: *const c_char,
+    out_message: *mut *mut c_char,
+) -> bool {
+    let Some(shared) = shared_data(data) else {
+        return true;
+    };
+    let text = if text.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(text).to_string_lossy().into_owned()
+    };
+
+    let result = crate::app::catch_handler_panic(|| match &shared.borrow().check {
+        Some(check) => check(&text),
+        None => Ok(()),
+    });
+    match result {
+        Ok(Ok(())) => true,
+        Ok(Err(message)) => {
+            if !out_message.is_null() {
+                *out_message = CString::new(message).unwrap_or_default().into_raw();
+            }
+            false
+        }
+        // A check that panicked did not accept the value
+        Err(payload) => {
+            crate::app::handle_handler_panic(payload, None, None);
+            false
+        }
+    }
+}
+
+/// Function called by C++ to read a bound text value.
+///
+/// # Safety
+/// `data` must be null or a live data pointer. The returned string must be
+/// freed with [`rust_validator_free_string`].
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_get_text(data: *mut c_void) -> *mut c_char {
+    let Some(shared) = shared_data(data) else {
+        return std::ptr::null_mut();
+    };
+    match &shared.borrow().value {
+        BoundValue::Text(cell) => match cell.try_borrow() {
+            Ok(text) => CString::new(text.as_str()).unwrap_or_default().into_raw(),
+            Err(_) => std::ptr::null_mut(),
+        },
+        _ => std::ptr::null_mut(),
+    }
+}
+
+/// Function called by C++ to store a bound text value.
+///
+/// # Safety
+/// `data` must be null or a live data pointer and `text` null or a valid C string.
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_set_text(data: *mut c_void, text: *const c_char) {
+    let Some(shared) = shared_data(data) else {
+        return;
+    };
+    if text.is_null() {
+        return;
+    }
+    if let BoundValue::Text(cell) = &shared.borrow().value {
+        if let Ok(mut value) = cell.try_borrow_mut() {
+            *value = CStr::from_ptr(text).to_string_lossy().into_owned();
+        }
+    }
+}
+
+/// Function called by C++ to read a bound integer value.
+///
+/// # Safety
+/// `data` must be null or a live data pointer and `out_value` null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_get_integer(
+    data: *mut c_void,
+    out_value: *mut i64,
+) -> bool {
+    let Some(shared) = shared_data(data) else {
+        return false;
+    };
+    match &shared.borrow().value {
+        BoundValue::Integer(cell) if !out_value.is_null() => match cell.try_borrow() {
+            Ok(value) => {
+                *out_value = *value;
+                true
+            }
+            Err(_) => false,
+        },
+        _ => false,
+    }
+}
+
+/// Function called by C++ to store a bound integer value.
+///
+/// # Safety
+/// `data` must be null or a live data pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_set_integer(data: *mut c_void, value: i64) {
+    let Some(shared) = shared_data(data) else {
+        return;
+    };
+    if let BoundValue::Integer(cell) = &shared.borrow().value {
+        if let Ok(mut bound) = cell.try_borrow_mut() {
+            *bound = value;
+        }
+    }
+}
+
+/// Function called by C++ to read a bound floating point value.
+///
+/// # Safety
+/// `data` must be null or a live data pointer and `out_value` null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_get_float(data: *mut c_void, out_value: *mut f64) -> bool {
+    let Some(shared) = shared_data(data) else {
+        return false;
+    };
+    match &shared.borrow().value {
+        BoundValue::Float(cell) if !out_value.is_null() => match cell.try_borrow() {
+            Ok(value) => {
+                *out_value = *value;
+                true
+            }
+            Err(_) => false,
+        },
+        _ => false,
+    }
+}
+
+/// Function called by C++ to store a bound floating point value.
+///
+/// # Safety
+/// `data` must be null or a live data pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_set_float(data: *mut c_void, value: f64) {
+    let Some(shared) = shared_data(data) else {
+        return;
+    };
+    if let BoundValue::Float(cell) = &shared.borrow().value {
+        if let Ok(mut bound) = cell.try_borrow_mut() {
+            *bound = value;
+        }
+    }
+}
+
+/// Function called by C++ to free a string returned by the validator callbacks.
+///
+/// # Safety
+/// `text` must be null or a string returned by one of the callbacks above,
+/// not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn rust_validator_free_string(text: *mut c_char) {
+    if !text.is_null() {
+        drop(CString::from_raw(text));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::event::{Event, EventType, WxEvtHandler};
+use crate::event::{Event, EventBinding, EventType, WxEvtHandler};
 use crate::prelude::*;
 use crate::window::Window;
 use std::ffi::CString;
@@ -504,61 +504,61 @@ impl AuiManagerEventData {
 // Implement event handling for AuiManager
 impl AuiManager {
     /// Bind a handler for the pane button event
-    pub fn on_pane_button<F>(&self, callback: F)
+    pub fn on_pane_button<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_BUTTON, callback);
+        self.bind_aui_event(EventType::AUI_PANE_BUTTON, callback)
     }
 
     /// Bind a handler for the pane close event
-    pub fn on_pane_close<F>(&self, callback: F)
+    pub fn on_pane_close<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_CLOSE, callback);
+        self.bind_aui_event(EventType::AUI_PANE_CLOSE, callback)
     }
 
     /// Bind a handler for the pane maximize event
-    pub fn on_pane_maximize<F>(&self, callback: F)
+    pub fn on_pane_maximize<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_MAXIMIZE, callback);
+        self.bind_aui_event(EventType::AUI_PANE_MAXIMIZE, callback)
     }
 
     /// Bind a handler for the pane restore event
-    pub fn on_pane_restore<F>(&self, callback: F)
+    pub fn on_pane_restore<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_RESTORE, callback);
+        self.bind_aui_event(EventType::AUI_PANE_RESTORE, callback)
     }
 
     /// Bind a handler for the pane activated event
-    pub fn on_pane_activated<F>(&self, callback: F)
+    pub fn on_pane_activated<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_PANE_ACTIVATED, callback);
+        self.bind_aui_event(EventType::AUI_PANE_ACTIVATED, callback)
     }
 
     /// Bind a handler for the render event
-    pub fn on_render<F>(&self, callback: F)
+    pub fn on_render<F>(&self, callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
-        self.bind_aui_event(EventType::AUI_RENDER, callback);
+        self.bind_aui_event(EventType::AUI_RENDER, callback)
     }
 
     // Internal helper to bind AUI events
-    fn bind_aui_event<F>(&self, event_type: EventType, mut callback: F)
+    fn bind_aui_event<F>(&self, event_type: EventType, mut callback: F) -> EventBinding
     where
         F: FnMut(AuiManagerEventData) + 'static,
     {
         self.bind_internal(event_type, move |event| {
             let data = AuiManagerEventData::new(event);
             callback(data);
-        });
+        })
     }
 }
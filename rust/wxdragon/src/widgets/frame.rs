@@ -1,5 +1,5 @@
 use crate::bitmap::Bitmap; // ADDED: Import Bitmap
-use crate::geometry::{Point, Size, DEFAULT_POSITION};
+use crate::geometry::{DipValue, Point, Size, DEFAULT_POSITION};
 use crate::id::Id;
 use crate::id::ID_ANY;
 use crate::menus::MenuBar; // ADDED: Import MenuBar
@@ -91,7 +91,7 @@ pub struct FrameBuilder {
     pos: Point,
     size: Size,
     style: FrameStyle,
-    // name: String, // Removed name for now
+    persistent_name: Option<String>,
 }
 
 // Manual implementation of Default
@@ -107,7 +107,7 @@ impl Default for FrameBuilder {
                 height: 400,
             }, // Specific default size for Frame
             style: FrameStyle::Default,
-            // name: String::new(),
+            persistent_name: None,
         }
     }
 }
@@ -143,12 +143,30 @@ impl FrameBuilder {
         self
     }
 
+    /// Sets the size in device-independent pixels.
+    ///
+    /// The size is converted at the DPI of the parent (so call `with_parent` first),
+    /// or of the primary display for frames without a parent.
+    pub fn with_dip_size(mut self, size: Size) -> Self {
+        self.size = unsafe { DipValue::from_dip_for(size, self.parent_ptr) };
+        self
+    }
+
     /// Sets the window style flags.
     pub fn with_style(mut self, style: FrameStyle) -> Self {
         self.style = style;
         self
     }
 
+    /// Saves the frame's position, size and maximized state under `name` when
+    /// it is destroyed, and restores them when it is built.
+    ///
+    /// See [`persistence`](crate::persistence) for where the state is stored.
+    pub fn with_persistent_name(mut self, name: &str) -> Self {
+        self.persistent_name = Some(name.to_string());
+        self
+    }
+
     /// Builds the `Frame`.
     ///
     /// # Panics
@@ -170,11 +188,15 @@ impl FrameBuilder {
         if ptr.is_null() {
             panic!("Failed to create wxFrame: wxWidgets returned a null pointer.");
         } else {
-            Frame {
+            let frame = Frame {
                 window: unsafe { Window::from_ptr(ptr as *mut ffi::wxd_Window_t) },
                 parent_ptr: self.parent_ptr,
                 _marker: PhantomData,
+            };
+            if let Some(name) = &self.persistent_name {
+                crate::persistence::register(&frame, name);
             }
+            frame
         }
     }
 }
@@ -357,20 +379,24 @@ impl Frame {
 // Add event binding methods to Frame
 impl Frame {
     /// Bind a handler to window events using the underlying window
-    pub(crate) fn bind_window_event<F>(&self, event_type: crate::event::EventType, handler: F)
+    pub(crate) fn bind_window_event<F>(
+        &self,
+        event_type: crate::event::EventType,
+        handler: F,
+    ) -> crate::event::EventBinding
     where
         F: FnMut(crate::event::Event) + 'static,
     {
         // Use the bind_internal method provided by the WxEvtHandler trait
-        <Self as crate::event::WxEvtHandler>::bind_internal(self, event_type, handler);
+        <Self as crate::event::WxEvtHandler>::bind_internal(self, event_type, handler)
     }
 
     /// Bind a handler to menu events
-    pub fn on_menu<F>(&self, handler: F)
+    pub fn on_menu<F>(&self, handler: F) -> crate::event::EventBinding
     where
         F: FnMut(crate::event::Event) + 'static,
     {
-        self.bind_window_event(crate::event::EventType::MENU, handler);
+        self.bind_window_event(crate::event::EventType::MENU, handler)
     }
 
     /// Convenience method for tracking menu lifecycle events
@@ -399,6 +425,9 @@ impl Frame {
 
 implement_widget_traits_with_target!(Frame, window, Window);
 
+// Top-level windows receive char hook events before their children
+impl crate::event::CharHookEvents for Frame {}
+
 // Manual XRC Support for Frame - complex structure needs custom handling
 #[cfg(feature = "xrc")]
 impl crate::xrc::XrcSupport for Frame {
@@ -1,7 +1,7 @@
 //!
 //! Safe wrapper for wxSplitterWindow.
 
-use crate::event::{Event, EventType, WindowEvents, WxEvtHandler};
+use crate::event::{Event, EventType, UpdateUIEvents, WindowEvents, WxEvtHandler};
 use crate::geometry::{Point, Size};
 use crate::id::Id;
 use crate::window::WxWidget;
@@ -220,6 +220,7 @@ crate::implement_widget_local_event_handlers!(
 
 // Add WindowEvents implementation
 impl WindowEvents for SplitterWindow {}
+impl UpdateUIEvents for SplitterWindow {}
 
 // Add XRC Support - enables SplitterWindow to be created from XRC-managed pointers
 #[cfg(feature = "xrc")]
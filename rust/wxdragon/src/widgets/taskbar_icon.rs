@@ -328,7 +328,7 @@ impl WxEvtHandler for TaskBarIcon {
 // Add menu event handling to TaskBarIcon
 impl TaskBarIcon {
     /// Bind a handler to menu events from the TaskBarIcon's popup menu
-    pub fn on_menu<F>(&self, handler: F)
+    pub fn on_menu<F>(&self, handler: F) -> crate::event::EventBinding
     where
         F: FnMut(crate::event::Event) + 'static,
     {
@@ -336,7 +336,7 @@ impl TaskBarIcon {
             self,
             crate::event::EventType::MENU,
             handler,
-        );
+        )
     }
 }
 
@@ -347,7 +347,11 @@ impl TaskBarIcon {
     /// Only available on Windows and Linux where TaskBarIcon events are supported.
     #[doc(hidden)]
     #[cfg(any(target_os = "windows", target_os = "linux"))]
-    pub(crate) fn bind_taskbar_event<F>(&self, event: TaskBarIconEvent, mut callback: F)
+    pub(crate) fn bind_taskbar_event<F>(
+        &self,
+        event: TaskBarIconEvent,
+        mut callback: F,
+    ) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
@@ -379,14 +383,14 @@ impl TaskBarIcon {
         };
 
         // Use internal bind method
-        crate::event::WxEvtHandler::bind_internal(self, event_type, wrapper);
+        crate::event::WxEvtHandler::bind_internal(self, event_type, wrapper)
     }
 
     /// Binds a handler to taskbar icon left mouse button down
     ///
     /// Note: Only available on Windows and Linux. Not supported on macOS.
     #[cfg(any(target_os = "windows", target_os = "linux"))]
-    pub fn on_left_down<F>(&self, callback: F)
+    pub fn on_left_down<F>(&self, callback: F) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
@@ -397,7 +401,7 @@ impl TaskBarIcon {
     ///
     /// Note: Only available on Windows and Linux. Not supported on macOS.
     #[cfg(any(target_os = "windows", target_os = "linux"))]
-    pub fn on_left_double_click<F>(&self, callback: F)
+    pub fn on_left_double_click<F>(&self, callback: F) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
@@ -409,7 +413,7 @@ impl TaskBarIcon {
 #[cfg(target_os = "windows")]
 impl TaskBarIcon {
     /// Binds a handler to taskbar icon mouse movement (Windows only)
-    pub fn on_move<F>(&self, callback: F)
+    pub fn on_move<F>(&self, callback: F) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
@@ -417,7 +421,7 @@ impl TaskBarIcon {
     }
 
     /// Binds a handler to taskbar icon left mouse button up (Windows only)
-    pub fn on_left_up<F>(&self, callback: F)
+    pub fn on_left_up<F>(&self, callback: F) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
@@ -425,7 +429,7 @@ impl TaskBarIcon {
     }
 
     /// Binds a handler to taskbar icon right mouse button down (Windows only)
-    pub fn on_right_down<F>(&self, callback: F)
+    pub fn on_right_down<F>(&self, callback: F) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
@@ -433,7 +437,7 @@ impl TaskBarIcon {
     }
 
     /// Binds a handler to taskbar icon right mouse button up (Windows only)
-    pub fn on_right_up<F>(&self, callback: F)
+    pub fn on_right_up<F>(&self, callback: F) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
@@ -441,7 +445,7 @@ impl TaskBarIcon {
     }
 
     /// Binds a handler to taskbar icon right mouse button double-click (Windows only)
-    pub fn on_right_double_click<F>(&self, callback: F)
+    pub fn on_right_double_click<F>(&self, callback: F) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
@@ -449,7 +453,7 @@ impl TaskBarIcon {
     }
 
     /// Binds a handler to taskbar icon balloon timeout (Windows only)
-    pub fn on_balloon_timeout<F>(&self, callback: F)
+    pub fn on_balloon_timeout<F>(&self, callback: F) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
@@ -457,7 +461,7 @@ impl TaskBarIcon {
     }
 
     /// Binds a handler to taskbar icon balloon click (Windows only)
-    pub fn on_balloon_click<F>(&self, callback: F)
+    pub fn on_balloon_click<F>(&self, callback: F) -> crate::event::EventBinding
     where
         F: FnMut(TaskBarIconEventData) + 'static,
     {
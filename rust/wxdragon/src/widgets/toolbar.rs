@@ -3,7 +3,7 @@
 
 use crate::bitmap::Bitmap;
 use crate::bitmap_bundle::BitmapBundle;
-use crate::event::{Event, EventType, WindowEvents, WxEvtHandler};
+use crate::event::{Event, EventType, UpdateUIEvents, WindowEvents, WxEvtHandler};
 use crate::id::Id;
 use crate::menus::ItemKind; // Reuse ItemKind for tool types
 use crate::window::{Window, WxWidget};
@@ -356,6 +356,7 @@ crate::implement_widget_local_event_handlers!(
 );
 
 impl WindowEvents for ToolBar {}
+impl UpdateUIEvents for ToolBar {}
 
 // Add XRC support
 // XRC Support - enables ToolBar to be created from XRC-managed pointers
@@ -689,6 +689,17 @@ pub trait WxWidget {
         self.set_extra_style_raw(current_style & !style.bits());
     }
 
+    /// Sends update UI events to this window and its children immediately.
+    ///
+    /// Useful after a state change when waiting for the next idle update would be
+    /// noticeable, or when the update UI mode is `UpdateUIMode::ProcessSpecified`.
+    fn update_window_ui(&self) {
+        let window_ptr = self.handle_ptr();
+        if !window_ptr.is_null() {
+            unsafe { ffi::wxd_Window_UpdateWindowUI(window_ptr) };
+        }
+    }
+
     /// Gets the parent window of this widget.
     ///
     /// # Returns
@@ -1054,6 +1065,97 @@ pub trait WxWidget {
         }
     }
 
+    // --- Validation ---
+
+    /// Sets the validator for this control. The window keeps its own copy.
+    ///
+    /// Returns false without setting it if the validator only works on text
+    /// entries (see [`Validator`](crate::validator::Validator)) and this control
+    /// isn't one.
+    fn set_validator(&self, validator: &crate::validator::Validator) -> bool {
+        let handle = self.handle_ptr();
+        if handle.is_null() || validator.as_ptr().is_null() {
+            return false;
+        }
+        unsafe { ffi::wxd_Window_SetValidator(handle, validator.as_ptr()) }
+    }
+
+    /// Runs the validators of this window's children, and of their children for
+    /// windows with `ExtraWindowStyle::ValidateRecursively`.
+    ///
+    /// # Returns
+    /// `false` if any of them failed; the user has then been told why.
+    fn validate(&self) -> bool {
+        let handle = self.handle_ptr();
+        !handle.is_null() && unsafe { ffi::wxd_Window_Validate(handle) }
+    }
+
+    /// Copies the values bound to the children's validators into the children.
+    fn transfer_data_to_window(&self) -> bool {
+        let handle = self.handle_ptr();
+        !handle.is_null() && unsafe { ffi::wxd_Window_TransferDataToWindow(handle) }
+    }
+
+    /// Copies the children's values into the values bound to their validators.
+    fn transfer_data_from_window(&self) -> bool {
+        let handle = self.handle_ptr();
+        !handle.is_null() && unsafe { ffi::wxd_Window_TransferDataFromWindow(handle) }
+    }
+
+    // --- DPI ---
+
+    /// Converts a [`Size`], [`Point`] or `i32` from device-independent pixels
+    /// (DIPs) to physical pixels at this window's current DPI.
+    ///
+    /// Sizes given in DIPs look the same on standard and high-DPI displays:
+    /// `window.from_dip(Size::new(200, 100))` is 200x100 pixels at 96 DPI and
+    /// 400x200 pixels at 192 DPI.
+    fn from_dip<T: crate::geometry::DipValue>(&self, value: T) -> T
+    where
+        Self: Sized,
+    {
+        unsafe { value.from_dip_for(self.handle_ptr()) }
+    }
+
+    /// Converts a [`Size`], [`Point`] or `i32` from physical pixels to
+    /// device-independent pixels at this window's current DPI.
+    fn to_dip<T: crate::geometry::DipValue>(&self, value: T) -> T
+    where
+        Self: Sized,
+    {
+        unsafe { value.to_dip_for(self.handle_ptr()) }
+    }
+
+    /// Gets the ratio of this window's DPI to the standard DPI (96 on Windows and Linux).
+    fn get_dpi_scale_factor(&self) -> f64 {
+        unsafe { ffi::wxd_Window_GetDPIScaleFactor(self.handle_ptr()) }
+    }
+
+    /// Gets the DPI of the display this window is on.
+    fn get_dpi(&self) -> Size {
+        let handle = self.handle_ptr();
+        if handle.is_null() {
+            return Size::new(0, 0);
+        }
+        unsafe { ffi::wxd_Window_GetDPI(handle) }.into()
+    }
+
+    // --- Accelerators ---
+
+    /// Sets the keyboard accelerators for this window.
+    ///
+    /// Pass `None` to remove the window's accelerators. The window keeps its own
+    /// copy of the table.
+    fn set_accelerator_table(&self, table: Option<&crate::accelerator::AcceleratorTable>) {
+        let handle = self.handle_ptr();
+        if !handle.is_null() {
+            let table_ptr = table.map(|t| t.as_ptr()).unwrap_or(std::ptr::null_mut());
+            unsafe {
+                ffi::wxd_Window_SetAcceleratorTable(handle, table_ptr);
+            }
+        }
+    }
+
     // --- Z-Order Management ---
 
     /// Raises the window to the top of the window hierarchy (Z-order).
@@ -1516,6 +1618,54 @@ pub trait WxWidget {
         }
     }
 
+    // --- Window Tree ---
+
+    /// Gets the direct children of this window, in the order they were created.
+    fn get_children(&self) -> Vec<Window> {
+        let handle = self.handle_ptr();
+        if handle.is_null() {
+            return Vec::new();
+        }
+
+        let count = unsafe { ffi::wxd_Window_GetChildren(handle, std::ptr::null_mut(), 0) };
+        if count <= 0 {
+            return Vec::new();
+        }
+        let mut ptrs = vec![std::ptr::null_mut(); count as usize];
+        let copied = unsafe { ffi::wxd_Window_GetChildren(handle, ptrs.as_mut_ptr(), count) };
+        ptrs.truncate(copied.clamp(0, count) as usize);
+        ptrs.into_iter()
+            .filter(|ptr| !ptr.is_null())
+            .map(|ptr| unsafe { Window::from_ptr(ptr) })
+            .collect()
+    }
+
+    /// Returns a depth-first iterator over all windows below this one.
+    ///
+    /// Each window is visited before its children; this window itself is not included.
+    ///
+    /// # Example
+    /// ```ignore
+    /// // Disable every text control in a panel
+    /// for window in panel.descendants() {
+    ///     if let Some(text) = window.as_widget::<TextCtrl>() {
+    ///         text.enable(false);
+    ///     }
+    /// }
+    /// ```
+    fn descendants(&self) -> Descendants {
+        Descendants::new(self.get_children())
+    }
+
+    /// Finds the first window below this one, in depth-first order, that matches `predicate`.
+    fn find_first<P>(&self, mut predicate: P) -> Option<Window>
+    where
+        P: FnMut(&Window) -> bool,
+        Self: Sized,
+    {
+        self.descendants().find(|window| predicate(window))
+    }
+
     /// Navigates to the next or previous control.
     ///
     /// This is equivalent to pressing Tab (forward) or Shift+Tab (backward)
@@ -1583,6 +1733,49 @@ pub trait WxWidget {
     }
 }
 
+/// Depth-first iterator over the windows below a window, created by
+/// [`WxWidget::descendants`].
+///
+/// Children are read when their parent is visited, so windows created or
+/// destroyed during iteration may or may not be seen.
+pub struct Descendants {
+    // Pending windows with their depth, next one last
+    stack: Vec<(Window, usize)>,
+    depth: usize,
+}
+
+impl Descendants {
+    fn new(children: Vec<Window>) -> Self {
+        Descendants {
+            stack: children.into_iter().rev().map(|child| (child, 1)).collect(),
+            depth: 0,
+        }
+    }
+
+    /// Gets the depth of the window most recently returned: 1 for direct
+    /// children, 2 for their children, and so on.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+impl Iterator for Descendants {
+    type Item = Window;
+
+    fn next(&mut self) -> Option<Window> {
+        let (window, depth) = self.stack.pop()?;
+        self.stack.extend(
+            window
+                .get_children()
+                .into_iter()
+                .rev()
+                .map(|child| (child, depth + 1)),
+        );
+        self.depth = depth;
+        Some(window)
+    }
+}
+
 /// Trait for widgets that can be cast from a Window using class name matching
 pub trait FromWindowWithClassName: Sized {
     /// Returns the expected wxWidgets class name for this widget type
@@ -1622,6 +1815,19 @@ impl Window {
         }
     }
 
+    /// Returns true if this window is an instance of the wxWidgets class
+    /// `class_name` (e.g. `"wxTextCtrl"`) or of a class derived from it
+    pub fn is_kind_of(&self, class_name: &str) -> bool {
+        let handle = self.handle_ptr();
+        if handle.is_null() {
+            return false;
+        }
+        match std::ffi::CString::new(class_name) {
+            Ok(c_name) => unsafe { ffi::wxd_Window_IsKindOf(handle, c_name.as_ptr()) },
+            Err(_) => false,
+        }
+    }
+
     /// Generic method to cast this window to a specific widget type
     /// Uses wxWidgets' built-in RTTI to safely verify the type before casting
     pub fn as_widget<T: FromWindowWithClassName>(&self) -> Option<T> {
@@ -1635,6 +1841,16 @@ impl Window {
             None
         }
     }
+
+    /// Like [`as_widget`](Self::as_widget), but also accepts windows whose class
+    /// derives from `T`'s class (see [`is_kind_of`](Self::is_kind_of))
+    pub fn as_kind_of<T: FromWindowWithClassName>(&self) -> Option<T> {
+        if self.is_kind_of(T::class_name()) {
+            Some(unsafe { T::from_ptr(self.handle_ptr()) })
+        } else {
+            None
+        }
+    }
 }
 
 /// Trait for downcasting wxWidgets to specific types.
@@ -1702,3 +1918,5 @@ impl WxEvtHandler for Window {
         self.0 as *mut ffi::wxd_EvtHandler_t
     }
 }
+
+impl crate::event::UpdateUIEvents for Window {}
@@ -31,6 +31,25 @@ impl XmlResource {
         }
     }
 
+    /// Sets the message catalog domain used to translate labels, titles and
+    /// other text in the resources loaded afterwards. By default every loaded
+    /// catalog is searched.
+    ///
+    /// See the [`i18n`](crate::i18n) module for loading catalogs.
+    pub fn set_domain(&self, domain: &str) {
+        let c_domain = CString::new(domain).unwrap_or_default();
+        unsafe {
+            ffi::wxd_XmlResource_SetDomain(self.ptr, c_domain.as_ptr());
+        }
+    }
+
+    /// Sets whether text in the resources loaded afterwards is translated (the default)
+    pub fn set_use_locale(&self, use_locale: bool) {
+        unsafe {
+            ffi::wxd_XmlResource_SetUseLocale(self.ptr, use_locale);
+        }
+    }
+
     /// Initialize platform-aware StaticBitmap handler for XRC files
     ///
     /// This should be called after `init_all_handlers()` and before loading XRC files.